@@ -0,0 +1,29 @@
+#!/bin/sh
+# Fail when a detection parser got more than 2x slower than the saved baseline.
+# Run after `cargo bench --bench screen_detection -- --baseline main`.
+set -eu
+
+criterion_dir="${CARGO_TARGET_DIR:-target}/criterion"
+budget=1.0
+status=0
+checked=0
+
+for estimates in "$criterion_dir"/parse_*/change/estimates.json; do
+    [ -f "$estimates" ] || continue
+    checked=$((checked + 1))
+    name=$(basename "$(dirname "$(dirname "$estimates")")")
+    # The first point estimate is the relative change of the mean
+    change=$(grep -o '"point_estimate":[-0-9.eE+]*' "$estimates" | head -n 1 | cut -d: -f2)
+    if awk -v change="$change" -v budget="$budget" 'BEGIN { exit !(change > budget) }'; then
+        echo "$name: mean changed by $(awk -v change="$change" 'BEGIN { printf "%+.0f%%", change * 100 }'), over the 2x budget"
+        status=1
+    fi
+done
+
+# No comparisons means the bench didn't run against a baseline, which must not pass as "no regression"
+if [ "$checked" -eq 0 ]; then
+    echo "No parse_* comparisons found in $criterion_dir; run the bench with --baseline first" >&2
+    exit 1
+fi
+
+exit $status
@@ -0,0 +1,49 @@
+#!/bin/sh
+# Write the medians of the saved `main` baseline into benches/BASELINE.md,
+# together with the machine and toolchain they were measured on.
+# Run after `cargo bench -- --save-baseline main`.
+set -eu
+
+criterion_dir="${CARGO_TARGET_DIR:-target}/criterion"
+baseline_md="$(dirname "$0")/BASELINE.md"
+table=$(mktemp)
+trap 'rm -f "$table" "$table.md"' EXIT
+
+cpu=$(grep -m 1 'model name' /proc/cpuinfo 2>/dev/null | cut -d: -f2- | sed 's/^ *//' || true)
+{
+    echo "Measured on $(date -u +%Y-%m-%d), commit $(git rev-parse --short HEAD)."
+    echo
+    echo "- Machine: ${cpu:-unknown CPU}, $(uname -srm)"
+    echo "- Toolchain: $(rustc --version)"
+    echo
+    echo "| Benchmark | Median |"
+    echo "|---|---|"
+} > "$table"
+
+count=0
+for estimates in $(find "$criterion_dir" -path '*/main/estimates.json' | sort); do
+    name=${estimates#"$criterion_dir"/}
+    name=${name%/main/estimates.json}
+    # Nanoseconds, the point estimate right after the median's confidence interval
+    median=$(grep -o '"median":{"confidence_interval":{[^}]*},"point_estimate":[0-9.eE+-]*' "$estimates" | sed 's/.*://')
+    awk -v name="$name" -v ns="$median" 'BEGIN {
+        if (ns >= 1e6) printf "| %s | %.2f ms |\n", name, ns / 1e6
+        else if (ns >= 1e3) printf "| %s | %.2f µs |\n", name, ns / 1e3
+        else printf "| %s | %.0f ns |\n", name, ns
+    }' >> "$table"
+    count=$((count + 1))
+done
+
+if [ "$count" -eq 0 ]; then
+    echo "No saved main baseline under $criterion_dir, run cargo bench -- --save-baseline main first" >&2
+    exit 1
+fi
+
+# Replace everything between the markers
+awk -v table="$table" '
+    /<!-- baseline:start -->/ { print; while ((getline line < table) > 0) print line; skip = 1; next }
+    /<!-- baseline:end -->/ { skip = 0 }
+    !skip
+' "$baseline_md" > "$table.md"
+mv "$table.md" "$baseline_md"
+echo "Recorded $count benchmark(s) in $baseline_md"
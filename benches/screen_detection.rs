@@ -0,0 +1,55 @@
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::fixtures;
+
+/// Repeat the mode lines of a fixture until the output is `lines` long
+fn padded(fixture: &str, mode_line: &str, lines: usize) -> String {
+    let mut output = fixture.to_string();
+    while output.lines().count() < lines {
+        output.push_str(mode_line);
+        output.push('\n');
+    }
+    output
+}
+
+/// The Hyprland fixture's monitors repeated until the array is `lines` long,
+/// still valid JSON so a serde_json parser can be benchmarked on the same input
+fn hyprctl_monitors(lines: usize) -> String {
+    let monitors = fixtures::HYPRCTL_MONITORS
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let mut output = format!("[{}]", monitors);
+    while output.lines().count() < lines {
+        output.insert_str(output.len() - 1, &format!(",{}", monitors));
+    }
+    output
+}
+
+fn parsers(c: &mut Criterion) {
+    let hyprctl = hyprctl_monitors(100);
+    let xrandr = padded(fixtures::XRANDR_CURRENT, "   1024x768      60.00", 200);
+    let wlr_randr = padded(fixtures::WLR_RANDR, "    1024x768 px, 60.004000 Hz", 100);
+
+    c.bench_function("parse_hyprctl_output", |b| {
+        b.iter(|| ScreenInfo::parse_hyprctl_output(black_box(&hyprctl)))
+    });
+    c.bench_function("parse_swaymsg_output", |b| {
+        b.iter(|| ScreenInfo::parse_swaymsg_output(black_box(fixtures::SWAYMSG_OUTPUTS)))
+    });
+    c.bench_function("parse_xrandr_primary", |b| {
+        b.iter(|| ScreenInfo::parse_xrandr_primary(black_box(&xrandr)))
+    });
+    c.bench_function("parse_wlr_randr_output", |b| {
+        b.iter(|| ScreenInfo::parse_wlr_randr_output(black_box(&wlr_randr)))
+    });
+    c.bench_function("parse_drm_modes", |b| {
+        b.iter(|| ScreenInfo::parse_drm_modes(black_box(fixtures::DRM_MODES)))
+    });
+}
+
+criterion_group!(screen_detection, parsers);
+criterion_main!(screen_detection);
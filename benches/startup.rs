@@ -0,0 +1,69 @@
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+
+use common::{EmptyFileSystem, FixtureRunner, hyprland};
+use quick_accent::accents;
+use quick_accent::config::app::AppConfig;
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::geometry::Rect;
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::fixtures;
+
+fn config_load(c: &mut Criterion) {
+    let xdg_dir = std::env::temp_dir().join(format!("quick-accent-bench-{}", std::process::id()));
+    let config_path = xdg_dir.join("quick-accent").join("config.ron");
+    AppConfig::default()
+        .save_to_path(&config_path)
+        .expect("write benchmark config");
+
+    c.bench_function("config_load_validate", |b| {
+        b.iter(|| {
+            let config = AppConfig::load_from_path(black_box(&config_path)).unwrap();
+            config.validate().unwrap();
+            config
+        })
+    });
+
+    let _ = std::fs::remove_dir_all(&xdg_dir);
+}
+
+fn character_sets(c: &mut Criterion) {
+    c.bench_function("variants_for_all_bases", |b| {
+        b.iter(|| {
+            ('a'..='z')
+                .chain('A'..='Z')
+                .map(|base| accents::variants_for(black_box(base)).len())
+                .sum::<usize>()
+        })
+    });
+}
+
+fn geometry_pipeline(c: &mut Criterion) {
+    let config = AppConfig::default();
+    let screen_config = ScreenConfig::default();
+    let env = hyprland();
+
+    c.bench_function("geometry_pipeline", |b| {
+        // A fresh runner per iteration, so its call log doesn't grow across the run
+        b.iter_batched(
+            || FixtureRunner::new().answer("hyprctl monitors -j", fixtures::HYPRCTL_MONITORS),
+            |runner| {
+                let screen = ScreenInfo::detect_with(&screen_config, &env, &runner, &EmptyFileSystem);
+                let (width, height) = config.calculate_window_size(screen.width, screen.height);
+                let (x, y) = config.calculate_window_position(screen.width, screen.height, width, None);
+                config.ensure_visible(
+                    Rect::new(screen.x + x, screen.y + y, width, height),
+                    Rect::new(screen.x, screen.y, screen.width, screen.height),
+                )
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(startup, config_load, character_sets, geometry_pipeline);
+criterion_main!(startup);
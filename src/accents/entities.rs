@@ -0,0 +1,236 @@
+/// Diacritic of an accented letter, shared by the HTML and LaTeX tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    Acute,
+    Grave,
+    Circumflex,
+    Diaeresis,
+    Tilde,
+    Ring,
+    Cedilla,
+    Macron,
+    Caron,
+    Dot,
+    Ogonek,
+}
+
+impl Mark {
+    /// Suffix of the HTML entity name, e.g. "acute" in `&eacute;`
+    fn html_suffix(self) -> &'static str {
+        match self {
+            Mark::Acute => "acute",
+            Mark::Grave => "grave",
+            Mark::Circumflex => "circ",
+            Mark::Diaeresis => "uml",
+            Mark::Tilde => "tilde",
+            Mark::Ring => "ring",
+            Mark::Cedilla => "cedil",
+            Mark::Macron => "macr",
+            Mark::Caron => "caron",
+            Mark::Dot => "dot",
+            Mark::Ogonek => "ogon",
+        }
+    }
+
+    /// LaTeX accent command, e.g. `\'` for acute
+    fn latex_command(self) -> &'static str {
+        match self {
+            Mark::Acute => "\\'",
+            Mark::Grave => "\\`",
+            Mark::Circumflex => "\\^",
+            Mark::Diaeresis => "\\\"",
+            Mark::Tilde => "\\~",
+            Mark::Ring => "\\r",
+            Mark::Cedilla => "\\c",
+            Mark::Macron => "\\=",
+            Mark::Caron => "\\v",
+            Mark::Dot => "\\.",
+            Mark::Ogonek => "\\k",
+        }
+    }
+}
+
+/// Base letter and diacritic of a lowercase accented letter
+fn decompose(ch: char) -> Option<(char, Mark)> {
+    let parts = match ch {
+        'à' => ('a', Mark::Grave),
+        'á' => ('a', Mark::Acute),
+        'â' => ('a', Mark::Circumflex),
+        'ä' => ('a', Mark::Diaeresis),
+        'ã' => ('a', Mark::Tilde),
+        'å' => ('a', Mark::Ring),
+        'ā' => ('a', Mark::Macron),
+        'ç' => ('c', Mark::Cedilla),
+        'ć' => ('c', Mark::Acute),
+        'č' => ('c', Mark::Caron),
+        'è' => ('e', Mark::Grave),
+        'é' => ('e', Mark::Acute),
+        'ê' => ('e', Mark::Circumflex),
+        'ë' => ('e', Mark::Diaeresis),
+        'ē' => ('e', Mark::Macron),
+        'ė' => ('e', Mark::Dot),
+        'ę' => ('e', Mark::Ogonek),
+        'î' => ('i', Mark::Circumflex),
+        'ï' => ('i', Mark::Diaeresis),
+        'í' => ('i', Mark::Acute),
+        'ī' => ('i', Mark::Macron),
+        'į' => ('i', Mark::Ogonek),
+        'ì' => ('i', Mark::Grave),
+        'ñ' => ('n', Mark::Tilde),
+        'ń' => ('n', Mark::Acute),
+        'ô' => ('o', Mark::Circumflex),
+        'ö' => ('o', Mark::Diaeresis),
+        'ò' => ('o', Mark::Grave),
+        'ó' => ('o', Mark::Acute),
+        'ō' => ('o', Mark::Macron),
+        'õ' => ('o', Mark::Tilde),
+        'ś' => ('s', Mark::Acute),
+        'š' => ('s', Mark::Caron),
+        'û' => ('u', Mark::Circumflex),
+        'ü' => ('u', Mark::Diaeresis),
+        'ù' => ('u', Mark::Grave),
+        'ú' => ('u', Mark::Acute),
+        'ū' => ('u', Mark::Macron),
+        'ÿ' => ('y', Mark::Diaeresis),
+        'ž' => ('z', Mark::Caron),
+        'ź' => ('z', Mark::Acute),
+        'ż' => ('z', Mark::Dot),
+        _ => return None,
+    };
+    Some(parts)
+}
+
+/// Accented letter split into its base letter (in the letter's case) and diacritic
+fn decompose_any_case(ch: char) -> Option<(char, Mark)> {
+    if let Some(parts) = decompose(ch) {
+        return Some(parts);
+    }
+    if !ch.is_uppercase() {
+        return None;
+    }
+    let mut lower = ch.to_lowercase();
+    let (letter, mark) = match (lower.next(), lower.next()) {
+        (Some(single), None) => decompose(single)?,
+        _ => return None,
+    };
+    Some((letter.to_ascii_uppercase(), mark))
+}
+
+/// HTML entity names of characters that aren't a letter plus diacritic
+fn html_special(ch: char) -> Option<&'static str> {
+    let name = match ch {
+        'æ' => "aelig",
+        'Æ' => "AElig",
+        'œ' => "oelig",
+        'Œ' => "OElig",
+        'ĳ' => "ijlig",
+        'Ĳ' => "IJlig",
+        'ø' => "oslash",
+        'Ø' => "Oslash",
+        'ł' => "lstrok",
+        'Ł' => "Lstrok",
+        'ß' => "szlig",
+        'ı' => "imath",
+        'İ' => "Idot",
+        'ð' => "eth",
+        'θ' => "theta",
+        'ª' => "ordf",
+        'º' => "ordm",
+        '©' => "copy",
+        '®' => "reg",
+        '™' => "trade",
+        '¢' => "cent",
+        '€' => "euro",
+        '£' => "pound",
+        '¥' => "yen",
+        '&' => "amp",
+        '°' => "deg",
+        '¶' => "para",
+        '§' => "sect",
+        '×' => "times",
+        '–' => "ndash",
+        '—' => "mdash",
+        '…' => "hellip",
+        '·' => "middot",
+        '¡' => "iexcl",
+        '¿' => "iquest",
+        '“' => "ldquo",
+        '”' => "rdquo",
+        '«' => "laquo",
+        '»' => "raquo",
+        '‘' => "lsquo",
+        '’' => "rsquo",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// LaTeX commands of characters that aren't a letter plus diacritic
+fn latex_special(ch: char) -> Option<&'static str> {
+    let command = match ch {
+        'æ' => "\\ae",
+        'Æ' => "\\AE",
+        'œ' => "\\oe",
+        'Œ' => "\\OE",
+        'ø' => "\\o",
+        'Ø' => "\\O",
+        'ł' => "\\l",
+        'Ł' => "\\L",
+        'ß' => "\\ss",
+        'ı' => "\\i",
+        'İ' => "\\.I",
+        '©' => "\\copyright",
+        '®' => "\\textregistered",
+        '™' => "\\texttrademark",
+        '€' => "\\euro",
+        '£' => "\\pounds",
+        '&' => "\\&",
+        '$' => "\\$",
+        '°' => "\\textdegree",
+        '¶' => "\\P",
+        '§' => "\\S",
+        '–' => "--",
+        '—' => "---",
+        '…' => "\\dots",
+        '·' => "\\textperiodcentered",
+        '¡' => "!`",
+        '¿' => "?`",
+        '“' => "``",
+        '”' => "''",
+        '«' => "\\guillemotleft",
+        '»' => "\\guillemotright",
+        '‘' => "`",
+        '’' => "'",
+        _ => return None,
+    };
+    Some(command)
+}
+
+/// Named HTML entity for `ch`, e.g. `&eacute;` for 'é'
+pub fn html_entity(ch: char) -> Option<String> {
+    if let Some(name) = html_special(ch) {
+        return Some(format!("&{};", name));
+    }
+    let (letter, mark) = decompose_any_case(ch)?;
+    Some(format!("&{}{};", letter, mark.html_suffix()))
+}
+
+/// LaTeX text-mode representation of `ch`, e.g. `\'e` for 'é'
+pub fn latex(ch: char) -> Option<String> {
+    if let Some(command) = latex_special(ch) {
+        // A control word like \ss would swallow a following letter, so group it
+        let is_word = command.strip_prefix('\\').is_some_and(|name| name.chars().all(|c| c.is_ascii_alphabetic()));
+        if is_word {
+            return Some(format!("{{{}}}", command));
+        }
+        return Some(command.to_string());
+    }
+    let (letter, mark) = decompose_any_case(ch)?;
+    let command = mark.latex_command();
+    // Letter commands like \c need braces, symbol commands like \' don't
+    if command.ends_with(|c: char| c.is_ascii_alphabetic()) {
+        Some(format!("{}{{{}}}", command, letter))
+    } else {
+        Some(format!("{}{}", command, letter))
+    }
+}
@@ -0,0 +1,51 @@
+/// Language code of a POSIX locale string: "fr_FR.UTF-8" and "fr" both give
+/// "fr". None for the "C" and "POSIX" locales, which name no language.
+pub fn language(locale: &str) -> Option<&str> {
+    let language = locale
+        .split(['_', '.', '@'])
+        .next()
+        .filter(|language| !language.is_empty())?;
+    if language == "C" || language == "POSIX" {
+        return None;
+    }
+    Some(language)
+}
+
+/// Lowercase variants a language writes with, most common first
+fn language_variants(language: &str) -> &'static [char] {
+    match language.to_ascii_lowercase().as_str() {
+        "fr" => &['é', 'è', 'à', 'ç', 'ê', 'ù', 'â', 'î', 'ô', 'û', 'ë', 'ï', 'ü', 'ÿ', 'œ', 'æ'],
+        "de" => &['ä', 'ö', 'ü', 'ß'],
+        "es" => &['ñ', 'á', 'é', 'í', 'ó', 'ú', 'ü'],
+        "pt" => &['ã', 'õ', 'á', 'é', 'í', 'ó', 'ú', 'â', 'ê', 'ô', 'à', 'ç'],
+        "it" => &['à', 'è', 'é', 'ì', 'ò', 'ù'],
+        "nl" => &['ĳ', 'ë', 'ï', 'é', 'è'],
+        "pl" => &['ą', 'ć', 'ę', 'ł', 'ń', 'ó', 'ś', 'ź', 'ż'],
+        "cs" => &['á', 'č', 'é', 'í', 'ó', 'š', 'ú', 'ý', 'ž'],
+        "tr" => &['ç', 'ğ', 'ı', 'ö', 'ş', 'ü'],
+        "sv" => &['å', 'ä', 'ö'],
+        "da" | "nb" | "nn" | "no" => &['æ', 'ø', 'å'],
+        "lt" => &['ą', 'č', 'ę', 'ė', 'į', 'š', 'ų', 'ū', 'ž'],
+        "lv" => &['ā', 'č', 'ē', 'ģ', 'ī', 'ķ', 'ļ', 'ņ', 'š', 'ū', 'ž'],
+        _ => &[],
+    }
+}
+
+/// Lowercase variants preferred for `locale`, empty when its language is unknown
+pub fn preferred_variants(locale: &str) -> &'static [char] {
+    language(locale).map_or(&[], language_variants)
+}
+
+/// Move the variants in `preferred` to the front of `group`, in `preferred`
+/// order; the rest keep their order. Uppercase variants match their lowercase form.
+pub fn boost(group: &mut [char], preferred: &[char]) {
+    let rank = |ch: &char| {
+        let lower = ch.to_lowercase().next().unwrap_or(*ch);
+        preferred
+            .iter()
+            .position(|&wanted| wanted == lower)
+            .unwrap_or(preferred.len())
+    };
+    // Stable, so variants outside `preferred` stay in display order
+    group.sort_by_key(rank);
+}
@@ -0,0 +1,219 @@
+pub mod entities;
+pub mod locale;
+pub mod names;
+
+/// Accented variants for a lowercase base letter
+fn lowercase_variants(base: char) -> &'static [char] {
+    match base {
+        'a' => &['à', 'á', 'â', 'ä', 'æ', 'ã', 'å', 'ā'],
+        'c' => &['ç', 'ć', 'č'],
+        'e' => &['è', 'é', 'ê', 'ë', 'ē', 'ė', 'ę'],
+        'i' => &['î', 'ï', 'í', 'ī', 'į', 'ì', 'ı', 'ĳ'],
+        'l' => &['ł'],
+        'n' => &['ñ', 'ń'],
+        'o' => &['ô', 'ö', 'ò', 'ó', 'œ', 'ø', 'ō', 'õ'],
+        's' => &['ß', 'ś', 'š'],
+        'u' => &['û', 'ü', 'ù', 'ú', 'ū'],
+        'y' => &['ÿ'],
+        'z' => &['ž', 'ź', 'ż'],
+        _ => &[],
+    }
+}
+
+/// Typographic symbols associated with a base character
+fn lowercase_typographic(base: char) -> &'static [char] {
+    match base {
+        'a' => &['ª', '@'],
+        'c' => &['©', '¢'],
+        'e' => &['€', '&'],
+        'l' => &['£'],
+        'o' => &['º', '°'],
+        'p' => &['¶'],
+        'r' => &['®'],
+        's' => &['§', '$'],
+        't' => &['™'],
+        'x' => &['×'],
+        'y' => &['¥'],
+        '-' => &['–', '—'],
+        '.' => &['…', '·'],
+        '!' => &['¡'],
+        '?' => &['¿'],
+        '"' => &['“', '”', '«', '»'],
+        '\'' => &['‘', '’'],
+        _ => &[],
+    }
+}
+
+/// IPA letters associated with a base character
+fn lowercase_ipa(base: char) -> &'static [char] {
+    match base {
+        'a' => &['ɑ', 'ɐ'],
+        'd' => &['ð'],
+        'e' => &['ə', 'ɛ'],
+        'i' => &['ɪ'],
+        'n' => &['ŋ', 'ɲ'],
+        'o' => &['ɔ'],
+        'r' => &['ɹ', 'ɾ'],
+        's' => &['ʃ'],
+        't' => &['θ'],
+        'u' => &['ʊ', 'ʌ'],
+        'z' => &['ʒ'],
+        _ => &[],
+    }
+}
+
+/// Uppercase forms a language expects that `to_uppercase` doesn't give, by the
+/// lowercase base letter whose row they appear in:
+/// (language, base, lowercase variant, uppercase form)
+const UPPERCASE_PAIRS: &[(&str, char, char, &str)] = &[
+    // Turkish and Azerbaijani capitalise i to the dotted İ, which the I row then
+    // offers, and the dotless ı to I
+    ("tr", 'i', 'i', "İ"),
+    ("tr", 'i', 'ı', "I"),
+    ("az", 'i', 'i', "İ"),
+    ("az", 'i', 'ı', "I"),
+    // Dutch capitalises the ij digraph as a whole, typed as two letters
+    ("nl", 'i', 'ĳ', "IJ"),
+    // German has a capital sharp s, to_uppercase gives "SS"
+    ("de", 's', 'ß', "ẞ"),
+];
+
+/// Pairs of `language` for the row of the lowercase `base`
+fn uppercase_pairs(base: char, language: Option<&str>) -> impl Iterator<Item = (char, &'static str)> + '_ {
+    UPPERCASE_PAIRS
+        .iter()
+        .filter(move |(pair_language, pair_base, _, _)| {
+            *pair_base == base && language.is_some_and(|language| language.eq_ignore_ascii_case(pair_language))
+        })
+        .map(|&(_, _, lower, upper)| (lower, upper))
+}
+
+fn single_char(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(single), None) => Some(single),
+        _ => None,
+    }
+}
+
+/// Uppercase form of `variant` in the row of the lowercase `base` for `language`:
+/// the language's pair when it has one, `to_uppercase` otherwise. May be more than
+/// one character, like the Dutch "IJ" for ĳ or "SS" for ß outside German.
+pub fn uppercase_form(base: char, variant: char, language: Option<&str>) -> String {
+    match uppercase_pairs(base, language).find(|&(lower, _)| lower == variant) {
+        Some((_, upper)) => upper.to_string(),
+        None => variant.to_uppercase().collect(),
+    }
+}
+
+/// Character standing for `variant` in the uppercase row of the lowercase `base`:
+/// its `uppercase_form` when that is one character, else `to_uppercase` when that
+/// is, like Ĳ for the Dutch "IJ". None when neither is, like "SS" for ß outside German.
+pub fn uppercase_variant(base: char, variant: char, language: Option<&str>) -> Option<char> {
+    single_char(&uppercase_form(base, variant, language))
+        .or_else(|| single_char(&variant.to_uppercase().collect::<String>()))
+}
+
+/// Letters typed for `shown` in the row of the uppercase `base` when `language`
+/// uppercases it to more than one, like "IJ" for Ĳ in Dutch
+pub fn uppercase_digraph(base: char, shown: char, language: Option<&str>) -> Option<&'static str> {
+    if !base.is_uppercase() {
+        return None;
+    }
+    let lower = base.to_lowercase().next().unwrap_or(base);
+    uppercase_pairs(lower, language)
+        .find(|&(variant, upper)| {
+            upper.chars().count() > 1 && uppercase_variant(lower, variant, language) == Some(shown)
+        })
+        .map(|(_, upper)| upper)
+}
+
+/// Uppercase `variants` when the base is uppercase, following `language`'s pairs
+fn matching_case(base: char, variants: &[char], language: Option<&str>) -> Vec<char> {
+    if !base.is_uppercase() {
+        return variants.to_vec();
+    }
+
+    let lower = base.to_lowercase().next().unwrap_or(base);
+    // Uppercase forms of the base letter itself, like the Turkish İ for I
+    let own_forms = uppercase_pairs(lower, language)
+        .filter(|&(variant, _)| variant == lower)
+        .filter_map(|(_, upper)| single_char(upper));
+    variants
+        .iter()
+        // Keep variants whose uppercase form is more than one char
+        .map(|&ch| uppercase_variant(lower, ch, language).unwrap_or(ch))
+        .chain(own_forms)
+        // The dotless ı uppercases to the base I itself
+        .filter(|&ch| ch != base)
+        .collect()
+}
+
+/// Accented variants for `base`, uppercased when the base is uppercase
+pub fn variants_for(base: char) -> Vec<char> {
+    variants_in(base, None)
+}
+
+/// Same as `variants_for`, uppercased the way `language` (e.g. "tr") does
+pub fn variants_in(base: char, language: Option<&str>) -> Vec<char> {
+    let lower = base.to_lowercase().next().unwrap_or(base);
+    matching_case(base, lowercase_variants(lower), language)
+}
+
+/// Kind of characters in a group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Accents,
+    Typographic,
+    Ipa,
+}
+
+impl Category {
+    /// Name shown in the breadcrumb
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::Accents => "Accents",
+            Category::Typographic => "Typographic",
+            Category::Ipa => "IPA",
+        }
+    }
+}
+
+/// Variants for `base` split into groups: accents, then typographic symbols and
+/// IPA letters when enabled. Empty groups are left out.
+pub fn groups_for(base: char, include_typographic: bool, include_ipa: bool) -> Vec<Vec<char>> {
+    categorized_groups(base, include_typographic, include_ipa)
+        .into_iter()
+        .map(|(_, group)| group)
+        .collect()
+}
+
+/// Same as `groups_for`, with the category of each group
+pub fn categorized_groups(
+    base: char,
+    include_typographic: bool,
+    include_ipa: bool,
+) -> Vec<(Category, Vec<char>)> {
+    categorized_groups_in(base, include_typographic, include_ipa, None)
+}
+
+/// Same as `categorized_groups`, uppercased the way `language` does
+pub fn categorized_groups_in(
+    base: char,
+    include_typographic: bool,
+    include_ipa: bool,
+    language: Option<&str>,
+) -> Vec<(Category, Vec<char>)> {
+    let lower = base.to_lowercase().next().unwrap_or(base);
+    let mut groups = vec![(Category::Accents, variants_in(base, language))];
+
+    if include_typographic {
+        groups.push((Category::Typographic, matching_case(base, lowercase_typographic(lower), language)));
+    }
+    if include_ipa {
+        groups.push((Category::Ipa, matching_case(base, lowercase_ipa(lower), language)));
+    }
+
+    groups.retain(|(_, group)| !group.is_empty());
+    groups
+}
@@ -0,0 +1,144 @@
+/// Short descriptive name of a lowercase accented character
+fn lowercase_name(ch: char) -> Option<&'static str> {
+    let name = match ch {
+        'à' => "a grave",
+        'á' => "a acute",
+        'â' => "a circumflex",
+        'ä' => "a diaeresis",
+        'æ' => "ae ligature",
+        'ã' => "a tilde",
+        'å' => "a ring",
+        'ā' => "a macron",
+        'ç' => "c cedilla",
+        'ć' => "c acute",
+        'č' => "c caron",
+        'è' => "e grave",
+        'é' => "e acute",
+        'ê' => "e circumflex",
+        'ë' => "e diaeresis",
+        'ē' => "e macron",
+        'ė' => "e dot",
+        'ę' => "e ogonek",
+        'î' => "i circumflex",
+        'ï' => "i diaeresis",
+        'í' => "i acute",
+        'ī' => "i macron",
+        'į' => "i ogonek",
+        'ì' => "i grave",
+        'ı' => "i dotless",
+        'ĳ' => "ij ligature",
+        'ł' => "l stroke",
+        'ñ' => "n tilde",
+        'ń' => "n acute",
+        'ô' => "o circumflex",
+        'ö' => "o diaeresis",
+        'ò' => "o grave",
+        'ó' => "o acute",
+        'œ' => "oe ligature",
+        'ø' => "o stroke",
+        'ō' => "o macron",
+        'õ' => "o tilde",
+        'ß' => "sharp s",
+        'ś' => "s acute",
+        'š' => "s caron",
+        'û' => "u circumflex",
+        'ü' => "u diaeresis",
+        'ù' => "u grave",
+        'ú' => "u acute",
+        'ū' => "u macron",
+        'ÿ' => "y diaeresis",
+        'ž' => "z caron",
+        'ź' => "z acute",
+        'ż' => "z dot",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Unicode name of a typographic symbol or IPA letter, lowercase
+fn symbol_name(ch: char) -> Option<&'static str> {
+    let name = match ch {
+        'ª' => "feminine ordinal indicator",
+        'º' => "masculine ordinal indicator",
+        '@' => "commercial at",
+        '©' => "copyright sign",
+        '¢' => "cent sign",
+        '€' => "euro sign",
+        '&' => "ampersand",
+        '£' => "pound sign",
+        '°' => "degree sign",
+        '¶' => "pilcrow sign",
+        '®' => "registered sign",
+        '§' => "section sign",
+        '$' => "dollar sign",
+        '™' => "trade mark sign",
+        '×' => "multiplication sign",
+        '¥' => "yen sign",
+        '–' => "en dash",
+        '—' => "em dash",
+        '…' => "horizontal ellipsis",
+        '·' => "middle dot",
+        '¡' => "inverted exclamation mark",
+        '¿' => "inverted question mark",
+        '“' => "left double quotation mark",
+        '”' => "right double quotation mark",
+        '«' => "left-pointing double angle quotation mark",
+        '»' => "right-pointing double angle quotation mark",
+        '‘' => "left single quotation mark",
+        '’' => "right single quotation mark",
+        'ɑ' => "alpha",
+        'ɐ' => "turned a",
+        'ð' => "eth",
+        'ə' => "schwa",
+        'ɛ' => "open e",
+        'ɪ' => "small capital i",
+        'ŋ' => "eng",
+        'ɲ' => "n with left hook",
+        'ɔ' => "open o",
+        'ɹ' => "turned r",
+        'ɾ' => "r with fishhook",
+        'ʃ' => "esh",
+        'θ' => "theta",
+        'ʊ' => "upsilon",
+        'ʌ' => "turned v",
+        'ʒ' => "ezh",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Longest short name shown by `short_name`
+pub const SHORT_NAME_CHARS: usize = 8;
+
+/// One uppercase word telling a character apart from its neighbours, e.g.
+/// "ACUTE" for 'é' or "EURO" for '€', cut to `SHORT_NAME_CHARS` characters.
+/// Letters use their accent, everything else the first word of its Unicode name.
+pub fn short_name(ch: char) -> Option<String> {
+    let lower = ch.to_lowercase().next().unwrap_or(ch);
+    let word = match lowercase_name(lower) {
+        Some(name) => name.rsplit(' ').next()?,
+        None => symbol_name(ch)?.split(' ').next()?,
+    };
+    Some(word.to_uppercase().chars().take(SHORT_NAME_CHARS).collect())
+}
+
+/// Display name shown under a variant, e.g. "e acute" or "E acute"
+pub fn variant_name(ch: char) -> Option<String> {
+    // Capitals whose lowercase form names them badly
+    match ch {
+        'İ' => return Some("I dot above".to_string()),
+        'ẞ' => return Some("capital sharp s".to_string()),
+        _ => {}
+    }
+
+    let lower = ch.to_lowercase().next().unwrap_or(ch);
+    let name = lowercase_name(lower)?;
+
+    if ch.is_uppercase() {
+        // Uppercase only the letter part: "E acute", "AE ligature"
+        let (letters, accent) = name.split_once(' ').unwrap_or((name, ""));
+        Some(format!("{} {}", letters.to_uppercase(), accent).trim_end().to_string())
+    } else {
+        Some(name.to_string())
+    }
+}
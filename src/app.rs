@@ -0,0 +1,1253 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{Column, Row, Stack, button, canvas, container, mouse_area, text};
+use iced::{
+    Border, Color, Element, Event, Padding, Size, Subscription, Task, Theme, border, event, keyboard, mouse,
+    theme, window,
+};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::accents::{Category, locale};
+use crate::category::CategoryStack;
+use crate::clipboard_ring::ClipboardRing;
+use crate::close::CloseStep;
+use crate::config::app::AppConfig;
+use crate::config::theme::ThemeConfig;
+use crate::config::tool::{ToolConfig, WindowBehavior, WindowEvent, WindowState};
+use crate::config::window::WindowConfig;
+use crate::output::{Delivery, OutputBackend};
+use crate::screen::runner::{CommandRunner, SystemRunner};
+use crate::shadow::ShadowLayer;
+use crate::sticky::StickySession;
+use crate::trigger::{self, Trigger};
+use crate::usage::UsageHistory;
+use crate::{accents, hotkey, hover, idle, layout, search};
+
+pub struct App {
+    config: ToolConfig,
+    window_config: WindowConfig,
+    theme: ThemeConfig,
+    idle_timer: Option<idle::IdleTimer>,
+    /// Character whose variants are shown
+    base: char,
+    /// Categories drilled into; the shown groups are scoped to the innermost one
+    categories: CategoryStack,
+    /// Variants of the base character split into groups, in display order
+    groups: Vec<Vec<char>>,
+    /// Groups for a new base that need a taller window, shown once the resize lands
+    /// or `RESIZE_TIMEOUT` after it was requested, whichever comes first
+    pending_groups: Option<(Vec<Vec<char>>, Instant)>,
+    /// Last window size known to the app, updated from resize events
+    window_size: Size,
+    /// Recent picks, newest first
+    ring: ClipboardRing,
+    /// Text picked by the user, typed by `DeliverPicked` once the window is hidden.
+    /// Without a delivery backend it is left here for the caller.
+    picked: Arc<Mutex<Option<String>>>,
+    /// Whether the release of the key that opened the popup has been seen
+    trigger_key_consumed: bool,
+    /// Error shown instead of the picker; the next keypress closes the window
+    banner: Option<String>,
+    /// Variant highlighted by the arrow keys, as a flat index like the number keys
+    hovered_index: Option<usize>,
+    /// Touchpad scrolling not yet turned into a `ScrollNavigation` step
+    scroll_pixels: f32,
+    /// Whether the mouse is over the highlighted variant
+    mouse_hovering: bool,
+    /// Current size factor of the highlighted character, animated towards `hover::HOVER_SCALE`
+    hover_scale: f32,
+    /// Typed search text narrowing the shown variants, see `search::matches`
+    filter_text: String,
+    /// Whether the window is shown, hidden until the next trigger, or closed
+    window_state: WindowState,
+    /// Backend the app delivers picks with itself, after hiding the window
+    delivery: Option<OutputBackend>,
+    /// Settings the app started with; a daemon trigger starts over from them, so
+    /// the options of a forwarded URI hold for one use
+    launch: (ToolConfig, Option<OutputBackend>),
+    /// Runs the output tools for `delivery`
+    runner: Box<dyn CommandRunner>,
+    /// Last text delivered by the app and how, read by `main` for the history and exit code
+    delivered: Arc<Mutex<Option<(String, Delivery)>>>,
+    /// Separates the picks delivered between two dismissals
+    sticky: StickySession,
+    /// Past picks, ranking the variants; shared with `main`, which saves it
+    usage: Arc<Mutex<UsageHistory>>,
+    /// Variants of the locale's language, shown first in their group
+    preferred_variants: &'static [char],
+    /// Language of the locale, e.g. "tr", deciding how variants are uppercased
+    language: Option<String>,
+    /// Variant clicked once with `double_click_to_select`, and when
+    pending_selection: Option<(usize, Instant)>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Any keyboard or mouse input, resets the idle timer
+    InputReceived,
+    IdleTick(Instant),
+    CharacterSelected(char),
+    /// Click on the variant at this flat index; picks it, or with
+    /// `double_click_to_select` only marks it until it is clicked again
+    VariantClicked(usize),
+    /// Periodic check whether the first click of a double click has timed out
+    PendingSelectionExpired(Instant),
+    /// Check whether the global `keyboard_shortcut` was pressed
+    PollHotkey,
+    /// Check for `SingletonDaemon` triggers read from stdin or the control socket
+    PollTriggers,
+    /// Show the variants of a new base character, as if its key was just pressed
+    TriggerForKey(char),
+    KeyReleased,
+    /// Number key shortcut, picks the variant at this flat index
+    IndexSelected(usize),
+    /// Arrow key, moves the highlight within the grid
+    Navigate(layout::Direction),
+    /// Mouse wheel, moves the highlight this many variants right (negative: left),
+    /// wrapping around at the ends
+    ScrollNavigation(i32),
+    /// Touchpad scrolling by this many pixels, up positive; steps once enough has added up
+    ScrolledPixels(f32),
+    /// Enter, picks the highlighted variant, or the top match in compact mode;
+    /// with `passthrough_on_no_match` a filter matching nothing is picked as typed
+    ConfirmHovered,
+    /// The mouse entered the variant at this flat index
+    HoverEntered(usize),
+    HoverLeft,
+    HoverAnimationTick,
+    /// Alt+number shortcut, re-emits the clipboard ring entry at this index
+    RingEntrySelected(usize),
+    /// Show only this category's variants, with a breadcrumb to go back up
+    EnterCategory(Category),
+    /// Tab, drills into the category of the highlighted variant
+    EnterHoveredCategory,
+    /// Leave the innermost category
+    PopCategory,
+    /// Printable key while `search_on_type`, `compact_mode` or `space_autoinsert`
+    /// is on, extends the filter; space ends a trigger word with `space_autoinsert`
+    FilterTyped(char),
+    /// Backspace, removes the last filter character or leaves the innermost category
+    Backspace,
+    /// Escape, clears the filter or closes the popup
+    Escape,
+    /// Close without picking anything
+    Dismissed,
+    /// Deliver the pick once the window is hidden
+    DeliverPicked,
+    WindowResized(Size),
+    /// Periodic check whether the resize for `pending_groups` is overdue
+    ResizeTimedOut(Instant),
+}
+
+/// How long to wait for the compositor to acknowledge a resize before showing
+/// the new content anyway
+pub const RESIZE_TIMEOUT: Duration = Duration::from_millis(250);
+
+impl App {
+    pub fn new(
+        config: AppConfig,
+        window_size: Size,
+        ring: ClipboardRing,
+        picked: Arc<Mutex<Option<String>>>,
+    ) -> Self {
+        let AppConfig {
+            tool: config,
+            window: window_config,
+            theme,
+            ..
+        } = config;
+        let idle_timer = config
+            .auto_close_idle_ms
+            .map(|ms| idle::IdleTimer::new(Duration::from_millis(ms), Instant::now()));
+        let groups = accents::groups_for(
+            config.base_character,
+            config.include_typographic,
+            config.include_ipa,
+        );
+        let window_state = config.window_behavior.initial_state();
+        let sticky = StickySession::new(config.sticky_separator.clone());
+        let launch = (config.clone(), None);
+        App {
+            base: config.base_character,
+            categories: CategoryStack::default(),
+            config,
+            window_config,
+            theme,
+            idle_timer,
+            groups,
+            pending_groups: None,
+            window_size,
+            ring,
+            picked,
+            trigger_key_consumed: false,
+            banner: None,
+            hovered_index: None,
+            scroll_pixels: 0.0,
+            mouse_hovering: false,
+            hover_scale: 1.0,
+            filter_text: String::new(),
+            window_state,
+            delivery: None,
+            launch,
+            runner: Box::new(SystemRunner),
+            delivered: Arc::default(),
+            sticky,
+            usage: Arc::default(),
+            preferred_variants: &[],
+            language: None,
+            pending_selection: None,
+        }
+    }
+
+    /// Show `message` instead of the picker, closing on the next keypress
+    pub fn with_banner(mut self, message: String) -> Self {
+        self.banner = Some(message);
+        self
+    }
+
+    /// Deliver picks with `backend` from within the app once the window is hidden,
+    /// recording each outcome in `delivered`. A failed delivery brings the window
+    /// back with an error banner when it was about to close or stay hidden.
+    pub fn with_delivery(
+        mut self,
+        backend: OutputBackend,
+        delivered: Arc<Mutex<Option<(String, Delivery)>>>,
+    ) -> Self {
+        self.delivery = Some(backend);
+        self.launch.1 = Some(backend);
+        self.delivered = delivered;
+        self
+    }
+
+    /// Run the output tools with `runner` (mockable for tests)
+    pub fn with_runner(mut self, runner: impl CommandRunner + 'static) -> Self {
+        self.runner = Box::new(runner);
+        self
+    }
+
+    /// Rank the variants by `usage` and count each pick in it
+    pub fn with_usage(mut self, usage: Arc<Mutex<UsageHistory>>) -> Self {
+        self.usage = usage;
+        self.groups = self.scoped_groups();
+        self
+    }
+
+    /// Show the variants `locale`'s language uses first, and uppercase them the way
+    /// it does, when `locale_aware` is on
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        if self.config.locale_aware {
+            self.preferred_variants = locale::preferred_variants(locale);
+            self.language = locale::language(locale).map(str::to_string);
+            self.groups = self.scoped_groups();
+        }
+        self
+    }
+
+    pub fn window_state(&self) -> WindowState {
+        self.window_state
+    }
+
+    /// Whether the window only waits for a keypress to close
+    pub fn is_pending_close(&self) -> bool {
+        self.banner.is_some()
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::InputReceived => {
+                if let Some(timer) = self.idle_timer.as_mut() {
+                    timer.reset(Instant::now());
+                }
+                Task::none()
+            }
+            Message::IdleTick(now) => {
+                if self.idle_timer.as_ref().is_some_and(|timer| timer.is_expired(now)) {
+                    eprintln!("Idle timeout reached, closing");
+                    return self.close();
+                }
+                Task::none()
+            }
+            Message::CharacterSelected(ch) => {
+                if let Ok(mut usage) = self.usage.lock() {
+                    usage.record(ch, SystemTime::now());
+                }
+                // A digraph is typed as its plain letters, whatever the representation
+                let text = match accents::uppercase_digraph(self.base, ch, self.language.as_deref()) {
+                    Some(digraph) => digraph.to_string(),
+                    None => self.config.output_representation.render(ch),
+                };
+                if let Ok(mut picked) = self.picked.lock() {
+                    *picked = Some(text);
+                }
+                self.finish(WindowEvent::Picked)
+            }
+            Message::VariantClicked(index) => {
+                if self.config.double_click_to_select {
+                    let now = Instant::now();
+                    let timeout = Duration::from_millis(self.config.double_click_timeout_ms);
+                    let second_click = self.pending_selection.take().is_some_and(|(pending, clicked_at)| {
+                        pending == index && now.duration_since(clicked_at) <= timeout
+                    });
+                    if !second_click {
+                        self.pending_selection = Some((index, now));
+                        return Task::none();
+                    }
+                }
+                match self.visible_variants().get(index) {
+                    Some(&ch) => self.update(Message::CharacterSelected(ch)),
+                    None => Task::none(),
+                }
+            }
+            Message::PollHotkey => {
+                if hotkey::take_pressed() {
+                    return self.update(Message::TriggerForKey(self.config.base_character));
+                }
+                Task::none()
+            }
+            Message::PollTriggers => {
+                // Only the latest of several queued triggers is worth showing
+                let Some(trigger) = trigger::take_pending().pop() else {
+                    return Task::none();
+                };
+                (self.config, self.delivery) = self.launch.clone();
+                let base = match trigger {
+                    Trigger::Show => self.config.base_character,
+                    Trigger::Base(base) => base,
+                    Trigger::Uri(uri) => {
+                        uri.apply(&mut self.config);
+                        if self.delivery.is_some() && (uri.print || uri.output.is_some()) {
+                            self.delivery = self.config.output_backend;
+                        }
+                        self.config.base_character
+                    }
+                };
+                self.update(Message::TriggerForKey(base))
+            }
+            Message::PendingSelectionExpired(now) => {
+                let timeout = Duration::from_millis(self.config.double_click_timeout_ms);
+                if self
+                    .pending_selection
+                    .is_some_and(|(_, clicked_at)| now.duration_since(clicked_at) > timeout)
+                {
+                    self.pending_selection = None;
+                }
+                Task::none()
+            }
+            Message::TriggerForKey(base) => {
+                // Within a category the new base only shows that category
+                self.base = base;
+                self.trigger_key_consumed = false;
+                self.filter_text.clear();
+                self.hovered_index = None;
+                self.pending_selection = None;
+                let switch = self.switch_groups(self.scoped_groups());
+
+                let was_shown = self.window_state == WindowState::Shown;
+                self.window_state = self
+                    .config
+                    .window_behavior
+                    .next_state(self.window_state, WindowEvent::Triggered);
+                if was_shown || self.window_state != WindowState::Shown {
+                    return switch;
+                }
+                let show = window::latest().and_then(|id| {
+                    window::change_mode(id, window::Mode::Windowed).chain(window::gain_focus(id))
+                });
+                switch.chain(show)
+            }
+            Message::EnterCategory(category) => {
+                self.categories.push(category);
+                self.hovered_index = None;
+                self.pending_selection = None;
+                self.switch_groups(self.scoped_groups())
+            }
+            Message::EnterHoveredCategory => {
+                let Some(index) = self.hovered_index else {
+                    return Task::none();
+                };
+                if !self.categories.is_empty() {
+                    return Task::none();
+                }
+                let categorized = self.categorized_groups().into_iter().map(|(category, group)| {
+                    let group = search::filter_groups(&[group], &self.filter_text, self.config.case_insensitive_filter);
+                    (category, group.concat())
+                });
+                let mut start = 0;
+                for (category, group) in categorized {
+                    if index < start + group.len() {
+                        return Task::done(Message::EnterCategory(category));
+                    }
+                    start += group.len();
+                }
+                Task::none()
+            }
+            Message::PopCategory => {
+                if self.categories.pop().is_none() {
+                    return Task::none();
+                }
+                self.hovered_index = None;
+                self.pending_selection = None;
+                self.switch_groups(self.scoped_groups())
+            }
+            Message::FilterTyped(' ') if self.config.space_autoinsert => {
+                // A lone space isn't worth closing the popup for
+                if self.filter_text.is_empty() {
+                    return Task::none();
+                }
+                let typed = format!("{} ", self.filter_text);
+                let Some(text) = search::expand_on_space(&typed) else {
+                    return Task::none();
+                };
+                if let Ok(mut picked) = self.picked.lock() {
+                    *picked = Some(text);
+                }
+                self.finish(WindowEvent::Picked)
+            }
+            Message::FilterTyped(ch) => {
+                if self.accepts_typing() && search::accepts(ch) {
+                    self.filter_text.push(ch);
+                    self.hovered_index = None;
+                    self.pending_selection = None;
+                }
+                Task::none()
+            }
+            Message::Backspace => {
+                if self.filter_text.pop().is_some() {
+                    self.hovered_index = None;
+                    self.pending_selection = None;
+                    return Task::none();
+                }
+                Task::done(Message::PopCategory)
+            }
+            Message::Escape => {
+                if self.filter_text.is_empty() {
+                    return Task::done(Message::Dismissed);
+                }
+                self.filter_text.clear();
+                self.hovered_index = None;
+                self.pending_selection = None;
+                Task::none()
+            }
+            Message::WindowResized(size) => {
+                self.window_size = size;
+                // The taller window is in place, the new content fits now
+                if let Some((groups, _)) = self.pending_groups.take() {
+                    self.groups = groups;
+                }
+                Task::none()
+            }
+            Message::ResizeTimedOut(now) => {
+                // The compositor ignored the resize, show the new content in the window we have
+                match self.pending_groups.take() {
+                    Some((groups, requested)) if now.duration_since(requested) >= RESIZE_TIMEOUT => {
+                        self.groups = groups;
+                    }
+                    pending => self.pending_groups = pending,
+                }
+                Task::none()
+            }
+            Message::IndexSelected(index) => match self.visible_groups().iter().flatten().nth(index) {
+                Some(&ch) => Task::done(Message::CharacterSelected(ch)),
+                None => Task::none(),
+            },
+            Message::Navigate(direction) => {
+                self.navigate(direction);
+                Task::none()
+            }
+            Message::ScrollNavigation(step) => {
+                let count = self.visible_variants().len();
+                self.hovered_index = layout::scroll_step(self.hovered_index, step, count);
+                Task::none()
+            }
+            Message::ScrolledPixels(y) => {
+                self.scroll_pixels += y;
+                let steps = (self.scroll_pixels / layout::SCROLL_STEP_PIXELS).trunc();
+                if steps == 0.0 {
+                    return Task::none();
+                }
+                self.scroll_pixels -= steps * layout::SCROLL_STEP_PIXELS;
+                // Scrolling up moves left, like a wheel notch
+                self.update(Message::ScrollNavigation(-(steps as i32)))
+            }
+            Message::HoverEntered(index) => {
+                if self.hovered_index != Some(index) {
+                    self.hover_scale = 1.0;
+                }
+                self.hovered_index = Some(index);
+                self.mouse_hovering = true;
+                Task::none()
+            }
+            Message::HoverLeft => {
+                self.mouse_hovering = false;
+                Task::none()
+            }
+            Message::HoverAnimationTick => {
+                self.hover_scale = hover::step_scale(self.hover_scale, self.hover_target());
+                Task::none()
+            }
+            Message::ConfirmHovered
+                if self.config.passthrough_on_no_match
+                    && !self.filter_text.is_empty()
+                    && self.visible_variants().is_empty() =>
+            {
+                if let Ok(mut picked) = self.picked.lock() {
+                    *picked = Some(self.filter_text.clone());
+                }
+                self.finish(WindowEvent::Picked)
+            }
+            Message::ConfirmHovered if self.config.compact_mode => match self.top_match() {
+                Some(ch) => self.update(Message::CharacterSelected(ch)),
+                None => Task::none(),
+            },
+            Message::ConfirmHovered => match self.hovered_index {
+                Some(index) => Task::done(Message::IndexSelected(index)),
+                None => Task::none(),
+            },
+            Message::RingEntrySelected(index) => {
+                let Some(entry) = self.ring.get(index) else {
+                    return Task::none();
+                };
+                if let Ok(mut picked) = self.picked.lock() {
+                    *picked = Some(entry.to_string());
+                }
+                self.finish(WindowEvent::Picked)
+            }
+            Message::KeyReleased => {
+                if !self.config.dismiss_on_key_release {
+                    return Task::none();
+                }
+                // The first release is the key that opened the popup
+                if !self.trigger_key_consumed {
+                    self.trigger_key_consumed = true;
+                    return Task::none();
+                }
+                self.update(Message::Dismissed)
+            }
+            Message::Dismissed => self.finish(WindowEvent::Dismissed),
+            Message::DeliverPicked => {
+                let Some(backend) = self.delivery else {
+                    return Task::none();
+                };
+                let Some(text) = self.picked.lock().ok().and_then(|mut picked| picked.take()) else {
+                    return Task::none();
+                };
+                let delivery = backend.deliver(&self.sticky.text_for(&text), self.runner.as_ref());
+                if !matches!(delivery, Delivery::Failed { .. }) {
+                    self.ring.push(text.clone());
+                }
+                let banner = delivery.banner(&text);
+                if let Ok(mut delivered) = self.delivered.lock() {
+                    *delivered = Some((text, delivery));
+                }
+
+                match banner {
+                    // Bring a closed or hidden window back with the error so the pick isn't silently lost
+                    Some(banner) if self.window_state != WindowState::Shown => {
+                        eprintln!("{}", banner);
+                        self.banner = Some(banner);
+                        self.window_state = WindowState::Shown;
+                        window::latest().and_then(|id| window::change_mode(id, window::Mode::Windowed))
+                    }
+                    Some(banner) => {
+                        eprintln!("{}", banner);
+                        Task::none()
+                    }
+                    None if self.window_state == WindowState::Closed => self.close(),
+                    None => Task::none(),
+                }
+            }
+        }
+    }
+
+    /// Close or hide the popup after a pick or dismissal, following `window_behavior`.
+    /// An error banner always closes.
+    fn finish(&mut self, event: WindowEvent) -> Task<Message> {
+        self.window_state = if self.is_pending_close() {
+            WindowState::Closed
+        } else {
+            self.config.window_behavior.next_state(self.window_state, event)
+        };
+
+        // Picks made while the window keeps coming back form one sticky session
+        if event == WindowEvent::Dismissed || self.window_state == WindowState::Closed {
+            self.sticky.reset();
+        }
+
+        match self.window_state {
+            // Typing needs the previously focused app back, so hide before delivering
+            WindowState::Closed if event == WindowEvent::Picked && self.delivery.is_some() => window::latest()
+                .and_then(|id| window::change_mode(id, window::Mode::Hidden))
+                .chain(Task::done(Message::DeliverPicked)),
+            WindowState::Closed => self.close(),
+            WindowState::Hidden => {
+                // The next trigger starts over at the top level
+                self.filter_text.clear();
+                self.categories = CategoryStack::default();
+                self.hovered_index = None;
+                self.pending_selection = None;
+                window::latest()
+                    .and_then(|id| window::change_mode(id, window::Mode::Hidden))
+                    .chain(Task::done(Message::DeliverPicked))
+            }
+            WindowState::Shown => Task::none(),
+        }
+    }
+
+    /// Close the popup following `close_strategy`. Exiting the process is left
+    /// to `main`, after the pick has been delivered.
+    fn close(&self) -> Task<Message> {
+        self.config
+            .close_strategy
+            .steps()
+            .iter()
+            .fold(Task::none(), |task, step| match step {
+                CloseStep::CloseWindow => task.chain(window::latest().and_then(window::close)),
+                CloseStep::ExitRuntime => task.chain(iced::exit()),
+                CloseStep::ExitProcess => task,
+            })
+    }
+
+    /// Replace the shown variants in the existing window, resizing it to fit when
+    /// `auto_resize` is on. Growing keeps the old content until the resize is
+    /// acknowledged (or `RESIZE_TIMEOUT` passed) so the new rows are never drawn
+    /// clipped; shrinking, or keeping the size, shows the new content right away.
+    fn switch_groups(&mut self, groups: Vec<Vec<char>>) -> Task<Message> {
+        if !self.window_config.auto_resize {
+            self.groups = groups;
+            return Task::none();
+        }
+
+        let needed_height = self.required_height(&groups);
+        let current_height = self.window_size.height;
+
+        // No resize is requested, so no resize event will ever come
+        if (needed_height - current_height).abs() < 1.0 {
+            self.pending_groups = None;
+            self.groups = groups;
+            return Task::none();
+        }
+
+        if needed_height > current_height {
+            self.pending_groups = Some((groups, Instant::now()));
+        } else {
+            self.pending_groups = None;
+            self.groups = groups;
+        }
+
+        let size = Size::new(self.window_size.width, needed_height);
+        window::latest().and_then(move |id| window::resize(id, size))
+    }
+
+    /// Groups of the base character with their categories, the locale's and the
+    /// most used variants first
+    fn categorized_groups(&self) -> Vec<(Category, Vec<char>)> {
+        let now = SystemTime::now();
+        accents::categorized_groups_in(
+            self.base,
+            self.config.include_typographic,
+            self.config.include_ipa,
+            self.language.as_deref(),
+        )
+        .into_iter()
+        .map(|(category, mut group)| {
+            locale::boost(&mut group, self.preferred_variants);
+            if let Ok(usage) = self.usage.lock() {
+                usage.rank(&mut group, now, self.config.history_decay_half_life_days);
+            }
+            (category, group)
+        })
+        .collect()
+    }
+
+    /// Groups of the current category, or all of them at the top level
+    fn scoped_groups(&self) -> Vec<Vec<char>> {
+        self.categories.scope(self.categorized_groups())
+    }
+
+    /// Groups as shown, narrowed by the search filter
+    fn visible_groups(&self) -> Vec<Vec<char>> {
+        search::filter_groups(&self.groups, &self.filter_text, self.config.case_insensitive_filter)
+    }
+
+    /// Shown variants in reading order
+    pub fn visible_variants(&self) -> Vec<char> {
+        self.visible_groups().into_iter().flatten().collect()
+    }
+
+    /// Variant Enter inserts in compact mode
+    pub fn top_match(&self) -> Option<char> {
+        search::top_match(&self.groups, &self.filter_text, self.config.case_insensitive_filter)
+    }
+
+    /// Whether typed letters go into the filter
+    fn accepts_typing(&self) -> bool {
+        self.config.search_on_type || self.config.compact_mode || self.config.space_autoinsert
+    }
+
+    /// Flat index of the highlighted variant
+    pub fn hovered_index(&self) -> Option<usize> {
+        self.hovered_index
+    }
+
+    /// Flat index of the variant waiting for its second click
+    pub fn pending_selection(&self) -> Option<usize> {
+        self.pending_selection.map(|(index, _)| index)
+    }
+
+    /// Text typed to filter the variants as typed, before any case folding;
+    /// empty when nothing is filtered
+    pub fn filter_text(&self) -> &str {
+        &self.filter_text
+    }
+
+    /// Breadcrumb of the categories drilled into, None at the top level
+    pub fn breadcrumb(&self) -> Option<String> {
+        self.categories.breadcrumb()
+    }
+
+    /// Current size factor of the highlighted character
+    pub fn hover_scale(&self) -> f32 {
+        self.hover_scale
+    }
+
+    fn hover_target(&self) -> f32 {
+        if self.mouse_hovering {
+            hover::HOVER_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    /// Move the highlight one step, skipping separator cells. The first arrow key
+    /// press highlights the first variant.
+    fn navigate(&mut self, direction: layout::Direction) {
+        let groups = self.visible_groups();
+        let cells = layout::cells(&groups, self.config.separator_character.is_some());
+        let variant_cells: Vec<usize> = cells
+            .iter()
+            .enumerate()
+            .filter_map(|(cell, ch)| ch.map(|_| cell))
+            .collect();
+        if variant_cells.is_empty() {
+            return;
+        }
+        let Some(current) = self.hovered_index else {
+            self.hovered_index = Some(0);
+            return;
+        };
+
+        let (per_row, _) = self.grid_shape(&groups);
+        let mut cell = variant_cells[current.min(variant_cells.len() - 1)];
+        loop {
+            let next = layout::navigate(cell, direction, per_row, cells.len());
+            if next == cell {
+                break;
+            }
+            cell = next;
+            if cells[cell].is_some() {
+                break;
+            }
+        }
+
+        // Stuck on a separator at the edge of the grid leaves the highlight where it was
+        if let Some(index) = variant_cells.iter().position(|&c| c == cell) {
+            self.hovered_index = Some(index);
+        }
+    }
+
+    /// Size factor for the character at flat `index`
+    fn scale_for(&self, index: usize) -> f32 {
+        if self.config.animate_character_on_hover && self.hovered_index == Some(index) {
+            self.hover_scale
+        } else {
+            1.0
+        }
+    }
+
+    /// Width of the popup panel, the window minus the space kept for the shadow
+    fn content_width(&self) -> f32 {
+        self.window_size.width - 2.0 * self.config.shadow_margin()
+    }
+
+    /// Cells per row and number of rows needed for `groups`
+    fn grid_shape(&self, groups: &[Vec<char>]) -> (usize, usize) {
+        let content_width = self.content_width();
+        let h_pad = self.config.horizontal_padding(content_width);
+
+        // Names widen the cells, so fewer fit per row
+        let longest_name = if self.config.show_variant_names {
+            groups
+                .iter()
+                .flatten()
+                .filter_map(|&ch| accents::names::variant_name(ch))
+                .map(|name| name.chars().count())
+                .max()
+        } else {
+            None
+        };
+        // So do code point labels
+        let display = self.config.character_display;
+        let longest_label = groups
+            .iter()
+            .flatten()
+            .map(|&ch| display.label(ch).chars().count())
+            .max()
+            .unwrap_or(1);
+        let cell_width = layout::padded_cell_width(
+            self.config.font_size,
+            longest_label,
+            longest_name.unwrap_or(0),
+            self.theme.cell_padding,
+        );
+        let per_row = self.config.grid_columns.unwrap_or_else(|| {
+            layout::cells_per_row(content_width - 2.0 * h_pad, cell_width, self.theme.cell_spacing)
+        });
+
+        let cells = layout::cell_count(groups, self.config.separator_character.is_some());
+        (per_row, layout::grid_rows(cells, per_row))
+    }
+
+    /// Window height needed to show `groups`, never below the configured height
+    fn required_height(&self, groups: &[Vec<char>]) -> f32 {
+        let (_, rows) = self.grid_shape(groups);
+        let cell_height = self.config.cell_height();
+        let extra_space = self.preview_space() + self.breadcrumb_space() + 2.0 * self.config.shadow_margin();
+        let grid_height = layout::rows_height(rows, cell_height, self.theme.cell_spacing);
+        (grid_height + extra_space).max(self.window_config.height + extra_space)
+    }
+
+    /// Window height needed for the shown variants
+    pub fn required_window_height(&self) -> f32 {
+        self.required_height(&self.groups)
+    }
+
+    /// Height taken by the breadcrumb line, 0 at the top level
+    fn breadcrumb_space(&self) -> f32 {
+        if self.categories.is_empty() {
+            0.0
+        } else {
+            layout::breadcrumb_height()
+        }
+    }
+
+    /// Height taken by the preview area, 0 when the preview is off
+    fn preview_space(&self) -> f32 {
+        if self.config.preview_character {
+            self.window_config.preview_height
+        } else {
+            0.0
+        }
+    }
+
+    pub fn subscription(&self) -> Subscription<Message> {
+        if self.is_pending_close() {
+            return event::listen_with(|event, _status, _window| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed { .. }) => Some(Message::Dismissed),
+                _ => None,
+            });
+        }
+
+        let mut subscriptions = Vec::new();
+
+        if self.idle_timer.is_some() {
+            subscriptions.push(iced::time::every(Duration::from_millis(100)).map(Message::IdleTick));
+            subscriptions.push(event::listen_with(|event, _status, _window| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed { .. })
+                | Event::Mouse(mouse::Event::ButtonPressed(_)) => Some(Message::InputReceived),
+                _ => None,
+            }));
+        }
+
+        // 1-9 pick the variants in reading order, across grid rows;
+        // with Alt they pick from the clipboard ring instead
+        subscriptions.push(event::listen_with(|event, _status, _window| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) => match c.parse::<usize>() {
+                Ok(digit @ 1..=9) if modifiers.alt() => Some(Message::RingEntrySelected(digit - 1)),
+                Ok(digit @ 1..=9) => Some(Message::IndexSelected(digit - 1)),
+                _ => None,
+            },
+            _ => None,
+        }));
+
+        if self.config.keyboard_shortcut.is_some() {
+            subscriptions.push(iced::time::every(hotkey::POLL_INTERVAL).map(|_| Message::PollHotkey));
+        }
+
+        if self.config.window_behavior == WindowBehavior::SingletonDaemon {
+            subscriptions.push(iced::time::every(trigger::POLL_INTERVAL).map(|_| Message::PollTriggers));
+        }
+
+        if self.pending_groups.is_some() {
+            subscriptions.push(iced::time::every(RESIZE_TIMEOUT).map(Message::ResizeTimedOut));
+        }
+
+        if self.pending_selection.is_some() {
+            let timeout = Duration::from_millis(self.config.double_click_timeout_ms);
+            subscriptions.push(iced::time::every(timeout).map(Message::PendingSelectionExpired));
+        }
+
+        // Tick while hovering, and after leaving until the character is back to normal size
+        if self.config.animate_character_on_hover
+            && (self.mouse_hovering || self.hover_scale != self.hover_target())
+        {
+            subscriptions.push(iced::time::every(hover::TICK).map(|_| Message::HoverAnimationTick));
+        }
+
+        subscriptions.push(event::listen_with(|event, _status, _window| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => match named {
+                keyboard::key::Named::ArrowUp => Some(Message::Navigate(layout::Direction::Up)),
+                keyboard::key::Named::ArrowDown => Some(Message::Navigate(layout::Direction::Down)),
+                keyboard::key::Named::ArrowLeft => Some(Message::Navigate(layout::Direction::Left)),
+                keyboard::key::Named::ArrowRight => Some(Message::Navigate(layout::Direction::Right)),
+                keyboard::key::Named::Enter => Some(Message::ConfirmHovered),
+                keyboard::key::Named::Tab => Some(Message::EnterHoveredCategory),
+                keyboard::key::Named::Backspace => Some(Message::Backspace),
+                _ => None,
+            },
+            _ => None,
+        }));
+
+        // The wheel steps through the variants; listeners can't capture, hence two
+        subscriptions.push(if self.config.invert_scroll_direction {
+            event::listen_with(|event, _status, _window| scroll_navigation(event, true))
+        } else {
+            event::listen_with(|event, _status, _window| scroll_navigation(event, false))
+        });
+
+        // Letters narrow the variants; digits stay number key shortcuts
+        if self.accepts_typing() {
+            subscriptions.push(event::listen_with(|event, _status, _window| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Character(c),
+                    modifiers,
+                    ..
+                }) if !modifiers.alt() && !modifiers.control() => {
+                    let mut chars = c.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(ch), None) if search::accepts(ch) => Some(Message::FilterTyped(ch)),
+                        _ => None,
+                    }
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                    ..
+                }) => Some(Message::Escape),
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Space),
+                    ..
+                }) => Some(Message::FilterTyped(' ')),
+                _ => None,
+            }));
+        }
+
+        if self.config.dismiss_on_key_release {
+            subscriptions.push(event::listen_with(|event, _status, _window| match event {
+                Event::Keyboard(keyboard::Event::KeyReleased { .. }) => Some(Message::KeyReleased),
+                _ => None,
+            }));
+        }
+
+        if self.window_config.auto_resize {
+            subscriptions.push(event::listen_with(|event, _status, _window| match event {
+                Event::Window(window::Event::Resized(size)) => Some(Message::WindowResized(size)),
+                _ => None,
+            }));
+        }
+
+        Subscription::batch(subscriptions)
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if let Some(banner) = &self.banner {
+            return self.view_banner(banner);
+        }
+
+        if self.config.compact_mode {
+            return self.view_compact();
+        }
+
+        // Padding scales with the window so the row stays proportionate
+        let h_pad = self.config.horizontal_padding(self.content_width());
+        let groups = self.visible_groups();
+        let (per_row, _) = self.grid_shape(&groups);
+
+        let name_for = |ch: char| {
+            if self.config.show_variant_names {
+                accents::names::variant_name(ch)
+            } else {
+                None
+            }
+        };
+
+        let character_name_for = |ch: char| {
+            if self.config.show_character_name {
+                accents::names::short_name(ch)
+            } else {
+                None
+            }
+        };
+
+        let mut cells: Vec<Element<'_, Message>> = Vec::new();
+        let mut variant_index = 0;
+        for (index, group) in groups.iter().enumerate() {
+            if index > 0 {
+                if let Some(separator) = self.config.separator_character {
+                    cells.push(
+                        text(separator.to_string())
+                            .size(self.config.font_size)
+                            .style(|theme: &Theme| text::Style {
+                                color: Some(theme.extended_palette().background.strong.color),
+                            })
+                            .into(),
+                    );
+                }
+            }
+
+            for &ch in group {
+                let mut label = Column::new()
+                    .push(
+                        text(self.config.character_display.label(ch))
+                            .size(self.config.font_size * self.scale_for(variant_index)),
+                    )
+                    .align_x(Horizontal::Center);
+                if let Some(name) = name_for(ch) {
+                    label = label.push(text(name).size(layout::NAME_FONT_SIZE));
+                }
+                if let Some(name) = character_name_for(ch) {
+                    label = label.push(
+                        text(name)
+                            .size(self.config.character_name_font_size)
+                            .style(|theme: &Theme| text::Style {
+                                color: Some(theme.palette().text.scale_alpha(0.6)),
+                            }),
+                    );
+                }
+                let mut cell = button(label)
+                    .padding(Padding::from([5.0, self.theme.cell_padding]))
+                    .on_press(Message::VariantClicked(variant_index));
+                if self.pending_selection() == Some(variant_index) {
+                    // Marked by a first click, drawn apart from the keyboard highlight
+                    cell = cell.style(|theme: &Theme, status| button::Style {
+                        border: Border::default()
+                            .width(2.0)
+                            .color(theme.extended_palette().success.strong.color)
+                            .rounded(4.0),
+                        ..button::primary(theme, status)
+                    });
+                } else if self.hovered_index == Some(variant_index) {
+                    cell = cell.style(|theme: &Theme, status| button::Style {
+                        border: Border::default()
+                            .width(2.0)
+                            .color(theme.palette().text)
+                            .rounded(4.0),
+                        ..button::primary(theme, status)
+                    });
+                }
+                let cell = mouse_area(cell)
+                    .on_enter(Message::HoverEntered(variant_index))
+                    .on_exit(Message::HoverLeft);
+                cells.push(cell.into());
+                variant_index += 1;
+            }
+        }
+
+        let mut rows: Vec<Element<'_, Message>> = Vec::new();
+        let mut cells = cells.into_iter().peekable();
+        while cells.peek().is_some() {
+            let row = Row::with_children(cells.by_ref().take(per_row))
+                .spacing(self.theme.cell_spacing)
+                .align_y(Vertical::Center);
+            rows.push(row.into());
+        }
+        let grid = Column::with_children(rows)
+            .spacing(self.theme.cell_spacing)
+            .align_x(Horizontal::Center);
+
+        let grid = container(grid)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center)
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill);
+
+        // The preview area is always reserved so the grid doesn't jump when hovering starts
+        let body: Element<'_, Message> = if self.config.preview_character {
+            let hovered = self
+                .hovered_index
+                .and_then(|index| groups.iter().flatten().nth(index));
+            let preview = match hovered {
+                Some(&ch) => text(ch.to_string()).size(self.config.preview_font_size),
+                None => text(""),
+            };
+            Column::new()
+                .push(
+                    container(preview)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center)
+                        .width(iced::Length::Fill)
+                        .height(self.window_config.preview_height),
+                )
+                .push(grid)
+                .into()
+        } else {
+            grid.into()
+        };
+
+        let body: Element<'_, Message> = if self.filter_text.is_empty() {
+            body
+        } else {
+            let filter = container(text(self.filter_text.clone()).size(layout::NAME_FONT_SIZE))
+                .padding(Padding::new(2.0).left(6.0).right(6.0))
+                .style(|theme: &Theme| {
+                    container::Style::default().border(
+                        Border::default()
+                            .width(1.0)
+                            .color(theme.extended_palette().background.strong.color)
+                            .rounded(4.0),
+                    )
+                });
+            Column::new()
+                .push(body)
+                .push(container(filter).align_x(Horizontal::Center).width(iced::Length::Fill))
+                .into()
+        };
+
+        let body: Element<'_, Message> = match self.categories.breadcrumb() {
+            Some(breadcrumb) => Column::new()
+                .push(text(breadcrumb).size(layout::NAME_FONT_SIZE))
+                .push(body)
+                .into(),
+            None => body,
+        };
+
+        let content = container(body)
+            .padding(Padding::new(0.0).left(h_pad).right(h_pad))
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill);
+
+        let radius = self.config.corner_radius();
+        let chrome = self.config.border.map(|config| {
+            let [r, g, b, a] = config.color;
+            Border::default()
+                .width(config.width)
+                .color(Color::from_rgba8(r, g, b, f32::from(a) / 255.0))
+                .rounded(radius)
+        });
+
+        let Some(shadow) = self.config.shadow else {
+            return match chrome {
+                Some(chrome) => content.style(move |_theme: &Theme| container::Style::default().border(chrome)),
+                None => content,
+            }
+            .into();
+        };
+
+        // The window is transparent, so the panel paints its own background over the shadow
+        let panel = content.style(move |theme: &Theme| {
+            container::Style::default()
+                .background(theme.palette().background)
+                .border(chrome.unwrap_or_else(|| border::rounded(radius)))
+        });
+
+        Stack::new()
+            .push(
+                canvas(ShadowLayer::new(shadow, radius))
+                    .width(iced::Length::Fill)
+                    .height(iced::Length::Fill),
+            )
+            .push(container(panel).padding(shadow.margin()))
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill)
+            .into()
+    }
+
+    /// Window background, transparent around the popup when it casts a shadow
+    pub fn style(&self, theme: &Theme) -> theme::Style {
+        let palette = theme.palette();
+        let background_color = if self.config.shadow.is_some() {
+            Color::TRANSPARENT
+        } else {
+            palette.background
+        };
+        theme::Style {
+            background_color,
+            text_color: palette.text,
+        }
+    }
+}
+
+impl App {
+    /// The typed filter and the variant Enter would insert, on one line
+    fn view_compact(&self) -> Element<'_, Message> {
+        let query = text(self.filter_text.clone()).size(self.config.font_size).style(|theme: &Theme| {
+            text::Style {
+                color: Some(theme.extended_palette().background.strong.color),
+            }
+        });
+        let result: Element<'_, Message> = match self.top_match() {
+            Some(ch) => button(text(self.config.character_display.label(ch)).size(self.config.font_size))
+                .on_press(Message::CharacterSelected(ch))
+                .into(),
+            None => text("").size(self.config.font_size).into(),
+        };
+
+        let line = Row::new()
+            .push(query)
+            .push(result)
+            .spacing(self.theme.cell_spacing * 2.0)
+            .align_y(Vertical::Center);
+
+        container(line)
+            .padding(Padding::new(0.0).left(self.config.horizontal_padding(self.content_width())))
+            .align_y(Vertical::Center)
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill)
+            .into()
+    }
+
+    fn view_banner<'a>(&self, banner: &'a str) -> Element<'a, Message> {
+        let message = text(banner)
+            .size(layout::NAME_FONT_SIZE * 1.5)
+            .style(|theme: &Theme| text::Style {
+                color: Some(theme.extended_palette().danger.weak.text),
+            });
+
+        container(message)
+            .padding(self.theme.cell_spacing * 2.0)
+            .style(|theme: &Theme| {
+                container::Style::default().background(theme.extended_palette().danger.weak.color)
+            })
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center)
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill)
+            .into()
+    }
+}
+
+/// One variant step for a vertical wheel event: scrolling up moves left, or
+/// right when `inverted`. Touchpad pixels are added up by the app instead.
+/// Horizontal scrolling is ignored.
+fn scroll_navigation(event: Event, inverted: bool) -> Option<Message> {
+    let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event else {
+        return None;
+    };
+    let y = match delta {
+        mouse::ScrollDelta::Lines { y, .. } => y,
+        mouse::ScrollDelta::Pixels { y, .. } if y != 0.0 => {
+            return Some(Message::ScrolledPixels(if inverted { -y } else { y }));
+        }
+        mouse::ScrollDelta::Pixels { .. } => return None,
+    };
+    if y == 0.0 {
+        return None;
+    }
+    let step = if y > 0.0 { -1 } else { 1 };
+    Some(Message::ScrollNavigation(if inverted { -step } else { step }))
+}
@@ -0,0 +1,54 @@
+use crate::accents::Category;
+
+/// Categories the user drilled into, innermost last. Empty while browsing every
+/// group of the base character.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CategoryStack {
+    entries: Vec<Category>,
+}
+
+impl CategoryStack {
+    /// Drill into `category`; entering the current category again does nothing
+    pub fn push(&mut self, category: Category) {
+        if self.current() != Some(category) {
+            self.entries.push(category);
+        }
+    }
+
+    /// Go up one level, returning the category that was left
+    pub fn pop(&mut self) -> Option<Category> {
+        self.entries.pop()
+    }
+
+    pub fn current(&self) -> Option<Category> {
+        self.entries.last().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Path shown above the variants, e.g. "Typographic ›"
+    pub fn breadcrumb(&self) -> Option<String> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let names: Vec<&str> = self.entries.iter().map(|category| category.name()).collect();
+        Some(format!("{} \u{203a}", names.join(" \u{203a} ")))
+    }
+
+    /// Whether groups of `category` are shown: only the current category once
+    /// drilled in, every category otherwise
+    pub fn in_scope(&self, category: Category) -> bool {
+        self.current().is_none_or(|current| current == category)
+    }
+
+    /// Groups to show out of `groups`
+    pub fn scope(&self, groups: Vec<(Category, Vec<char>)>) -> Vec<Vec<char>> {
+        groups
+            .into_iter()
+            .filter(|(category, _)| self.in_scope(*category))
+            .map(|(_, group)| group)
+            .collect()
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::test_output::TestOutputMode;
+use crate::uri::AccentUri;
+
+pub const USAGE: &str = "\
+Usage: quick-accent [--config PATH] [COMMAND | URI]
+
+Options:
+  --config PATH    Use this config file (overrides $QUICK_ACCENT_CONFIG)
+
+Commands:
+  (none)           Show the accent picker
+  config upgrade   Add new config fields with their defaults, keeping your values
+  self-test        Check config, parsers and backend selection without a display
+  test-output [--mode type|clipboard]
+                   Output a marker string to check that the output backend works
+  --record-env FILE
+                   Record what screen detection sees and the resulting geometry,
+                   for attaching to a bug report
+  --replay FILE --dry-run
+                   Rerun detection against a recording and print the geometry
+
+URI:
+  accent:BASE[?KEY=VALUE&...]
+                   Show the picker for BASE (percent-encoded), with keys
+                   uppercase=1|0, set=accents|typographic|ipa|all,
+                   output=wtype|xdotool|stdout|wl-copy|xclip, print=1,
+                   as=glyph|html|latex
+                   A running SingletonDaemon shows it instead of a new window
+";
+
+/// Exit status when the picked text could not be typed into the focused app,
+/// whether or not it was copied to the clipboard instead
+pub const EXIT_INJECTION_FAILED: i32 = 4;
+
+/// Exit status for invalid command line arguments
+pub const EXIT_USAGE: i32 = 2;
+
+/// Exit status for a malformed `accent:` URI, so launchers can tell it apart from
+/// usage errors and failed injection
+pub const EXIT_INVALID_URI: i32 = 3;
+
+/// What the binary was asked to do
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Show the picker (default)
+    Show,
+    /// `config upgrade`
+    ConfigUpgrade,
+    /// `self-test`
+    SelfTest,
+    /// `test-output [--mode type|clipboard]`
+    TestOutput(TestOutputMode),
+    /// `--record-env FILE`
+    RecordEnv(PathBuf),
+    /// `--replay FILE --dry-run`
+    Replay(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cli {
+    pub command: Command,
+    /// `--config PATH`
+    pub config_path: Option<PathBuf>,
+    /// `accent:` URI, still unparsed so that a malformed one gets its own exit status
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliError(pub String);
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl Cli {
+    /// Parse arguments, excluding the program name
+    pub fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let args: Vec<String> = args.into_iter().map(Into::into).collect();
+        let mut config_path = None;
+        let mut uri = None;
+        let mut positional = Vec::new();
+        let mut iter = args.iter().map(String::as_str);
+        while let Some(arg) = iter.next() {
+            if arg == "--config" {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| CliError("--config needs a path".to_string()))?;
+                config_path = Some(PathBuf::from(path));
+            } else if let Some(path) = arg.strip_prefix("--config=") {
+                config_path = Some(PathBuf::from(path));
+            } else if AccentUri::is_uri(arg) {
+                uri = Some(arg.to_string());
+            } else {
+                positional.push(arg);
+            }
+        }
+        let args = positional;
+
+        if uri.is_some() && !args.is_empty() {
+            return Err(CliError("A URI can't be combined with a command".to_string()));
+        }
+
+        let command = match args.as_slice() {
+            [] => Command::Show,
+            ["config", "upgrade"] => Command::ConfigUpgrade,
+            ["self-test"] => Command::SelfTest,
+            ["test-output"] => Command::TestOutput(TestOutputMode::Type),
+            ["test-output", "--mode", mode] => match TestOutputMode::parse(mode) {
+                Some(mode) => Command::TestOutput(mode),
+                None => return Err(CliError(format!("Unknown test-output mode: {}", mode))),
+            },
+            ["test-output", other, ..] => {
+                return Err(CliError(format!("Unknown test-output argument: {}", other)));
+            }
+            ["--record-env", path] => Command::RecordEnv(PathBuf::from(path)),
+            ["--record-env"] => return Err(CliError("--record-env needs a file".to_string())),
+            ["--replay", path, "--dry-run"] | ["--dry-run", "--replay", path] => {
+                Command::Replay(PathBuf::from(path))
+            }
+            ["--replay", ..] => {
+                return Err(CliError(
+                    "--replay only recomputes the geometry, run it as --replay FILE --dry-run".to_string(),
+                ));
+            }
+            ["config", other, ..] => {
+                return Err(CliError(format!("Unknown config command: {}", other)));
+            }
+            [other, ..] => return Err(CliError(format!("Unknown argument: {}", other))),
+        };
+
+        Ok(Cli {
+            command,
+            config_path,
+            uri,
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The last picks, newest first, kept between runs so they can be re-emitted
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClipboardRing {
+    capacity: usize,
+    entries: VecDeque<String>,
+}
+
+impl ClipboardRing {
+    /// Empty ring holding at most `capacity` entries (0 keeps nothing)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Add a pick as the newest entry, evicting the oldest one when full.
+    /// Picking something already in the ring moves it to the front instead of duplicating it.
+    pub fn push(&mut self, text: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|entry| *entry != text);
+        self.entries.push_front(text);
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Entry at `index`, where 0 is the most recent pick
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries from newest to oldest
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(String::as_str)
+    }
+
+    /// `$XDG_DATA_HOME/quick-accent/history.ron`, falling back to
+    /// `~/.local/share/quick-accent/history.ron`. Independent of the config path,
+    /// so configs picked with `--config` or `QUICK_ACCENT_CONFIG` share one history.
+    pub fn default_path() -> PathBuf {
+        let data_dir = if let Ok(xdg_data) = std::env::var("XDG_DATA_HOME") {
+            PathBuf::from(xdg_data)
+        } else if let Ok(home) = std::env::var("HOME") {
+            PathBuf::from(home).join(".local").join("share")
+        } else {
+            PathBuf::from(".")
+        };
+        data_dir.join("quick-accent").join("history.ron")
+    }
+
+    /// Load the ring from `path`, starting empty when the file does not exist yet.
+    /// Entries beyond `capacity` (e.g. after lowering the setting) are dropped.
+    pub fn load_from_path<P: AsRef<Path>>(
+        path: P,
+        capacity: usize,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let mut ring = Self::new(capacity);
+        if !path.exists() {
+            return Ok(ring);
+        }
+
+        let content = fs::read_to_string(path)?;
+        let entries: Vec<String> = ron::from_str(&content)?;
+        ring.entries = entries.into_iter().take(capacity).collect();
+        Ok(ring)
+    }
+
+    /// Write the ring to `path`, newest entry first
+    pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let entries: Vec<&String> = self.entries.iter().collect();
+        let content = ron::ser::to_string_pretty(&entries, ron::ser::PrettyConfig::default())?;
+
+        // Same write-then-rename as the config so a crash never truncates the history
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
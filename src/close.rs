@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// How the popup goes away once it is done
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum CloseStrategy {
+    /// Stop the iced runtime and let it tear the window down
+    #[default]
+    Exit,
+    /// Close the window explicitly first, then stop the runtime
+    CloseThenExit,
+    /// Close the window and stop the runtime, then end the process right after
+    /// output instead of returning from `main`, for compositors that keep the
+    /// window or process around
+    ProcessExit,
+}
+
+/// One step of closing, in the order they run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseStep {
+    /// `window::close` on the popup
+    CloseWindow,
+    /// `iced::exit`
+    ExitRuntime,
+    /// `std::process::exit` once the pick has been delivered
+    ExitProcess,
+}
+
+impl CloseStrategy {
+    pub fn steps(self) -> &'static [CloseStep] {
+        match self {
+            CloseStrategy::Exit => &[CloseStep::ExitRuntime],
+            CloseStrategy::CloseThenExit => &[CloseStep::CloseWindow, CloseStep::ExitRuntime],
+            CloseStrategy::ProcessExit => &[
+                CloseStep::CloseWindow,
+                CloseStep::ExitRuntime,
+                CloseStep::ExitProcess,
+            ],
+        }
+    }
+
+    /// Whether `main` should end the process right after delivering the pick
+    pub fn exits_process(self) -> bool {
+        self.steps().contains(&CloseStep::ExitProcess)
+    }
+}
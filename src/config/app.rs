@@ -1,17 +1,27 @@
-use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use serde::{Deserialize, Serialize};
+
+use crate::config::error::{ConfigError, Warning};
 use crate::config::screen::ScreenConfig;
+use crate::config::theme::ThemeConfig;
 use crate::config::tool::ToolConfig;
 use crate::config::window::WindowConfig;
+use crate::geometry::Rect;
+use crate::hotkey::Shortcut;
+use crate::screen::ScreenInfo;
+use crate::{accents, layout};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
 pub struct AppConfig {
     pub window: WindowConfig,
     pub screen: ScreenConfig,
     /// Application text content
     pub tool: ToolConfig,
+    /// Look of the character grid
+    pub theme: ThemeConfig,
 }
 
 impl Default for AppConfig {
@@ -20,6 +30,7 @@ impl Default for AppConfig {
             window: WindowConfig::default(),
             screen: ScreenConfig::default(),
             tool: ToolConfig::default(),
+            theme: ThemeConfig::default(),
         }
     }
 }
@@ -27,6 +38,11 @@ impl Default for AppConfig {
 impl AppConfig {
     /// Get the default config file path
     pub fn default_config_path() -> PathBuf {
+        // An explicit override wins, e.g. for per-alias configs
+        if let Some(path) = std::env::var_os("QUICK_ACCENT_CONFIG").filter(|path| !path.is_empty()) {
+            return PathBuf::from(path);
+        }
+
         // Try XDG config directory first, then fall back to home directory
         if let Ok(xdg_config) = std::env::var("XDG_CONFIG_HOME") {
             PathBuf::from(xdg_config)
@@ -69,6 +85,95 @@ impl AppConfig {
         Ok(config)
     }
 
+    /// Check that values are within their allowed ranges. Values that are allowed
+    /// but look like mistakes come back as warnings.
+    pub fn validate(&self) -> Result<Vec<Warning>, ConfigError> {
+        if self.tool.grid_columns == Some(0) {
+            return Err(ConfigError::InvalidValue {
+                field: "tool.grid_columns",
+                reason: "must be at least 1".to_string(),
+            });
+        }
+
+        let fraction = self.tool.horizontal_padding_fraction;
+        if !(0.0..0.5).contains(&fraction) {
+            return Err(ConfigError::InvalidValue {
+                field: "tool.horizontal_padding_fraction",
+                reason: format!("{} is not in [0.0, 0.5)", fraction),
+            });
+        }
+
+        if self.tool.double_click_timeout_ms == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "tool.double_click_timeout_ms",
+                reason: "must be at least 1".to_string(),
+            });
+        }
+
+        if let Some(shortcut) = &self.tool.keyboard_shortcut {
+            if let Err(e) = shortcut.parse::<Shortcut>() {
+                return Err(ConfigError::InvalidValue {
+                    field: "tool.keyboard_shortcut",
+                    reason: e.to_string(),
+                });
+            }
+        }
+
+        let half_life = self.tool.history_decay_half_life_days;
+        if half_life <= 0.0 || !half_life.is_finite() {
+            return Err(ConfigError::InvalidValue {
+                field: "tool.history_decay_half_life_days",
+                reason: format!("{} is not a positive number of days", half_life),
+            });
+        }
+
+        for (field, value) in [
+            ("theme.cell_padding", self.theme.cell_padding),
+            ("theme.cell_spacing", self.theme.cell_spacing),
+        ] {
+            if value < 0.0 || value.is_nan() {
+                return Err(ConfigError::InvalidValue {
+                    field,
+                    reason: format!("{} is not a non-negative number", value),
+                });
+            }
+        }
+
+        // The name line makes cells taller, a single row must still fit
+        let row_height = layout::rows_height(1, self.tool.cell_height(), self.theme.cell_spacing);
+        if self.tool.show_character_name && self.window.height < row_height {
+            return Err(ConfigError::InvalidValue {
+                field: "window.height",
+                reason: format!(
+                    "{} is too small to show character names, needs at least {}",
+                    self.window.height, row_height
+                ),
+            });
+        }
+
+        let mut warnings = Vec::new();
+        if self.window.width_fraction > 0.95 {
+            warnings.push(Warning {
+                field: "window.width_fraction",
+                reason: format!("{} leaves almost no room beside the popup", self.window.width_fraction),
+            });
+        }
+        if self.tool.font_size > 128.0 {
+            warnings.push(Warning {
+                field: "tool.font_size",
+                reason: format!("{} fits only a few characters per row", self.tool.font_size),
+            });
+        }
+        if let Some(ms) = self.tool.auto_close_idle_ms.filter(|&ms| ms < 500) {
+            warnings.push(Warning {
+                field: "tool.auto_close_idle_ms",
+                reason: format!("{} ms closes the popup before there is time to pick", ms),
+            });
+        }
+
+        Ok(warnings)
+    }
+
     /// Save configuration to default path
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
         let config_path = Self::default_config_path();
@@ -85,33 +190,184 @@ impl AppConfig {
         }
 
         let config_content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
-        fs::write(path, config_content)?;
+
+        // Write to a sibling file and rename so a crash never leaves a half-written config
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, config_content)?;
+        fs::rename(&tmp_path, path)?;
         eprintln!("Saved config to {:?}", path);
         Ok(())
     }
 
+    /// Add fields missing from the config file with their default values and write it back,
+    /// keeping existing values. Returns the dotted paths of the added fields.
+    pub fn upgrade_file<P: AsRef<Path>>(path: P) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let config_content = fs::read_to_string(path)?;
+
+        // Missing fields are filled from Default via #[serde(default)]
+        let existing: ron::Value = ron::from_str(&config_content)?;
+        let config: AppConfig = ron::from_str(&config_content)?;
+        let complete: ron::Value = ron::from_str(&ron::to_string(&config)?)?;
+
+        let mut added = Vec::new();
+        collect_missing_fields(&existing, &complete, "", &mut added);
+
+        if !added.is_empty() {
+            config.save_to_path(path)?;
+        }
+        Ok(added)
+    }
+
     /// Calculate window dimensions based on screen size and config
     pub fn calculate_window_size(&self, screen_width: f32, screen_height: f32) -> (f32, f32) {
-        let window_width = screen_width * self.window.width_fraction;
-        let window_height = self.window.height;
+        // The shadow is drawn inside the window, around the popup
+        let shadow_space = 2.0 * self.tool.shadow_margin();
+        let window_width = screen_width * self.window.width_fraction + shadow_space;
+        let window_height = self.window.height + self.preview_space() + shadow_space;
         (window_width, window_height)
     }
 
-    /// Calculate window position based on screen size and config
+    /// Height taken by the preview area, 0 when the preview is off
+    pub fn preview_space(&self) -> f32 {
+        if self.tool.preview_character {
+            self.window.preview_height
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculate window dimensions for a grid of `cell_count` cells. Without
+    /// `grid_columns` this is the same as `calculate_window_size`; with it the
+    /// height grows to fit every row, never dropping below `window.height`.
+    pub fn calculate_grid_window_size(
+        &self,
+        screen_width: f32,
+        screen_height: f32,
+        cell_count: usize,
+    ) -> (f32, f32) {
+        let (window_width, window_height) = self.calculate_window_size(screen_width, screen_height);
+        let Some(columns) = self.tool.grid_columns else {
+            return (window_width, window_height);
+        };
+
+        let rows = layout::grid_rows(cell_count, columns);
+        let grid_height = layout::rows_height(rows, self.tool.cell_height(), self.theme.cell_spacing)
+            + self.preview_space()
+            + 2.0 * self.tool.shadow_margin();
+        (window_width, window_height.max(grid_height))
+    }
+
+    /// Calculate window position based on screen size and config. With
+    /// `popup_offset` and a known `cursor` (relative to the screen) the popup
+    /// opens at the cursor moved by the offset instead.
     pub fn calculate_window_position(
         &self,
         screen_width: f32,
         screen_height: f32,
         window_width: f32,
+        cursor: Option<(f32, f32)>,
     ) -> (f32, f32) {
+        // Keep the popup itself where it would be without a shadow
+        let shadow_margin = self.tool.shadow_margin();
+
+        if let (Some((offset_x, offset_y)), Some((cursor_x, cursor_y))) = (self.tool.popup_offset, cursor) {
+            return (cursor_x + offset_x - shadow_margin, cursor_y + offset_y - shadow_margin);
+        }
+
         let x_position = if self.window.center_horizontally {
             (screen_width - window_width) / 2.0
         } else {
-            self.window.x_offset
+            self.window.x_offset - shadow_margin
         };
 
-        let y_position = screen_height * self.window.y_position_fraction;
+        let y_position = screen_height * self.window.y_position_fraction - shadow_margin;
 
         (x_position, y_position)
     }
+
+    /// Window placement on `screen` for the configured base character: sized to its
+    /// variants, positioned on the monitor and clamped onto it
+    pub fn window_rect(&self, screen: &ScreenInfo) -> Rect {
+        let groups = accents::groups_for(
+            self.tool.base_character,
+            self.tool.include_typographic,
+            self.tool.include_ipa,
+        );
+        let cell_count = layout::cell_count(&groups, self.tool.separator_character.is_some());
+        let (width, height) = self.calculate_grid_window_size(screen.width, screen.height, cell_count);
+        let cursor = screen.cursor_on_screen();
+        let (x, y) = self.calculate_window_position(screen.width, screen.height, width, cursor);
+
+        // Make sure the window actually lands on the selected monitor
+        self.ensure_visible(
+            Rect::new(screen.x + x, screen.y + y, width, height),
+            Rect::new(screen.x, screen.y, screen.width, screen.height),
+        )
+    }
+
+    /// Clamp the window back onto `monitor` when too little of it would be visible
+    pub fn ensure_visible(&self, window: Rect, monitor: Rect) -> Rect {
+        if self.window.allow_offscreen {
+            return window;
+        }
+
+        let visible = window.visible_fraction_in(&monitor);
+        if visible >= self.window.min_visible_fraction.clamp(0.0, 1.0) {
+            return window;
+        }
+
+        let mut offending = Vec::new();
+        if window.x < monitor.x || window.right() > monitor.right() {
+            if self.window.center_horizontally {
+                offending.push("window.width_fraction");
+            } else {
+                offending.push("window.x_offset");
+            }
+        }
+        if window.y < monitor.y || window.bottom() > monitor.bottom() {
+            offending.push("window.y_position_fraction");
+            offending.push("window.height");
+        }
+
+        let clamped = window.clamped_into(&monitor);
+        eprintln!(
+            "Warning: only {:.0}% of the window would be visible (check {}), moving it from ({}, {}) to ({}, {})",
+            visible * 100.0,
+            offending.join(", "),
+            window.x,
+            window.y,
+            clamped.x,
+            clamped.y
+        );
+        clamped
+    }
+}
+
+/// Record the dotted path of every field present in `complete` but not in `existing`
+fn collect_missing_fields(
+    existing: &ron::Value,
+    complete: &ron::Value,
+    prefix: &str,
+    added: &mut Vec<String>,
+) {
+    let (ron::Value::Map(existing), ron::Value::Map(complete)) = (existing, complete) else {
+        return;
+    };
+
+    for (key, value) in complete.iter() {
+        let ron::Value::String(name) = key else {
+            continue;
+        };
+        let field_path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+
+        match existing.get(key) {
+            Some(existing_value) => collect_missing_fields(existing_value, value, &field_path, added),
+            None => added.push(field_path),
+        }
+    }
 }
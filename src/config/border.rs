@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct BorderConfig {
+    /// Border width in pixels, drawn inside the popup
+    pub width: f32,
+    /// Border color as RGBA
+    pub color: [u8; 4],
+    /// Corner radius of the popup, also used for its shadow
+    pub radius: f32,
+}
+
+impl Default for BorderConfig {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            color: [128, 128, 128, 255],
+            radius: 8.0,
+        }
+    }
+}
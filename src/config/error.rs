@@ -0,0 +1,32 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    /// A field holds a value outside its allowed range
+    InvalidValue { field: &'static str, reason: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidValue { field, reason } => {
+                write!(f, "Invalid value for `{}`: {}", field, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A value that is allowed but probably not what the user meant
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub field: &'static str,
+    pub reason: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Suspicious value for `{}`: {}", self.field, self.reason)
+    }
+}
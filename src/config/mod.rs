@@ -1,4 +1,8 @@
 pub mod screen;
 pub mod window;
 pub mod app;
-pub mod tool;
\ No newline at end of file
+pub mod tool;
+pub mod error;
+pub mod shadow;
+pub mod border;
+pub mod theme;
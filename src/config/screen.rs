@@ -1,13 +1,31 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
 pub struct ScreenConfig {
-    /// Prefer focused screen over primary screen
+    /// Prefer focused screen over primary screen; when off the primary monitor
+    /// is detected first and focus is only a fallback
     pub prefer_focused_screen: bool,
     /// Fallback to X11 when Wayland fails
     pub allow_x11_fallback: bool,
     /// Debug screen detection
     pub debug_screen_detection: bool,
+    /// Use this resolution and skip detection entirely
+    pub fixed_resolution: Option<(f32, f32)>,
+    /// Read the physical screen size from EDID and report it with the real DPI in the
+    /// detection output; the window is still sized from the resolution
+    pub detect_physical_size: bool,
+    /// Run `xrandr --verbose --current`, for xrandr builds whose short output doesn't parse
+    pub xrandr_verbose: bool,
+    /// Always open on the monitor with this connector or display name, e.g. "DP-1",
+    /// ignoring focus and primary (falls back to normal detection when not connected)
+    pub force_monitor: Option<String>,
+    /// Detect the screen on a background thread while the rest of startup runs
+    pub prewarm_detection: bool,
+    /// Smallest believable screen width; a backend reporting less is skipped
+    pub min_width: f32,
+    /// Smallest believable screen height; a backend reporting less is skipped
+    pub min_height: f32,
 }
 
 impl Default for ScreenConfig {
@@ -16,6 +34,13 @@ impl Default for ScreenConfig {
             prefer_focused_screen: true,
             allow_x11_fallback: true,
             debug_screen_detection: true,
+            fixed_resolution: None,
+            detect_physical_size: false,
+            xrandr_verbose: false,
+            force_monitor: None,
+            prewarm_detection: false,
+            min_width: 0.0,
+            min_height: 0.0,
         }
     }
 }
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct ShadowConfig {
+    /// Horizontal shadow offset in pixels (positive moves it right)
+    pub offset_x: f32,
+    /// Vertical shadow offset in pixels (positive moves it down)
+    pub offset_y: f32,
+    /// How far the shadow fades out around the popup
+    pub blur_radius: f32,
+    /// Shadow color as RGBA
+    pub color: [u8; 4],
+}
+
+impl Default for ShadowConfig {
+    fn default() -> Self {
+        Self {
+            offset_x: 0.0,
+            offset_y: 4.0,
+            blur_radius: 12.0,
+            color: [0, 0, 0, 96],
+        }
+    }
+}
+
+impl ShadowConfig {
+    /// Space the window needs on each side of the popup to draw the shadow
+    pub fn margin(&self) -> f32 {
+        self.blur_radius.max(0.0)
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::layout;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// Padding left and right of the character inside each cell, in pixels
+    pub cell_padding: f32,
+    /// Gap between neighbouring cells, both within a row and between rows
+    pub cell_spacing: f32,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            cell_padding: layout::CELL_PADDING,
+            cell_spacing: layout::CELL_SPACING,
+        }
+    }
+}
@@ -1,15 +1,256 @@
 use serde::{Deserialize, Serialize};
 
+use crate::close::CloseStrategy;
+use crate::config::border::BorderConfig;
+use crate::config::shadow::ShadowConfig;
+use crate::output::{OutputBackend, OutputRepresentation};
+use crate::{layout, shadow};
+
+/// What happens to the popup window between uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum WindowBehavior {
+    /// Close after every pick or dismissal; each trigger launches a new process
+    #[default]
+    CloseAndReopenEachTime,
+    /// Hide after a pick or dismissal and show the same window again on the next trigger
+    HideAndShow,
+    /// Run in the background with a hidden window, shown on each trigger line read
+    /// from stdin (see `trigger::Trigger`) or press of `keyboard_shortcut`
+    SingletonDaemon,
+}
+
+/// Whether the popup window is on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowState {
+    Shown,
+    Hidden,
+    /// Gone for good, the runtime is stopping
+    Closed,
+}
+
+/// Something that moves the popup window between states
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowEvent {
+    /// A key asked for the picker
+    Triggered,
+    /// A character was picked
+    Picked,
+    /// Closed without picking
+    Dismissed,
+}
+
+impl WindowBehavior {
+    /// `HideAndShow` is launched by the trigger it answers, so it opens shown and
+    /// only hides between uses. `SingletonDaemon` is started ahead of time and
+    /// waits hidden for its first trigger.
+    pub fn initial_state(self) -> WindowState {
+        match self {
+            WindowBehavior::CloseAndReopenEachTime | WindowBehavior::HideAndShow => WindowState::Shown,
+            WindowBehavior::SingletonDaemon => WindowState::Hidden,
+        }
+    }
+
+    /// State after `event` in `state`; a closed window stays closed
+    pub fn next_state(self, state: WindowState, event: WindowEvent) -> WindowState {
+        match (state, event) {
+            (WindowState::Closed, _) => WindowState::Closed,
+            (_, WindowEvent::Triggered) => WindowState::Shown,
+            (_, WindowEvent::Picked | WindowEvent::Dismissed) => match self {
+                WindowBehavior::CloseAndReopenEachTime => WindowState::Closed,
+                WindowBehavior::HideAndShow | WindowBehavior::SingletonDaemon => WindowState::Hidden,
+            },
+        }
+    }
+
+    /// Whether the process outlives a pick, so the app delivers it itself
+    pub fn keeps_window(self) -> bool {
+        self != WindowBehavior::CloseAndReopenEachTime
+    }
+}
+
+/// How each character is labelled in the popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum CharacterDisplay {
+    /// The character itself
+    #[default]
+    Glyph,
+    /// Hex code point, e.g. "00E9"; shows invisible characters too
+    HexCodepoint,
+    /// Decimal code point, e.g. "233"
+    DecCodepoint,
+    /// Numeric HTML entity, e.g. "&#233;"
+    HtmlEntity,
+}
+
+impl CharacterDisplay {
+    pub fn label(self, ch: char) -> String {
+        match self {
+            CharacterDisplay::Glyph => ch.to_string(),
+            CharacterDisplay::HexCodepoint => format!("{:04X}", ch as u32),
+            CharacterDisplay::DecCodepoint => format!("{}", ch as u32),
+            CharacterDisplay::HtmlEntity => format!("&#{};", ch as u32),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
 pub struct ToolConfig {
     /// Prefer focused screen over primary screen
     pub test_key: bool,
+    /// Close the picker after this many milliseconds without input (None disables)
+    pub auto_close_idle_ms: Option<u64>,
+    /// Base character whose accented variants are shown
+    pub base_character: char,
+    /// Font size of the characters in the row
+    pub font_size: f32,
+    /// Label the buttons with the character or its code point, for debugging character maps
+    pub character_display: CharacterDisplay,
+    /// Horizontal padding of the row as fraction of window width (0.0 to 0.5)
+    pub horizontal_padding_fraction: f32,
+    /// Show a short name (e.g. "e acute") under each variant
+    pub show_variant_names: bool,
+    /// Show one word of the Unicode name (e.g. "ACUTE") under each character
+    pub show_character_name: bool,
+    /// Font size of the Unicode name line
+    pub character_name_font_size: f32,
+    /// Also show typographic symbols (©, €, «, ...) for the base character
+    pub include_typographic: bool,
+    /// Also show IPA letters (ə, ʃ, ŋ, ...) for the base character
+    pub include_ipa: bool,
+    /// Unclickable separator shown between the character groups
+    pub separator_character: Option<char>,
+    /// How to deliver the picked character (None picks one for the session)
+    pub output_backend: Option<OutputBackend>,
+    /// Emit the character itself, or its HTML entity or LaTeX command
+    pub output_representation: OutputRepresentation,
+    /// Close the popup when a key is released, after the key that opened it
+    pub dismiss_on_key_release: bool,
+    /// Lay the variants out in a grid with this many columns (None fills rows to the window width)
+    pub grid_columns: Option<usize>,
+    /// Remember this many recent picks, re-emitted with Alt+1..9 (0 disables the history)
+    pub clipboard_ring_size: usize,
+    /// Use a single selected character (primary selection) as the base character
+    pub use_primary_selection: bool,
+    /// How the popup closes after a pick or dismissal
+    pub close_strategy: CloseStrategy,
+    /// Whether the popup closes or only hides between uses
+    pub window_behavior: WindowBehavior,
+    /// Drop shadow under the popup (None draws no shadow). Needs a compositor
+    /// that supports transparent windows, it is turned off otherwise.
+    pub shadow: Option<ShadowConfig>,
+    /// Border around the popup, for compositors without server-side decorations
+    pub border: Option<BorderConfig>,
+    /// Grow the character under the mouse slightly, like a magnifier
+    pub animate_character_on_hover: bool,
+    /// Show the hovered character enlarged in a preview area above the variants
+    pub preview_character: bool,
+    /// Font size of the character in the preview area
+    pub preview_font_size: f32,
+    /// Typing letters filters the shown variants; Backspace and Escape edit and clear the filter
+    pub search_on_type: bool,
+    /// Typed filter text matches variants in either case, e.g. "A" keeps à and À
+    pub case_insensitive_filter: bool,
+    /// Show only the typed filter and its best match on one line; Enter inserts the match
+    pub compact_mode: bool,
+    /// Typing a trigger word like "eacute" and then space inserts é and the space;
+    /// text that isn't a trigger word is inserted as typed. Digits stay number key
+    /// shortcuts, so trigger words are letters only, e.g. "frac12" can't be typed.
+    pub space_autoinsert: bool,
+    /// Show the variants the system locale's language uses first, e.g. é, è and à for fr_FR,
+    /// and uppercase them the way it does, e.g. İ in the Turkish I row or IJ in Dutch.
+    /// Without it the Shift/Caps rows use plain `to_uppercase`.
+    pub locale_aware: bool,
+    /// Scrolling up moves the highlight right instead of left, like natural scrolling on a trackpad
+    pub invert_scroll_direction: bool,
+    /// Clicking a character only marks it; a second click on it inserts it.
+    /// Keyboard picks are unaffected.
+    pub double_click_to_select: bool,
+    /// Longest gap between the two clicks of `double_click_to_select`
+    pub double_click_timeout_ms: u64,
+    /// Inserted before each pick after the first within a sticky session, e.g. " " or ",".
+    /// A session lasts while the window hides between picks, until a dismissal.
+    pub sticky_separator: String,
+    /// Global shortcut that shows the popup again, e.g. "Super+Grave" or "Ctrl+Alt+A".
+    /// Needs the `global-hotkey` feature; the window then hides between uses.
+    pub keyboard_shortcut: Option<String>,
+    /// Enter with a typed filter that matches nothing inserts the typed text itself
+    pub passthrough_on_no_match: bool,
+    /// Open the popup at the mouse pointer moved by this offset instead of at the
+    /// configured screen position (None keeps the screen position)
+    pub popup_offset: Option<(f32, f32)>,
+    /// Days after which a past pick counts half as much when ranking by usage
+    pub history_decay_half_life_days: f32,
 }
 
 impl Default for ToolConfig {
     fn default() -> Self {
         Self {
             test_key: true,
+            auto_close_idle_ms: None,
+            base_character: 'e',
+            font_size: 32.0,
+            character_display: CharacterDisplay::Glyph,
+            horizontal_padding_fraction: 0.05,
+            show_variant_names: false,
+            show_character_name: false,
+            character_name_font_size: 8.0,
+            include_typographic: false,
+            include_ipa: false,
+            separator_character: None,
+            output_backend: None,
+            output_representation: OutputRepresentation::Glyph,
+            dismiss_on_key_release: false,
+            grid_columns: None,
+            clipboard_ring_size: 0,
+            use_primary_selection: false,
+            close_strategy: CloseStrategy::Exit,
+            window_behavior: WindowBehavior::CloseAndReopenEachTime,
+            shadow: None,
+            border: None,
+            animate_character_on_hover: false,
+            preview_character: false,
+            preview_font_size: 72.0,
+            search_on_type: false,
+            case_insensitive_filter: true,
+            compact_mode: false,
+            space_autoinsert: false,
+            locale_aware: false,
+            invert_scroll_direction: false,
+            double_click_to_select: false,
+            double_click_timeout_ms: 400,
+            sticky_separator: String::new(),
+            keyboard_shortcut: None,
+            passthrough_on_no_match: false,
+            popup_offset: None,
+            history_decay_half_life_days: 30.0,
+        }
+    }
+}
+
+impl ToolConfig {
+    /// Left/right padding of the character row for a window of the given width
+    pub fn horizontal_padding(&self, window_width: f32) -> f32 {
+        window_width * self.horizontal_padding_fraction
+    }
+
+    /// Height of one character cell with every line shown under the glyph
+    pub fn cell_height(&self) -> f32 {
+        let cell_height = layout::cell_height(self.font_size, self.show_variant_names);
+        if self.show_character_name {
+            cell_height + layout::character_name_height(self.character_name_font_size)
+        } else {
+            cell_height
         }
     }
+
+    /// Space around the popup reserved for the shadow, on each side
+    pub fn shadow_margin(&self) -> f32 {
+        self.shadow.as_ref().map_or(0.0, ShadowConfig::margin)
+    }
+
+    /// Corner radius of the popup panel: the border's, or the default one
+    pub fn corner_radius(&self) -> f32 {
+        self.border.map_or(shadow::CORNER_RADIUS, |border| border.radius)
+    }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
 pub struct WindowConfig {
     /// Window width as fraction of screen width (0.0 to 1.0)
     pub width_fraction: f32,
@@ -12,6 +13,15 @@ pub struct WindowConfig {
     pub center_horizontally: bool,
     /// Manual X offset if not centering (pixels)
     pub x_offset: f32,
+    /// Minimum fraction of the window that must be on the monitor before it gets clamped (0.0 to 1.0)
+    pub min_visible_fraction: f32,
+    /// Skip the on-screen clamp entirely
+    pub allow_offscreen: bool,
+    /// Resize the open window to fit when the base character changes.
+    /// Turn off for compositors that misbehave on runtime resizes.
+    pub auto_resize: bool,
+    /// Height of the preview area in pixels, added to the window when `tool.preview_character` is on
+    pub preview_height: f32,
 }
 
 impl Default for WindowConfig {
@@ -22,6 +32,10 @@ impl Default for WindowConfig {
             y_position_fraction: 0.25,
             center_horizontally: true,
             x_offset: 0.0,
+            min_visible_fraction: 1.0,
+            allow_offscreen: false,
+            auto_resize: true,
+            preview_height: 80.0,
         }
     }
 }
@@ -0,0 +1,130 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the app was doing, recorded in crash reports
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrashContext {
+    /// Output backend the pick would be delivered with
+    pub backend: Option<String>,
+    pub config_path: Option<PathBuf>,
+    /// Monitor the popup was placed on
+    pub monitor: Option<String>,
+    /// Display server and compositor, e.g. "Wayland (Hyprland)"
+    pub session: Option<String>,
+    /// What the last screen detection tried, one line per backend
+    pub detection: Vec<String>,
+}
+
+static CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext {
+    backend: None,
+    config_path: None,
+    monitor: None,
+    session: None,
+    detection: Vec::new(),
+});
+
+/// Files that must not outlive the process, removed when it panics
+static ARTIFACTS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Locking never fails in the panic hook, even if the panic happened while holding the lock
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Update the context reported if the app panics
+pub fn update_context(update: impl FnOnce(&mut CrashContext)) {
+    update(&mut lock(&CONTEXT));
+}
+
+/// Remove `path` if the app panics
+pub fn register_artifact(path: PathBuf) {
+    let mut artifacts = lock(&ARTIFACTS);
+    if !artifacts.contains(&path) {
+        artifacts.push(path);
+    }
+}
+
+/// Remove the registered artifacts that exist and return them. Safe to call more
+/// than once; files that are already gone are skipped.
+pub fn cleanup_artifacts() -> Vec<PathBuf> {
+    let artifacts = lock(&ARTIFACTS);
+    artifacts
+        .iter()
+        .filter(|path| match fs::remove_file(path) {
+            Ok(()) => true,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => false,
+            Err(e) => {
+                eprintln!("Failed to remove {:?}: {}", path, e);
+                false
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// `$XDG_STATE_HOME/quick-accent`, falling back to `~/.local/state/quick-accent`
+pub fn default_report_dir() -> PathBuf {
+    if let Ok(xdg_state) = std::env::var("XDG_STATE_HOME") {
+        PathBuf::from(xdg_state).join("quick-accent")
+    } else if let Ok(home) = std::env::var("HOME") {
+        PathBuf::from(home)
+            .join(".local")
+            .join("state")
+            .join("quick-accent")
+    } else {
+        PathBuf::from(".")
+    }
+}
+
+/// Crash report for a panic with `message`, without the backtrace
+pub fn format_report(message: &str, context: &CrashContext, removed: &[PathBuf]) -> String {
+    let mut report = String::new();
+    let _ = writeln!(report, "quick-accent {} crashed", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(report, "{}", message);
+    let _ = writeln!(report);
+    let _ = writeln!(report, "session: {}", context.session.as_deref().unwrap_or("unknown"));
+    let _ = writeln!(report, "backend: {}", context.backend.as_deref().unwrap_or("unknown"));
+    let _ = writeln!(report, "config: {:?}", context.config_path);
+    let _ = writeln!(report, "monitor: {}", context.monitor.as_deref().unwrap_or("unknown"));
+    let _ = writeln!(report, "cleaned up: {:?}", removed);
+    if !context.detection.is_empty() {
+        let _ = writeln!(report, "screen detection:");
+        for line in &context.detection {
+            let _ = writeln!(report, "  {}", line);
+        }
+    }
+    report
+}
+
+/// Replace the default panic output with a crash report in `report_dir` and a
+/// one-line pointer to it, cleaning up the registered artifacts first
+pub fn install_panic_hook(report_dir: PathBuf) {
+    std::panic::set_hook(Box::new(move |info| {
+        let removed = cleanup_artifacts();
+
+        let context = lock(&CONTEXT).clone();
+        let mut report = format_report(&info.to_string(), &context, &removed);
+        let _ = writeln!(report);
+        let _ = writeln!(report, "{}", std::backtrace::Backtrace::force_capture());
+
+        match write_report(&report_dir, &report) {
+            Ok(path) => eprintln!("quick-accent crashed: {} (report: {})", info, path.display()),
+            Err(e) => eprintln!("quick-accent crashed: {} (could not write report: {})\n{}", info, e, report),
+        }
+    }));
+}
+
+fn write_report(report_dir: &Path, report: &str) -> io::Result<PathBuf> {
+    fs::create_dir_all(report_dir)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    let path = report_dir.join(format!("crash-{}.txt", timestamp));
+    fs::write(&path, report)?;
+    Ok(path)
+}
@@ -0,0 +1,96 @@
+/// Session-related environment variables, captured once at startup
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Env {
+    pub wayland_display: Option<String>,
+    pub display: Option<String>,
+    pub session_type: Option<String>,
+    pub current_desktop: Option<String>,
+    pub hyprland_instance_signature: Option<String>,
+    pub swaysock: Option<String>,
+    pub niri_socket: Option<String>,
+    /// Locale for character classification, e.g. "fr_FR.UTF-8"
+    pub locale: Option<String>,
+}
+
+impl Env {
+    /// Snapshot the current process environment
+    pub fn capture() -> Self {
+        // Treat empty values the same as unset
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        Self {
+            wayland_display: var("WAYLAND_DISPLAY"),
+            display: var("DISPLAY"),
+            session_type: var("XDG_SESSION_TYPE"),
+            current_desktop: var("XDG_CURRENT_DESKTOP"),
+            hyprland_instance_signature: var("HYPRLAND_INSTANCE_SIGNATURE"),
+            swaysock: var("SWAYSOCK"),
+            niri_socket: var("NIRI_SOCKET"),
+            // LC_ALL overrides LC_CTYPE, which overrides LANG
+            locale: var("LC_ALL").or_else(|| var("LC_CTYPE")).or_else(|| var("LANG")),
+        }
+    }
+
+    fn session_type_is(&self, wanted: &str) -> bool {
+        self.session_type
+            .as_deref()
+            .is_some_and(|session| session.eq_ignore_ascii_case(wanted))
+    }
+
+    fn desktop_contains(&self, wanted: &str) -> bool {
+        // XDG_CURRENT_DESKTOP is a colon separated list, e.g. "sway:wlroots"
+        self.current_desktop.as_deref().is_some_and(|desktops| {
+            desktops
+                .split(':')
+                .any(|desktop| desktop.eq_ignore_ascii_case(wanted))
+        })
+    }
+
+    pub fn is_wayland(&self) -> bool {
+        self.wayland_display.is_some() || self.session_type_is("wayland")
+    }
+
+    /// An X server is reachable, either native X11 or XWayland
+    pub fn has_x11(&self) -> bool {
+        self.display.is_some() || self.session_type_is("x11")
+    }
+
+    /// Neither Wayland nor X11 could be identified, e.g. a stripped environment
+    pub fn is_unknown(&self) -> bool {
+        !self.is_wayland() && !self.has_x11()
+    }
+
+    /// Display server and desktop for diagnostics, e.g. "Wayland (Hyprland)"
+    pub fn describe(&self) -> String {
+        let server = if self.is_wayland() {
+            "Wayland"
+        } else if self.has_x11() {
+            "X11"
+        } else {
+            "unknown"
+        };
+        match &self.current_desktop {
+            Some(desktop) => format!("{} ({})", server, desktop),
+            None => server.to_string(),
+        }
+    }
+
+    pub fn is_hyprland(&self) -> bool {
+        self.hyprland_instance_signature.is_some() || self.desktop_contains("Hyprland")
+    }
+
+    pub fn is_sway(&self) -> bool {
+        self.swaysock.is_some() || self.desktop_contains("sway")
+    }
+
+    pub fn is_niri(&self) -> bool {
+        self.niri_socket.is_some() || self.desktop_contains("niri")
+    }
+
+    pub fn is_kde(&self) -> bool {
+        self.desktop_contains("KDE")
+    }
+
+    pub fn is_gnome(&self) -> bool {
+        self.desktop_contains("GNOME")
+    }
+}
@@ -0,0 +1,55 @@
+/// Axis-aligned rectangle in global screen coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    pub fn area(&self) -> f32 {
+        self.width.max(0.0) * self.height.max(0.0)
+    }
+
+    /// Area shared with `other`, 0 when they don't overlap
+    pub fn intersection_area(&self, other: &Rect) -> f32 {
+        let overlap_w = self.right().min(other.right()) - self.x.max(other.x);
+        let overlap_h = self.bottom().min(other.bottom()) - self.y.max(other.y);
+        overlap_w.max(0.0) * overlap_h.max(0.0)
+    }
+
+    /// Fraction (0.0 to 1.0) of this rectangle that lies inside `monitor`
+    pub fn visible_fraction_in(&self, monitor: &Rect) -> f32 {
+        let area = self.area();
+        if area <= 0.0 {
+            return 1.0;
+        }
+        self.intersection_area(monitor) / area
+    }
+
+    /// Move (never resize) this rectangle so it lies inside `monitor`.
+    /// A rectangle larger than the monitor is pinned to the monitor's top/left edge.
+    pub fn clamped_into(&self, monitor: &Rect) -> Rect {
+        let x = self.x.min(monitor.right() - self.width).max(monitor.x);
+        let y = self.y.min(monitor.bottom() - self.height).max(monitor.y);
+        Rect::new(x, y, self.width, self.height)
+    }
+}
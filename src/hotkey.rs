@@ -0,0 +1,172 @@
+//! Global shortcut that brings the popup back while the process keeps running.
+//! Parsing is always available; registering needs the `global-hotkey` feature.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How often the app checks for shortcut presses
+pub const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotkeyError {
+    /// The shortcut isn't modifiers and one key joined by `+`, e.g. "Super+Grave"
+    InvalidFormat(String),
+    /// The desktop refused the shortcut, e.g. another program holds it
+    RegistrationFailed(String),
+}
+
+impl fmt::Display for HotkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HotkeyError::InvalidFormat(shortcut) => write!(f, "Invalid keyboard shortcut {:?}", shortcut),
+            HotkeyError::RegistrationFailed(reason) => {
+                write!(f, "Failed to register keyboard shortcut: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HotkeyError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifiers {
+    pub super_key: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+/// Key pressed together with the modifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// Uppercase ASCII letter
+    Letter(char),
+    /// ASCII digit on the main row
+    Digit(char),
+    /// Function key F1 to F12
+    Function(u8),
+    /// The ` key
+    Grave,
+    Space,
+}
+
+impl Key {
+    fn parse(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            return match ch {
+                'a'..='z' | 'A'..='Z' => Some(Key::Letter(ch.to_ascii_uppercase())),
+                '0'..='9' => Some(Key::Digit(ch)),
+                '`' => Some(Key::Grave),
+                _ => None,
+            };
+        }
+        match name.to_ascii_lowercase().as_str() {
+            "grave" | "backquote" => Some(Key::Grave),
+            "space" => Some(Key::Space),
+            function => function
+                .strip_prefix('f')
+                .and_then(|number| number.parse().ok())
+                .filter(|number| (1..=12).contains(number))
+                .map(Key::Function),
+        }
+    }
+}
+
+/// Parsed `keyboard_shortcut`, e.g. "Ctrl+Alt+A"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl FromStr for Shortcut {
+    type Err = HotkeyError;
+
+    /// Modifiers (Super, Ctrl, Alt, Shift, in any order and case) and exactly one
+    /// key, joined by `+`
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        let invalid = || HotkeyError::InvalidFormat(shortcut.to_string());
+        let mut parts: Vec<&str> = shortcut.split('+').map(str::trim).collect();
+        let key = parts.pop().and_then(Key::parse).ok_or_else(invalid)?;
+
+        let mut modifiers = Modifiers::default();
+        for part in parts {
+            let flag = match part.to_ascii_lowercase().as_str() {
+                "super" | "meta" | "win" | "cmd" => &mut modifiers.super_key,
+                "ctrl" | "control" => &mut modifiers.ctrl,
+                "alt" => &mut modifiers.alt,
+                "shift" => &mut modifiers.shift,
+                _ => return Err(invalid()),
+            };
+            // "Ctrl+Ctrl+A" is a typo rather than a shortcut
+            if std::mem::replace(flag, true) {
+                return Err(invalid());
+            }
+        }
+        Ok(Shortcut { modifiers, key })
+    }
+}
+
+/// Registered shortcut; it stays registered until this is dropped
+#[cfg(feature = "global-hotkey")]
+pub struct Registration {
+    _manager: global_hotkey::GlobalHotKeyManager,
+}
+
+/// Register `shortcut` with the desktop. On Linux this works on X11 (and
+/// XWayland) only; Wayland has no protocol for global shortcuts.
+#[cfg(feature = "global-hotkey")]
+pub fn register(shortcut: &Shortcut) -> Result<Registration, HotkeyError> {
+    use global_hotkey::hotkey::{Code, HotKey, Modifiers as HotKeyModifiers};
+
+    let mut modifiers = HotKeyModifiers::empty();
+    for (set, modifier) in [
+        (shortcut.modifiers.super_key, HotKeyModifiers::SUPER),
+        (shortcut.modifiers.ctrl, HotKeyModifiers::CONTROL),
+        (shortcut.modifiers.alt, HotKeyModifiers::ALT),
+        (shortcut.modifiers.shift, HotKeyModifiers::SHIFT),
+    ] {
+        if set {
+            modifiers |= modifier;
+        }
+    }
+
+    // `Code` parses its own names, e.g. "KeyA", "Digit1", "F5"
+    let code_name = match shortcut.key {
+        Key::Letter(letter) => format!("Key{}", letter),
+        Key::Digit(digit) => format!("Digit{}", digit),
+        Key::Function(number) => format!("F{}", number),
+        Key::Grave => "Backquote".to_string(),
+        Key::Space => "Space".to_string(),
+    };
+    let code: Code = code_name
+        .parse()
+        .map_err(|_| HotkeyError::RegistrationFailed(format!("unsupported key {}", code_name)))?;
+
+    let manager =
+        global_hotkey::GlobalHotKeyManager::new().map_err(|e| HotkeyError::RegistrationFailed(e.to_string()))?;
+    manager
+        .register(HotKey::new(Some(modifiers), code))
+        .map_err(|e| HotkeyError::RegistrationFailed(e.to_string()))?;
+    Ok(Registration { _manager: manager })
+}
+
+/// Whether the registered shortcut was pressed since the last call
+#[cfg(feature = "global-hotkey")]
+pub fn take_pressed() -> bool {
+    use global_hotkey::{GlobalHotKeyEvent, HotKeyState};
+
+    let mut pressed = false;
+    while let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
+        pressed |= event.state == HotKeyState::Pressed;
+    }
+    pressed
+}
+
+/// Without the `global-hotkey` feature nothing is ever registered
+#[cfg(not(feature = "global-hotkey"))]
+pub fn take_pressed() -> bool {
+    false
+}
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+/// Scale of the hovered character once the animation has finished
+pub const HOVER_SCALE: f32 = 1.2;
+
+/// Time to grow from normal size to `HOVER_SCALE`
+pub const HOVER_DURATION: Duration = Duration::from_millis(80);
+
+/// Interval of the animation ticks
+pub const TICK: Duration = Duration::from_millis(16);
+
+/// Scale after one tick moving `current` towards `target`, never overshooting
+pub fn step_scale(current: f32, target: f32) -> f32 {
+    let step = (HOVER_SCALE - 1.0) * TICK.as_secs_f32() / HOVER_DURATION.as_secs_f32();
+    if current < target {
+        (current + step).min(target)
+    } else {
+        (current - step).max(target)
+    }
+}
@@ -0,0 +1,25 @@
+use std::time::{Duration, Instant};
+
+/// Deadline tracker for closing the picker after a period without input
+pub struct IdleTimer {
+    timeout: Duration,
+    deadline: Instant,
+}
+
+impl IdleTimer {
+    pub fn new(timeout: Duration, now: Instant) -> Self {
+        Self {
+            timeout,
+            deadline: now + timeout,
+        }
+    }
+
+    /// Push the deadline out by the full timeout from `now`
+    pub fn reset(&mut self, now: Instant) {
+        self.deadline = now + self.timeout;
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now >= self.deadline
+    }
+}
@@ -0,0 +1,153 @@
+/// Font size of the variant name shown under each glyph
+pub const NAME_FONT_SIZE: f32 = 12.0;
+
+/// Default gap between neighbouring cells, both within a row and between rows
+pub const CELL_SPACING: f32 = 8.0;
+
+/// Touchpad scrolling, in pixels, that moves the highlight by one variant
+pub const SCROLL_STEP_PIXELS: f32 = 40.0;
+
+/// Default padding left and right of the label inside a cell, as iced pads buttons
+pub const CELL_PADDING: f32 = 10.0;
+
+/// Approximate advance of one character relative to the font size
+const CHAR_WIDTH_FACTOR: f32 = 0.6;
+
+/// Width of one character cell; `name_chars` is the longest variant name shown
+/// under the glyph, 0 when names are hidden
+pub fn cell_width(font_size: f32, name_chars: usize) -> f32 {
+    labelled_cell_width(font_size, 1, name_chars)
+}
+
+/// Width of a cell whose button shows `label_chars` characters, e.g. 4 for a
+/// hex code point instead of the glyph
+pub fn labelled_cell_width(font_size: f32, label_chars: usize, name_chars: usize) -> f32 {
+    padded_cell_width(font_size, label_chars, name_chars, CELL_PADDING)
+}
+
+/// Same as `labelled_cell_width`, with `padding` left and right of the label
+pub fn padded_cell_width(font_size: f32, label_chars: usize, name_chars: usize, padding: f32) -> f32 {
+    // Label, room for glyphs overhanging their advance, and button padding
+    let label_width = label_chars.max(1) as f32 * font_size * CHAR_WIDTH_FACTOR + font_size * 0.3 + 2.0 * padding;
+    let name_width = name_chars as f32 * NAME_FONT_SIZE * CHAR_WIDTH_FACTOR;
+    label_width.max(name_width)
+}
+
+/// Height of one character cell, including the name line when shown
+pub fn cell_height(font_size: f32, show_names: bool) -> f32 {
+    let glyph_height = font_size * 1.5;
+    if show_names {
+        glyph_height + NAME_FONT_SIZE * 1.5
+    } else {
+        glyph_height
+    }
+}
+
+/// Height of the short Unicode name line under a character
+pub fn character_name_height(font_size: f32) -> f32 {
+    font_size * 1.5
+}
+
+/// Height of the breadcrumb line shown above the variants of a category
+pub fn breadcrumb_height() -> f32 {
+    NAME_FONT_SIZE * 1.5
+}
+
+/// Height needed to show `rows` rows of cells `spacing` apart, with a
+/// spacing-sized margin above and below
+pub fn rows_height(rows: usize, cell_height: f32, spacing: f32) -> f32 {
+    let rows = rows.max(1) as f32;
+    rows * cell_height + (rows + 1.0) * spacing
+}
+
+/// Number of cells needed for `groups`, counting a separator cell between groups when shown
+pub fn cell_count(groups: &[Vec<char>], with_separators: bool) -> usize {
+    let separators = if with_separators {
+        groups.len().saturating_sub(1)
+    } else {
+        0
+    };
+    groups.iter().map(Vec::len).sum::<usize>() + separators
+}
+
+/// Cells for `groups` in display order: a variant, or `None` for the separator between groups
+pub fn cells(groups: &[Vec<char>], with_separators: bool) -> Vec<Option<char>> {
+    let mut cells = Vec::with_capacity(cell_count(groups, with_separators));
+    for (index, group) in groups.iter().enumerate() {
+        if index > 0 && with_separators {
+            cells.push(None);
+        }
+        cells.extend(group.iter().copied().map(Some));
+    }
+    cells
+}
+
+/// Rows needed to lay out `cells` cells in `columns` columns
+pub fn grid_rows(cells: usize, columns: usize) -> usize {
+    cells.div_ceil(columns.max(1))
+}
+
+/// Row and column of the cell at flat `index`, filling rows first
+pub fn grid_position(index: usize, columns: usize) -> (usize, usize) {
+    let columns = columns.max(1);
+    (index / columns, index % columns)
+}
+
+/// Arrow key movement within the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Cell reached from `index` by one arrow key step on a grid of `count` cells in
+/// `columns` columns. Left and right continue onto the neighbouring row, up and down
+/// keep the column. Moving down into a shorter last row lands on its last cell;
+/// at the edges the index stays put.
+pub fn navigate(index: usize, direction: Direction, columns: usize, count: usize) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    let columns = columns.max(1);
+    let last = count - 1;
+    let index = index.min(last);
+
+    match direction {
+        Direction::Left => index.saturating_sub(1),
+        Direction::Right => (index + 1).min(last),
+        Direction::Up => index.checked_sub(columns).unwrap_or(index),
+        Direction::Down => {
+            let (row, _) = grid_position(index, columns);
+            let (last_row, _) = grid_position(last, columns);
+            if row < last_row { (index + columns).min(last) } else { index }
+        }
+    }
+}
+
+/// Index reached from `index` by `step` scroll steps among `count` variants,
+/// wrapping around at both ends. Nothing highlighted yet starts just outside
+/// the row, so the first step forward lands on the first variant and the first
+/// step back on the last one.
+pub fn scroll_step(index: Option<usize>, step: i32, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let count = count as i64;
+    let start = match index {
+        Some(index) => (index as i64).min(count - 1),
+        None if step > 0 => -1,
+        None => count,
+    };
+    Some((start + i64::from(step)).rem_euclid(count) as usize)
+}
+
+/// How many cells of `cell_width` fit next to each other, `spacing` apart, in `available_width`
+pub fn cells_per_row(available_width: f32, cell_width: f32, spacing: f32) -> usize {
+    if cell_width <= 0.0 {
+        return 1;
+    }
+    let fitting = ((available_width + spacing) / (cell_width + spacing)).floor();
+    (fitting as usize).max(1)
+}
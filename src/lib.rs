@@ -0,0 +1,26 @@
+pub mod accents;
+pub mod app;
+pub mod category;
+pub mod cli;
+pub mod clipboard_ring;
+pub mod close;
+pub mod config;
+pub mod crash;
+pub mod env;
+pub mod geometry;
+pub mod hotkey;
+pub mod hover;
+pub mod idle;
+pub mod layout;
+pub mod output;
+pub mod record;
+pub mod screen;
+pub mod search;
+pub mod selection;
+pub mod self_test;
+pub mod shadow;
+pub mod sticky;
+pub mod test_output;
+pub mod trigger;
+pub mod uri;
+pub mod usage;
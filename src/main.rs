@@ -1,12 +1,93 @@
-use iced::alignment::{Horizontal, Vertical};
-use iced::{Element, Point, Size, Task, window};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 
-mod config;
-mod screen;
+use iced::{Point, Size, window};
+
+use quick_accent::app::App;
+use quick_accent::cli::{Cli, Command, EXIT_INJECTION_FAILED, EXIT_INVALID_URI, EXIT_USAGE, USAGE};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::tool::{ToolConfig, WindowBehavior, WindowState};
+use quick_accent::env::Env;
+#[cfg(feature = "global-hotkey")]
+use quick_accent::hotkey;
+use quick_accent::output::{Delivery, OutputBackend};
+use quick_accent::record::Recording;
+use quick_accent::screen::prewarm::{self, PendingDetection};
+use quick_accent::screen::runner::{SystemFileSystem, SystemRunner};
+use quick_accent::test_output::{self, TestOutputMode};
+use quick_accent::uri::AccentUri;
+use quick_accent::usage::UsageHistory;
+use quick_accent::{config, crash, screen, selection, self_test, shadow, trigger};
 
 pub fn main() -> iced::Result {
+    crash::install_panic_hook(crash::default_report_dir());
+
+    let cli = match Cli::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+
+    // --config beats $QUICK_ACCENT_CONFIG, which beats the XDG location
+    let config_path = cli
+        .config_path
+        .clone()
+        .unwrap_or_else(config::app::AppConfig::default_config_path);
+
+    // A malformed URI must not fall through to an unfiltered picker
+    let uri = cli.uri.as_deref().map(|uri| match AccentUri::parse(uri) {
+        Ok(uri) => uri,
+        Err(e) => {
+            eprintln!("Invalid URI: {}\n\n{}", e, USAGE);
+            std::process::exit(EXIT_INVALID_URI);
+        }
+    });
+    if let Some(uri) = &uri {
+        for key in &uri.ignored_keys {
+            eprintln!("Ignoring unknown URI key {:?}", key);
+        }
+    }
+
+    // A running daemon shows the URI in its own window
+    if let Some(raw_uri) = cli.uri.as_deref() {
+        if trigger::forward(&trigger::socket_path(), raw_uri).is_ok() {
+            return Ok(());
+        }
+    }
+
+    match cli.command {
+        Command::Show => {}
+        Command::ConfigUpgrade => {
+            run_config_upgrade(&config_path);
+            return Ok(());
+        }
+        Command::TestOutput(mode) => {
+            run_test_output(mode, &config_path);
+            return Ok(());
+        }
+        Command::SelfTest => {
+            run_self_test();
+            return Ok(());
+        }
+        Command::RecordEnv(path) => {
+            run_record_env(&path, &config_path);
+            return Ok(());
+        }
+        Command::Replay(path) => {
+            run_replay(&path);
+            return Ok(());
+        }
+    }
+
+    // Leftovers of an interrupted atomic write must not survive a crash
+    crash::update_context(|context| context.config_path = Some(config_path.clone()));
+    crash::register_artifact(config_path.with_extension("ron.tmp"));
+    crash::register_artifact(ClipboardRing::default_path().with_extension("ron.tmp"));
+
     // Load configuration (creates default if not exists)
-    let config = match config::app::AppConfig::load() {
+    let config = match config::app::AppConfig::load_from_path(&config_path) {
         Ok(cfg) => cfg,
         Err(e) => {
             eprintln!("Failed to load config: {}, using defaults", e);
@@ -14,21 +95,100 @@ pub fn main() -> iced::Result {
         }
     };
 
+    let mut config = match config.validate() {
+        Ok(warnings) => {
+            for warning in warnings {
+                eprintln!("{}", warning);
+            }
+            config
+        }
+        Err(e) => {
+            eprintln!("Invalid config: {}, using defaults", e);
+            config::app::AppConfig::default()
+        }
+    };
+
+    // Session environment decides which detection and output backends are tried
+    let env = Env::capture();
+    crash::update_context(|context| context.session = Some(env.describe()));
+
+    // Detection spawns external tools; let them run while the rest of startup does
+    let pending_screen = config
+        .screen
+        .prewarm_detection
+        .then(|| PendingDetection::spawn(config.screen.clone(), env.clone()));
+
+    // Without transparency the shadow margin would show as an opaque frame
+    if config.tool.shadow.is_some() && !shadow::transparency_supported(&env, &SystemRunner) {
+        eprintln!("No compositor with transparency support found, drawing the popup without a shadow");
+        config.tool.shadow = None;
+    }
+
+    // Keep the shortcut registered for as long as the app runs
+    let hotkey_registration = register_hotkey(&mut config.tool);
+
+    // A daemon is triggered through stdin and its socket. Otherwise the shortcut is
+    // the only trigger, and without it a hidden window would never come back
+    if config.tool.window_behavior == WindowBehavior::SingletonDaemon {
+        trigger::spawn_stdin_reader();
+        let socket_path = trigger::socket_path();
+        if let Err(e) = trigger::spawn_socket_listener(&socket_path) {
+            eprintln!("Not accepting forwarded URIs on {:?}: {}", socket_path, e);
+        }
+    } else if hotkey_registration.is_none() && config.tool.window_behavior.keeps_window() {
+        eprintln!(
+            "window_behavior {:?} needs a registered keyboard_shortcut, using CloseAndReopenEachTime",
+            config.tool.window_behavior
+        );
+        config.tool.window_behavior = WindowBehavior::CloseAndReopenEachTime;
+    }
+
+    if config.tool.use_primary_selection {
+        if let Some(base) = selection::base_from_primary_selection(&config.tool, &env, &SystemRunner) {
+            config.tool.base_character = base;
+        }
+    }
+
+    // An explicit URI beats both the config and the selection
+    if let Some(uri) = &uri {
+        uri.apply(&mut config.tool);
+    }
+
     // Detect actual screen dimensions
-    let screen_info = screen::ScreenInfo::detect();
+    let mut screen_info = match pending_screen {
+        Some(pending) => pending.join(prewarm::DETECTION_TIMEOUT),
+        None => screen::ScreenInfo::detect(&config.screen, &env),
+    };
+    if config.tool.popup_offset.is_some() {
+        screen_info.locate_cursor(&env, &SystemRunner);
+    }
+    crash::update_context(|context| {
+        context.monitor = Some(format!(
+            "{}x{} at ({}, {})",
+            screen_info.width, screen_info.height, screen_info.x, screen_info.y
+        ));
+    });
+
+    let backend = config
+        .tool
+        .output_backend
+        .unwrap_or_else(|| OutputBackend::auto_select(&env));
+    crash::update_context(|context| context.backend = Some(format!("{:?}", backend)));
 
     if config.screen.debug_screen_detection {
         println!(
             "Detected screen size: {}x{}",
             screen_info.width, screen_info.height
         );
+        if let (Some((width_mm, height_mm)), Some(dpi)) = (screen_info.physical_mm, screen_info.dpi()) {
+            println!("Physical size: {}x{} mm ({:.0} DPI)", width_mm, height_mm, dpi);
+        }
     }
 
     // Calculate window size and position using config
-    let (window_width, window_height) =
-        config.calculate_window_size(screen_info.width, screen_info.height);
-    let (x_position, y_position) =
-        config.calculate_window_position(screen_info.width, screen_info.height, window_width);
+    let window_rect = config.window_rect(&screen_info);
+    let (window_width, window_height) = (window_rect.width, window_rect.height);
+    let (x_position, y_position) = (window_rect.x, window_rect.y);
 
     if config.screen.debug_screen_detection {
         println!("Calculated window size: {}x{}", window_width, window_height);
@@ -38,60 +198,224 @@ pub fn main() -> iced::Result {
         );
     }
 
+    let ring_path = ClipboardRing::default_path();
+    let mut ring = match ClipboardRing::load_from_path(&ring_path, config.tool.clipboard_ring_size) {
+        Ok(ring) => ring,
+        Err(e) => {
+            eprintln!("Failed to load history from {:?}: {}", ring_path, e);
+            ClipboardRing::new(config.tool.clipboard_ring_size)
+        }
+    };
+
+    let usage_path = UsageHistory::default_path();
+    let usage = match UsageHistory::load_from_path(&usage_path) {
+        Ok(usage) => usage,
+        Err(e) => {
+            eprintln!("Failed to load usage from {:?}: {}", usage_path, e);
+            UsageHistory::default()
+        }
+    };
+    let usage = Arc::new(Mutex::new(usage));
+
     // Clone config for use in closure
-    let config_for_app = config.tool.clone();
+    let config_for_app = config.clone();
+    let delivered: Arc<Mutex<Option<(String, Delivery)>>> = Arc::default();
+    let delivered_for_app = delivered.clone();
+    let ring_for_app = ring.clone();
+    let usage_for_app = usage.clone();
+    let locale = env.locale.clone().unwrap_or_default();
 
     iced::application(
-        move || App::new(config_for_app.clone()),
+        move || {
+            // The app hides the window before delivering so the pick reaches the
+            // previously focused app, and shows it again with a banner on failure
+            App::new(
+                config_for_app.clone(),
+                Size::new(window_width, window_height),
+                ring_for_app.clone(),
+                Arc::default(),
+            )
+            .with_locale(&locale)
+            .with_delivery(backend, delivered_for_app.clone())
+            .with_usage(usage_for_app.clone())
+        },
         App::update,
         App::view,
     )
+    .subscription(App::subscription)
+    .style(App::style)
     .window(window::Settings {
         size: Size::new(window_width, window_height),
         position: window::Position::Specific(Point::new(x_position, y_position)),
         resizable: false,
+        visible: config.tool.window_behavior.initial_state() == WindowState::Shown,
+        transparent: config.tool.shadow.is_some(),
         ..Default::default()
     })
-    .run()
+    .run()?;
+
+    let delivered = delivered.lock().ok().and_then(|mut delivered| delivered.take());
+    if let Some((text, delivery)) = delivered {
+        if let Ok(usage) = usage.lock() {
+            if let Err(e) = usage.save_to_path(&usage_path) {
+                eprintln!("Failed to save usage to {:?}: {}", usage_path, e);
+            }
+        }
+
+        if config.tool.clipboard_ring_size > 0 && !matches!(delivery, Delivery::Failed { .. }) {
+            ring.push(text);
+            if let Err(e) = ring.save_to_path(&ring_path) {
+                eprintln!("Failed to save history to {:?}: {}", ring_path, e);
+            }
+        }
+
+        if delivery.exit_code() != 0 {
+            std::process::exit(delivery.exit_code());
+        }
+    }
+
+    if config.tool.close_strategy.exits_process() {
+        std::process::exit(0);
+    }
+
+    Ok(())
+}
+
+/// Register `keyboard_shortcut`, hiding the window between uses so the shortcut
+/// has something to show again. Turns the shortcut off when it can't be registered.
+#[cfg(feature = "global-hotkey")]
+fn register_hotkey(tool: &mut ToolConfig) -> Option<hotkey::Registration> {
+    // Validated with the rest of the config
+    let shortcut = tool.keyboard_shortcut.as_ref()?.parse().ok()?;
+    match hotkey::register(&shortcut) {
+        Ok(registration) => {
+            if tool.window_behavior == WindowBehavior::CloseAndReopenEachTime {
+                tool.window_behavior = WindowBehavior::HideAndShow;
+            }
+            Some(registration)
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            tool.keyboard_shortcut = None;
+            None
+        }
+    }
 }
 
-struct App {
-    config: config::tool::ToolConfig,
+#[cfg(not(feature = "global-hotkey"))]
+fn register_hotkey(tool: &mut ToolConfig) -> Option<()> {
+    if tool.keyboard_shortcut.take().is_some() {
+        eprintln!("keyboard_shortcut needs the global-hotkey feature, ignoring it");
+    }
+    None
 }
 
-#[derive(Debug, Clone)]
-enum Message {}
+fn run_self_test() {
+    let scratch_dir = std::env::temp_dir().join(format!("quick-accent-self-test-{}", std::process::id()));
+    if let Err(e) = std::fs::create_dir_all(&scratch_dir) {
+        eprintln!("Failed to create {:?}: {}", scratch_dir, e);
+        std::process::exit(1);
+    }
+
+    let sections = self_test::run(&scratch_dir);
+    let _ = std::fs::remove_dir_all(&scratch_dir);
 
-impl App {
-    fn new(config: config::tool::ToolConfig) -> Self {
-        App { config }
+    for section in &sections {
+        if section.passed() {
+            println!("PASS {}", section.name);
+        } else {
+            println!("FAIL {}", section.name);
+            for failure in &section.failures {
+                println!("     {}", failure);
+            }
+        }
     }
 
-    fn update(&mut self, _message: Message) -> Task<Message> {
-        Task::none()
+    if !sections.iter().all(|section| section.passed()) {
+        std::process::exit(1);
     }
+}
+
+fn run_record_env(path: &Path, config_path: &Path) {
+    let config = config::app::AppConfig::load_from_path(config_path).unwrap_or_else(|e| {
+        eprintln!("Failed to load config: {}, using defaults", e);
+        config::app::AppConfig::default()
+    });
+    let env = Env::capture();
 
-    fn view(&self) -> Element<'_, Message> {
-        // Display the configured text
-        println!("ToolConfig: {:?}", self.config);
-        iced::widget::container(iced::widget::text("some text"))
-            .align_x(Horizontal::Center)
-            .align_y(Vertical::Center)
-            .width(iced::Length::Fill)
-            .height(iced::Length::Fill)
-            .into()
+    let recording = Recording::capture(&config, &env, &SystemRunner, &SystemFileSystem);
+    if let Err(e) = recording.save_to_path(path) {
+        eprintln!("Failed to write {:?}: {}", path, e);
+        std::process::exit(1);
     }
+    println!(
+        "Recorded {} command(s) and {} read(s) to {:?}",
+        recording.commands.len(),
+        recording.reads.len(),
+        path
+    );
+}
+
+fn run_replay(path: &Path) {
+    let recording = match Recording::load_from_path(path) {
+        Ok(recording) => recording,
+        Err(e) => {
+            eprintln!("Failed to read {:?}: {}", path, e);
+            std::process::exit(1);
+        }
+    };
+
+    let (screen, window) = recording.replay();
+    println!("Recorded by quick-accent {}", recording.version);
+    println!(
+        "Monitor: {}x{} at ({}, {}), recorded {:?}",
+        screen.width, screen.height, screen.x, screen.y, recording.screen
+    );
+    println!(
+        "Window:  {}x{} at ({}, {}), recorded {:?}",
+        window.width, window.height, window.x, window.y, recording.window
+    );
 }
 
-impl Default for App {
-    fn default() -> Self {
-        let config = match config::app::AppConfig::load() {
-            Ok(cfg) => cfg,
-            Err(e) => {
-                eprintln!("Failed to load config: {}, using defaults", e);
-                config::app::AppConfig::default()
+fn run_test_output(mode: TestOutputMode, config_path: &Path) {
+    let config = config::app::AppConfig::load_from_path(config_path).unwrap_or_else(|e| {
+        eprintln!("Failed to load config: {}, using defaults", e);
+        config::app::AppConfig::default()
+    });
+    let env = Env::capture();
+
+    let Some(backend) = mode.backend(config.tool.output_backend, &env) else {
+        eprintln!("No clipboard tool for this session (neither Wayland nor X11 detected)");
+        std::process::exit(EXIT_INJECTION_FAILED);
+    };
+
+    if let Err(e) = test_output::run(mode, backend, &SystemRunner) {
+        eprintln!("Output via {:?} failed: {}", backend, e);
+        std::process::exit(EXIT_INJECTION_FAILED);
+    }
+}
+
+fn run_config_upgrade(config_path: &Path) {
+    if !config_path.exists() {
+        // load() writes a complete default config when none exists
+        if let Err(e) = config::app::AppConfig::load_from_path(config_path) {
+            eprintln!("Failed to create config: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    match config::app::AppConfig::upgrade_file(config_path) {
+        Ok(added) if added.is_empty() => println!("{:?} is already up to date", config_path),
+        Ok(added) => {
+            println!("Added {} field(s) to {:?}:", added.len(), config_path);
+            for field in added {
+                println!("  {}", field);
             }
-        };
-        Self::new(config.tool)
+        }
+        Err(e) => {
+            eprintln!("Failed to upgrade {:?}: {}", config_path, e);
+            std::process::exit(1);
+        }
     }
 }
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::accents::entities;
+use crate::cli::EXIT_INJECTION_FAILED;
+use crate::env::Env;
+use crate::screen::runner::CommandRunner;
+
+/// How the picked text is delivered to the focused application
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum OutputBackend {
+    /// Type via `wtype` (Wayland virtual keyboard)
+    Wtype,
+    /// Type via `xdotool type` (X11)
+    Xdotool,
+    /// Print to stdout, for scripts and sessions without a display
+    Stdout,
+    /// Copy to the Wayland clipboard via `wl-copy`
+    WlCopy,
+    /// Copy to the X11 clipboard via `xclip`
+    Xclip,
+}
+
+/// What is emitted for the picked character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum OutputRepresentation {
+    /// The character itself
+    #[default]
+    Glyph,
+    /// Named HTML entity, e.g. `&eacute;`
+    Html,
+    /// LaTeX text-mode command, e.g. `\'e`
+    Latex,
+}
+
+impl OutputRepresentation {
+    /// Text to emit for `ch`; characters without an entity are emitted as the glyph
+    pub fn render(self, ch: char) -> String {
+        let entity = match self {
+            OutputRepresentation::Glyph => return ch.to_string(),
+            OutputRepresentation::Html => entities::html_entity(ch),
+            OutputRepresentation::Latex => entities::latex(ch),
+        };
+        entity.unwrap_or_else(|| {
+            eprintln!("No {:?} representation for {:?}, emitting the character", self, ch);
+            ch.to_string()
+        })
+    }
+}
+
+/// Outcome of delivering the picked text
+#[derive(Debug, Clone, PartialEq)]
+pub enum Delivery {
+    /// The requested backend delivered the text
+    Emitted(OutputBackend),
+    /// The requested backend failed, a fallback (the clipboard) took over
+    FellBack {
+        failed: OutputBackend,
+        used: OutputBackend,
+        error: String,
+    },
+    /// Neither the requested backend nor any fallback worked
+    Failed { backend: OutputBackend, error: String },
+}
+
+impl Delivery {
+    /// Process exit status for this outcome
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Delivery::Emitted(_) => 0,
+            Delivery::FellBack { .. } | Delivery::Failed { .. } => EXIT_INJECTION_FAILED,
+        }
+    }
+
+    /// Message to show the user when `text` did not reach the focused app
+    pub fn banner(&self, text: &str) -> Option<String> {
+        match self {
+            Delivery::Emitted(_) => None,
+            Delivery::FellBack { .. } => Some(format!(
+                "Couldn't type into the focused app \u{2014} copied {} to the clipboard instead",
+                text
+            )),
+            Delivery::Failed { error, .. } => {
+                Some(format!("Couldn't type {} into the focused app: {}", text, error))
+            }
+        }
+    }
+}
+
+impl OutputBackend {
+    /// Pick the backend matching the session: Wayland first, since XWayland
+    /// sessions set DISPLAY too but native Wayland apps won't see X11 input
+    pub fn auto_select(env: &Env) -> Self {
+        if env.is_wayland() {
+            OutputBackend::Wtype
+        } else if env.has_x11() {
+            OutputBackend::Xdotool
+        } else {
+            OutputBackend::Stdout
+        }
+    }
+
+    /// Next backend to try when this one fails: typing falls back to the
+    /// clipboard of the same display server
+    pub fn fallback(self) -> Option<Self> {
+        match self {
+            OutputBackend::Wtype => Some(OutputBackend::WlCopy),
+            OutputBackend::Xdotool => Some(OutputBackend::Xclip),
+            OutputBackend::Stdout | OutputBackend::WlCopy | OutputBackend::Xclip => None,
+        }
+    }
+
+    /// Emit `text`, walking the fallback chain when a backend fails
+    pub fn deliver(self, text: &str, runner: &dyn CommandRunner) -> Delivery {
+        let error = match self.emit(text, runner) {
+            Ok(()) => return Delivery::Emitted(self),
+            Err(e) => e.to_string(),
+        };
+        eprintln!("Failed to output {:?} via {:?}: {}", text, self, error);
+
+        let mut next = self.fallback();
+        while let Some(backend) = next {
+            match backend.emit(text, runner) {
+                Ok(()) => {
+                    return Delivery::FellBack {
+                        failed: self,
+                        used: backend,
+                        error,
+                    };
+                }
+                Err(e) => eprintln!("Fallback {:?} failed too: {}", backend, e),
+            }
+            next = backend.fallback();
+        }
+
+        Delivery::Failed {
+            backend: self,
+            error,
+        }
+    }
+
+    /// Clipboard backend for the session, if it has a display server
+    pub fn clipboard_for(env: &Env) -> Option<Self> {
+        if env.is_wayland() {
+            Some(OutputBackend::WlCopy)
+        } else if env.has_x11() {
+            Some(OutputBackend::Xclip)
+        } else {
+            None
+        }
+    }
+
+    /// Program and arguments that deliver `text`; None for `Stdout`, which runs nothing.
+    /// `Xclip` reads the text from stdin rather than its arguments.
+    pub fn command(self, text: &str) -> Option<(&'static str, Vec<&str>)> {
+        match self {
+            OutputBackend::Wtype => Some(("wtype", vec!["--", text])),
+            OutputBackend::Xdotool => Some(("xdotool", vec!["type", "--clearmodifiers", "--", text])),
+            OutputBackend::WlCopy => Some(("wl-copy", vec!["--", text])),
+            OutputBackend::Xclip => Some(("xclip", vec!["-selection", "clipboard"])),
+            OutputBackend::Stdout => None,
+        }
+    }
+
+    pub fn emit(self, text: &str, runner: &dyn CommandRunner) -> Result<(), Box<dyn std::error::Error>> {
+        let Some((program, args)) = self.command(text) else {
+            println!("{}", text);
+            return Ok(());
+        };
+
+        let output = if self == OutputBackend::Xclip {
+            runner.run_with_input(program, &args, text)?
+        } else {
+            runner.run(program, &args)?
+        };
+        if !output.success {
+            return Err(format!("{} exited with an error", program).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+//! Record everything screen detection looked at on one machine and replay it on
+//! another, for debugging placement reports from unusual setups. The recorder and
+//! replayer are `CommandRunner`/`FileSystem` implementations wrapping the real ones.
+
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config::app::AppConfig;
+use crate::env::Env;
+use crate::geometry::Rect;
+use crate::screen::ScreenInfo;
+use crate::screen::runner::{CommandOutput, CommandRunner, FileSystem};
+
+/// One external command and what it returned
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub success: bool,
+    pub stdout: String,
+    /// Set when the command could not be run at all, e.g. not installed
+    pub error: Option<String>,
+}
+
+/// One file or directory read, with home directories shown as `~`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum RecordedRead {
+    File { path: String, contents: Result<Vec<u8>, String> },
+    Dir { path: String, entries: Result<Vec<String>, String> },
+}
+
+/// Display-related environment only; nothing else from the environment is kept
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedEnv {
+    pub wayland_display: Option<String>,
+    pub display: Option<String>,
+    pub session_type: Option<String>,
+    pub current_desktop: Option<String>,
+    pub hyprland_instance_signature: Option<String>,
+    pub swaysock: Option<String>,
+    pub niri_socket: Option<String>,
+    /// Decides which variants `locale_aware` ranks first
+    pub locale: Option<String>,
+}
+
+impl From<&Env> for RecordedEnv {
+    fn from(env: &Env) -> Self {
+        Self {
+            wayland_display: env.wayland_display.clone(),
+            display: env.display.clone(),
+            session_type: env.session_type.clone(),
+            current_desktop: env.current_desktop.clone(),
+            // Only whether they are set matters, the values name sockets and instances
+            hyprland_instance_signature: env.hyprland_instance_signature.as_ref().map(|_| "redacted".to_string()),
+            swaysock: env.swaysock.as_ref().map(|_| "redacted".to_string()),
+            niri_socket: env.niri_socket.as_ref().map(|_| "redacted".to_string()),
+            locale: env.locale.clone(),
+        }
+    }
+}
+
+impl From<&RecordedEnv> for Env {
+    fn from(env: &RecordedEnv) -> Self {
+        Self {
+            wayland_display: env.wayland_display.clone(),
+            display: env.display.clone(),
+            session_type: env.session_type.clone(),
+            current_desktop: env.current_desktop.clone(),
+            hyprland_instance_signature: env.hyprland_instance_signature.clone(),
+            swaysock: env.swaysock.clone(),
+            niri_socket: env.niri_socket.clone(),
+            locale: env.locale.clone(),
+        }
+    }
+}
+
+/// Everything needed to rerun detection and placement elsewhere
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Recording {
+    /// Version of quick-accent that made the recording
+    pub version: String,
+    pub env: RecordedEnv,
+    pub config: AppConfig,
+    pub commands: Vec<RecordedCommand>,
+    pub reads: Vec<RecordedRead>,
+    /// Detected monitor as (x, y, width, height)
+    pub screen: (f32, f32, f32, f32),
+    /// Final window geometry as (x, y, width, height)
+    pub window: (f32, f32, f32, f32),
+}
+
+impl Recording {
+    /// Run detection and placement through `runner` and `fs`, recording what they returned
+    pub fn capture(config: &AppConfig, env: &Env, runner: &dyn CommandRunner, fs: &dyn FileSystem) -> Self {
+        let recording_runner = RecordingRunner {
+            inner: runner,
+            commands: RefCell::default(),
+        };
+        let recording_fs = RecordingFileSystem {
+            inner: fs,
+            reads: RefCell::default(),
+        };
+
+        let screen = ScreenInfo::detect_with(&config.screen, env, &recording_runner, &recording_fs);
+        let window = config.window_rect(&screen);
+
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            env: RecordedEnv::from(env),
+            config: config.clone(),
+            commands: recording_runner.commands.into_inner(),
+            reads: recording_fs.reads.into_inner(),
+            screen: (screen.x, screen.y, screen.width, screen.height),
+            window: (window.x, window.y, window.width, window.height),
+        }
+    }
+
+    /// Rerun detection and placement against the recorded outputs, returning the
+    /// monitor and window geometry this machine computes from them
+    pub fn replay(&self) -> (Rect, Rect) {
+        let env = Env::from(&self.env);
+        let screen = ScreenInfo::detect_with(
+            &self.config.screen,
+            &env,
+            &ReplayRunner { recording: self },
+            &ReplayFileSystem { recording: self },
+        );
+        let window = self.config.window_rect(&screen);
+        (Rect::new(screen.x, screen.y, screen.width, screen.height), window)
+    }
+
+    pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    pub fn load_from_path<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        Ok(ron::from_str(&content)?)
+    }
+}
+
+/// Replace the home directory with `~` so recordings don't carry user names
+pub fn redact_path(path: &Path) -> String {
+    if let Some(home) = std::env::var_os("HOME").filter(|home| !home.is_empty()) {
+        if let Ok(rest) = path.strip_prefix(&home) {
+            return Path::new("~").join(rest).display().to_string();
+        }
+    }
+    path.display().to_string()
+}
+
+/// Inverse of `redact_path` on the replaying machine
+pub fn expand_path(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Passes commands through to `inner`, keeping a copy of every result
+pub struct RecordingRunner<'a> {
+    inner: &'a dyn CommandRunner,
+    commands: RefCell<Vec<RecordedCommand>>,
+}
+
+impl CommandRunner for RecordingRunner<'_> {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        let result = self.inner.run(program, args);
+        let (success, stdout, error) = match &result {
+            Ok(output) => (output.success, output.stdout.clone(), None),
+            Err(e) => (false, String::new(), Some(e.to_string())),
+        };
+        self.commands.borrow_mut().push(RecordedCommand {
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            success,
+            stdout,
+            error,
+        });
+        result
+    }
+}
+
+/// Passes reads through to `inner`, keeping a copy of every result
+pub struct RecordingFileSystem<'a> {
+    inner: &'a dyn FileSystem,
+    reads: RefCell<Vec<RecordedRead>>,
+}
+
+impl FileSystem for RecordingFileSystem<'_> {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let result = self.inner.read_dir(path);
+        self.reads.borrow_mut().push(RecordedRead::Dir {
+            path: redact_path(path),
+            entries: match &result {
+                Ok(entries) => Ok(entries.iter().map(|entry| redact_path(entry)).collect()),
+                Err(e) => Err(e.to_string()),
+            },
+        });
+        result
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let result = self.inner.read(path);
+        self.reads.borrow_mut().push(RecordedRead::File {
+            path: redact_path(path),
+            contents: match &result {
+                Ok(contents) => Ok(contents.clone()),
+                Err(e) => Err(e.to_string()),
+            },
+        });
+        result
+    }
+}
+
+/// Answers commands from a recording; commands that were never recorded are not found
+pub struct ReplayRunner<'a> {
+    recording: &'a Recording,
+}
+
+impl CommandRunner for ReplayRunner<'_> {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        let recorded = self
+            .recording
+            .commands
+            .iter()
+            .find(|command| command.program == program && command.args == args);
+
+        let Some(recorded) = recorded else {
+            eprintln!("replay: {} {:?} was not recorded", program, args);
+            return Err(io::Error::new(io::ErrorKind::NotFound, program.to_string()));
+        };
+        eprintln!(
+            "replay: {} {:?} -> success {}, {} bytes",
+            program,
+            args,
+            recorded.success,
+            recorded.stdout.len()
+        );
+        match &recorded.error {
+            Some(error) => Err(io::Error::other(error.clone())),
+            None => Ok(CommandOutput {
+                success: recorded.success,
+                stdout: recorded.stdout.clone(),
+            }),
+        }
+    }
+}
+
+/// Answers reads from a recording; paths that were never read are not found
+pub struct ReplayFileSystem<'a> {
+    recording: &'a Recording,
+}
+
+impl FileSystem for ReplayFileSystem<'_> {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let wanted = redact_path(path);
+        let entries = self.recording.reads.iter().find_map(|read| match read {
+            RecordedRead::Dir { path, entries } if *path == wanted => Some(entries),
+            _ => None,
+        });
+        match entries {
+            Some(Ok(entries)) => Ok(entries.iter().map(|entry| expand_path(entry)).collect()),
+            Some(Err(e)) => Err(io::Error::other(e.clone())),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, wanted)),
+        }
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let wanted = redact_path(path);
+        let contents = self.recording.reads.iter().find_map(|read| match read {
+            RecordedRead::File { path, contents } if *path == wanted => Some(contents),
+            _ => None,
+        });
+        match contents {
+            Some(Ok(contents)) => Ok(contents.clone()),
+            Some(Err(e)) => Err(io::Error::other(e.clone())),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, wanted)),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::env::Env;
+
+/// A single way of detecting the screen, tried in order until one succeeds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectionBackend {
+    /// `hyprctl activewindow -j`, monitor hosting the active window
+    HyprlandActiveWindow,
+    /// `hyprctl monitors -j`, monitor marked focused
+    HyprlandFocused,
+    /// `swaymsg`, output of the focused workspace
+    SwayFocused,
+    /// `wlr-randr`, works on most wlroots-based compositors
+    WlrRandr,
+    /// `hyprctl monitors -j`, first monitor
+    HyprlandMonitors,
+    /// `swaymsg -t get_outputs`, first output with a current mode
+    SwayOutputs,
+    /// `xdotool` + `xrandr`, monitor under the mouse cursor
+    X11,
+    /// `~/.local/share/kscreen`, KDE's saved output layout, no D-Bus needed.
+    /// Saved rather than live, so it only beats sysfs.
+    Kscreen,
+    /// `/sys/class/drm`, no compositor needed (Linux only)
+    Sysfs,
+}
+
+impl DetectionBackend {
+    /// Every backend in precedence order: Wayland focused, Wayland primary, X11,
+    /// saved KDE layout, sysfs
+    pub const ALL: [DetectionBackend; 9] = [
+        DetectionBackend::HyprlandActiveWindow,
+        DetectionBackend::HyprlandFocused,
+        DetectionBackend::SwayFocused,
+        DetectionBackend::WlrRandr,
+        DetectionBackend::HyprlandMonitors,
+        DetectionBackend::SwayOutputs,
+        DetectionBackend::X11,
+        DetectionBackend::Kscreen,
+        DetectionBackend::Sysfs,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DetectionBackend::HyprlandActiveWindow => "Hyprland active window",
+            DetectionBackend::HyprlandFocused => "Hyprland focused",
+            DetectionBackend::SwayFocused => "Sway focused",
+            DetectionBackend::WlrRandr => "wlr-randr",
+            DetectionBackend::HyprlandMonitors => "Hyprland monitors",
+            DetectionBackend::SwayOutputs => "Sway outputs",
+            DetectionBackend::X11 => "X11/XWayland",
+            DetectionBackend::Kscreen => "kscreen config",
+            DetectionBackend::Sysfs => "sysfs",
+        }
+    }
+
+    /// Whether this backend looks for the focused monitor rather than the primary one
+    pub fn is_focused(self) -> bool {
+        matches!(
+            self,
+            DetectionBackend::HyprlandActiveWindow | DetectionBackend::HyprlandFocused | DetectionBackend::SwayFocused
+        )
+    }
+
+    pub fn is_wayland(self) -> bool {
+        matches!(
+            self,
+            DetectionBackend::HyprlandActiveWindow
+                | DetectionBackend::HyprlandFocused
+                | DetectionBackend::SwayFocused
+                | DetectionBackend::WlrRandr
+                | DetectionBackend::HyprlandMonitors
+                | DetectionBackend::SwayOutputs
+        )
+    }
+
+    /// Whether this backend exists on `target_os` (as in `std::env::consts::OS`).
+    /// The compositor and X11 tools work on any Unix; sysfs is Linux-only.
+    pub fn supported_on(self, target_os: &str) -> bool {
+        self != DetectionBackend::Sysfs || target_os == "linux"
+    }
+
+    /// Whether this backend can possibly work in `env`. Errs on the side of trying:
+    /// an unidentified session tries everything, and any Wayland session tries the
+    /// generic wlr-randr path even when the compositor is unknown.
+    pub fn applicable(self, env: &Env) -> bool {
+        if !self.supported_on(std::env::consts::OS) {
+            return false;
+        }
+        if env.is_unknown() {
+            return true;
+        }
+
+        match self {
+            DetectionBackend::HyprlandActiveWindow
+            | DetectionBackend::HyprlandFocused
+            | DetectionBackend::HyprlandMonitors => {
+                env.is_wayland() && env.is_hyprland()
+            }
+            DetectionBackend::SwayFocused | DetectionBackend::SwayOutputs => {
+                env.is_wayland() && env.is_sway()
+            }
+            DetectionBackend::WlrRandr => env.is_wayland(),
+            // Also covers XWayland, where DISPLAY and WAYLAND_DISPLAY are both set
+            DetectionBackend::X11 => env.has_x11(),
+            // Plasma writes the same files on X11 and Wayland
+            DetectionBackend::Kscreen => env.is_kde(),
+            DetectionBackend::Sysfs => true,
+        }
+    }
+}
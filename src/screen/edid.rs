@@ -0,0 +1,101 @@
+/// Fixed 8-byte header every EDID block starts with
+const EDID_HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+
+/// Offsets of the four 18-byte display descriptors in the base block
+const DESCRIPTOR_OFFSETS: [usize; 4] = [54, 72, 90, 108];
+
+/// Descriptor tag for the monitor name string
+const MONITOR_NAME_TAG: u8 = 0xFC;
+
+/// Identification data decoded from an EDID base block
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdidInfo {
+    /// Three letter PNP manufacturer ID, e.g. "DEL"
+    pub manufacturer: String,
+    /// Manufacturer product code
+    pub product_code: u16,
+    /// Monitor name from the 0xFC descriptor, e.g. "DELL U2722D"
+    pub monitor_name: Option<String>,
+    /// Physical image width and height in millimetres
+    pub physical_mm: Option<(f32, f32)>,
+}
+
+impl EdidInfo {
+    /// Human readable display name: the monitor name descriptor when present,
+    /// otherwise manufacturer ID plus product code
+    pub fn display_name(&self) -> String {
+        match &self.monitor_name {
+            Some(name) => name.clone(),
+            None => format!("{} {:04X}", self.manufacturer, self.product_code),
+        }
+    }
+}
+
+/// Parse the identification section (bytes 8–17) and monitor name descriptor of an EDID blob.
+/// None for a short buffer, a wrong header or a base block whose checksum doesn't add up.
+pub fn parse_edid(bytes: &[u8]) -> Option<EdidInfo> {
+    if bytes.len() < 128 || bytes[..8] != EDID_HEADER {
+        return None;
+    }
+    // All 128 bytes of the base block sum to 0 modulo 256
+    if bytes[..128].iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0 {
+        return None;
+    }
+
+    // Bytes 8-9: big-endian, three 5-bit letters where 1 = 'A'
+    let raw_id = u16::from_be_bytes([bytes[8], bytes[9]]);
+    let mut manufacturer = String::with_capacity(3);
+    for shift in [10, 5, 0] {
+        let letter = ((raw_id >> shift) & 0x1F) as u8;
+        if !(1..=26).contains(&letter) {
+            return None;
+        }
+        manufacturer.push((b'A' + letter - 1) as char);
+    }
+
+    // Bytes 10-11: little-endian product code
+    let product_code = u16::from_le_bytes([bytes[10], bytes[11]]);
+
+    let monitor_name = DESCRIPTOR_OFFSETS.iter().find_map(|&offset| {
+        let descriptor = &bytes[offset..offset + 18];
+        // Display descriptors start with a zero pixel clock
+        if descriptor[0] != 0 || descriptor[1] != 0 || descriptor[3] != MONITOR_NAME_TAG {
+            return None;
+        }
+        let text: String = descriptor[5..]
+            .iter()
+            .take_while(|&&b| b != 0x0A)
+            .map(|&b| b as char)
+            .collect();
+        let text = text.trim().to_string();
+        if text.is_empty() { None } else { Some(text) }
+    });
+
+    Some(EdidInfo {
+        manufacturer,
+        product_code,
+        monitor_name,
+        physical_mm: parse_physical_size(bytes),
+    })
+}
+
+/// Physical image size in millimetres. The first detailed timing descriptor has
+/// millimetre precision; bytes 21-22 only give whole centimetres, so they are the fallback.
+fn parse_physical_size(bytes: &[u8]) -> Option<(f32, f32)> {
+    let timing = &bytes[DESCRIPTOR_OFFSETS[0]..DESCRIPTOR_OFFSETS[0] + 18];
+    // A non-zero pixel clock marks a detailed timing descriptor
+    if timing[0] != 0 || timing[1] != 0 {
+        let width_mm = u16::from(timing[12]) | (u16::from(timing[14] & 0xF0) << 4);
+        let height_mm = u16::from(timing[13]) | (u16::from(timing[14] & 0x0F) << 8);
+        if width_mm > 0 && height_mm > 0 {
+            return Some((f32::from(width_mm), f32::from(height_mm)));
+        }
+    }
+
+    // Zero here means a projector or an undefined size
+    let (width_cm, height_cm) = (bytes[21], bytes[22]);
+    if width_cm > 0 && height_cm > 0 {
+        return Some((f32::from(width_cm) * 10.0, f32::from(height_cm) * 10.0));
+    }
+    None
+}
@@ -0,0 +1,381 @@
+//! Captured output of the external tools used for detection, shared by the
+//! benchmarks and anything else that needs realistic parser input.
+
+/// `hyprctl activewindow -j` for a window on the first monitor of `HYPRCTL_MONITORS`,
+/// while the mouse left the second one marked focused
+pub const HYPRCTL_ACTIVEWINDOW: &str = r#"{
+    "address": "0x5d3a2c10",
+    "mapped": true,
+    "hidden": false,
+    "at": [12, 40],
+    "size": [2536, 1388],
+    "workspace": {
+        "id": 1,
+        "name": "1"
+    },
+    "floating": false,
+    "pseudo": false,
+    "monitor": 0,
+    "class": "kitty",
+    "title": "vim",
+    "initialClass": "kitty",
+    "initialTitle": "kitty",
+    "pid": 4242,
+    "xwayland": false,
+    "pinned": false,
+    "fullscreen": 0,
+    "fullscreenClient": 0,
+    "grouped": [],
+    "tags": [],
+    "swallowing": "0x0",
+    "focusHistoryID": 0
+}"#;
+
+/// `hyprctl monitors -j` with a landscape and a portrait monitor, second focused
+pub const HYPRCTL_MONITORS: &str = r#"[{
+    "id": 0,
+    "name": "DP-1",
+    "description": "Dell Inc. DELL U2722D 8GXXXX3",
+    "make": "Dell Inc.",
+    "model": "DELL U2722D",
+    "serial": "8GXXXX3",
+    "width": 2560,
+    "height": 1440,
+    "refreshRate": 59.95100,
+    "x": 0,
+    "y": 0,
+    "activeWorkspace": {
+        "id": 1,
+        "name": "1"
+    },
+    "specialWorkspace": {
+        "id": 0,
+        "name": ""
+    },
+    "reserved": [0, 0, 0, 0],
+    "scale": 1.00,
+    "transform": 0,
+    "focused": false,
+    "dpmsStatus": true,
+    "vrr": false,
+    "activelyTearing": false
+},{
+    "id": 1,
+    "name": "HDMI-A-1",
+    "description": "LG Electronics LG HDR 4K 0x0000C2F1",
+    "make": "LG Electronics",
+    "model": "LG HDR 4K",
+    "serial": "0x0000C2F1",
+    "width": 1440,
+    "height": 2560,
+    "refreshRate": 60.00000,
+    "x": 2560,
+    "y": 0,
+    "activeWorkspace": {
+        "id": 2,
+        "name": "2"
+    },
+    "specialWorkspace": {
+        "id": 0,
+        "name": ""
+    },
+    "reserved": [0, 0, 0, 0],
+    "scale": 1.00,
+    "transform": 1,
+    "focused": true,
+    "dpmsStatus": true,
+    "vrr": false,
+    "activelyTearing": false
+}]"#;
+
+/// `swaymsg -t get_workspaces` with workspace 2 focused on HDMI-A-1
+pub const SWAYMSG_WORKSPACES: &str = r#"[
+  {
+    "num": 1,
+    "name": "1",
+    "visible": true,
+    "focused": false,
+    "output": "eDP-1"
+  },
+  {
+    "num": 2,
+    "name": "2",
+    "visible": true,
+    "focused": true,
+    "output": "HDMI-A-1"
+  }
+]"#;
+
+/// `swaymsg -t get_outputs` for a laptop panel plus an external monitor
+pub const SWAYMSG_OUTPUTS: &str = r#"[
+  {
+    "id": 4,
+    "type": "output",
+    "name": "eDP-1",
+    "active": true,
+    "make": "BOE",
+    "model": "0x095F",
+    "scale": 1.0,
+    "modes": [
+      {
+        "width": 1920,
+        "height": 1080,
+        "refresh": 60049,
+        "current": true
+      }
+    ]
+  },
+  {
+    "id": 5,
+    "type": "output",
+    "name": "HDMI-A-1",
+    "active": true,
+    "make": "Dell Inc.",
+    "model": "DELL U2722D",
+    "scale": 1.0,
+    "modes": [
+      {
+        "width": 1920,
+        "height": 1080,
+        "refresh": 60000,
+        "current": false
+      },
+      {
+        "width": 2560,
+        "height": 1440,
+        "refresh": 59951,
+        "current": true
+      }
+    ]
+  }
+]"#;
+
+/// `xrandr --current` with a primary landscape and a rotated portrait monitor
+pub const XRANDR_CURRENT: &str = "\
+Screen 0: minimum 320 x 200, current 4000 x 2560, maximum 16384 x 16384
+DP-1 connected primary 2560x1440+0+0 (normal left inverted right x axis y axis) 597mm x 336mm
+   2560x1440     59.95*+
+   1920x1080     60.00    59.94
+   1280x720      60.00
+HDMI-1 connected 1440x2560+2560+0 left (normal left inverted right x axis y axis) 597mm x 336mm
+   2560x1440     59.95 +
+   1920x1080     60.00
+DP-2 disconnected (normal left inverted right x axis y axis)
+";
+
+/// `xrandr --verbose --current` for the same two monitors, trimmed to the lines the parsers look at
+pub const XRANDR_VERBOSE: &str = "\
+Screen 0: minimum 320 x 200, current 4000 x 2560, maximum 16384 x 16384
+DP-1 connected primary 2560x1440+0+0 (0x48) normal (normal left inverted right x axis y axis) 597mm x 336mm
+	Identifier: 0x42
+	Timestamp:  21452
+	Subpixel:   unknown
+	Gamma:      1.0:1.0:1.0
+	Brightness: 1.0
+	CRTC:       0
+	CRTCs:      0 1 2
+	Transform:  1.000000 0.000000 0.000000
+	            0.000000 1.000000 0.000000
+	            0.000000 0.000000 1.000000
+	           filter: 
+  2560x1440 (0x48) 241.500MHz +HSync -VSync *current +preferred
+        h: width  2560 start 2608 end 2640 total 2720 skew    0 clock  88.79KHz
+        v: height 1440 start 1443 end 1448 total 1481           clock  59.95Hz
+  1920x1080 (0x1a3) 148.500MHz +HSync +VSync
+        h: width  1920 start 2008 end 2052 total 2200 skew    0 clock  67.50KHz
+        v: height 1080 start 1084 end 1089 total 1125           clock  60.00Hz
+HDMI-1 connected 1440x2560+2560+0 (0x48) left (normal left inverted right x axis y axis) 597mm x 336mm
+	Identifier: 0x43
+	CRTC:       1
+  2560x1440 (0x48) 241.500MHz +HSync -VSync *current +preferred
+        h: width  2560 start 2608 end 2640 total 2720 skew    0 clock  88.79KHz
+        v: height 1440 start 1443 end 1448 total 1481           clock  59.95Hz
+DP-2 disconnected (normal left inverted right x axis y axis)
+	Identifier: 0x44
+";
+
+/// `~/.local/share/kscreen/<hash>` with the laptop panel disabled and a 4K monitor at 150%
+pub const KSCREEN_CONFIG: &str = r#"[
+    {
+        "enabled": false,
+        "id": "3a1dbbd5e0c1e4f4a2b6e3c5a7f1d9b2",
+        "metadata": {
+            "fullname": "xrandr-BOE-0x095F-unknown",
+            "name": "eDP-1"
+        },
+        "mode": {
+            "refresh": 60.049,
+            "size": {
+                "height": 1080,
+                "width": 1920
+            }
+        },
+        "overscan": 0,
+        "pos": {
+            "x": 0,
+            "y": 0
+        },
+        "priority": 0,
+        "rotation": 1,
+        "scale": 1,
+        "vrrpolicy": 2
+    },
+    {
+        "enabled": true,
+        "id": "8c4e2f6a1b3d5e7f9a0c2e4b6d8f1a3c",
+        "metadata": {
+            "fullname": "xrandr-LG Electronics-LG HDR 4K-0x0000C2F1",
+            "name": "HDMI-A-1"
+        },
+        "mode": {
+            "refresh": 60,
+            "size": {
+                "height": 2160,
+                "width": 3840
+            }
+        },
+        "overscan": 0,
+        "pos": {
+            "x": 1920,
+            "y": 0
+        },
+        "priority": 1,
+        "rotation": 1,
+        "scale": 1.5,
+        "vrrpolicy": 2
+    }
+]"#;
+
+/// kscreen output config of the same laptop undocked, with its panel enabled
+pub const KSCREEN_CONFIG_LAPTOP: &str = r#"[
+    {
+        "enabled": true,
+        "id": "3a1dbbd5e0c1e4f4a2b6e3c5a7f1d9b2",
+        "metadata": {
+            "fullname": "xrandr-BOE-0x095F-unknown",
+            "name": "eDP-1"
+        },
+        "mode": {
+            "refresh": 60.049,
+            "size": {
+                "height": 1080,
+                "width": 1920
+            }
+        },
+        "overscan": 0,
+        "pos": {
+            "x": 0,
+            "y": 0
+        },
+        "priority": 1,
+        "rotation": 1,
+        "scale": 1,
+        "vrrpolicy": 2
+    }
+]"#;
+
+/// `wlr-randr` for a single enabled output
+pub const WLR_RANDR: &str = "\
+DP-1 \"Dell Inc. DELL U2722D 8GXXXX3 (DP-1)\"
+  Make: Dell Inc.
+  Model: DELL U2722D
+  Serial: 8GXXXX3
+  Physical size: 600x340 mm
+  Enabled: yes
+  Modes:
+    2560x1440 px, 59.951000 Hz (preferred, current)
+    1920x1080 px, 60.000000 Hz
+    1280x720 px, 60.000000 Hz
+  Position: 0,0
+  Transform: normal
+  Scale: 1.000000
+  Adaptive Sync: disabled
+";
+
+/// `wlr-randr` for a laptop panel and an external monitor to its right
+pub const WLR_RANDR_TWO_OUTPUTS: &str = "\
+eDP-1 \"BOE 0x095F Unknown (eDP-1)\"
+  Make: BOE
+  Model: 0x095F
+  Serial: Unknown
+  Physical size: 290x170 mm
+  Enabled: yes
+  Modes:
+    1920x1080 px, 60.049000 Hz (preferred, current)
+  Position: 0,0
+  Transform: normal
+  Scale: 1.000000
+  Adaptive Sync: disabled
+HDMI-A-1 \"LG Electronics LG HDR 4K 0x0000C2F1 (HDMI-A-1)\"
+  Make: LG Electronics
+  Model: LG HDR 4K
+  Serial: 0x0000C2F1
+  Physical size: 600x340 mm
+  Enabled: yes
+  Modes:
+    3840x2160 px, 60.000000 Hz (preferred)
+    2560x1440 px, 59.951000 Hz (current)
+    1920x1080 px, 60.000000 Hz
+  Position: 1920,0
+  Transform: normal
+  Scale: 1.000000
+  Adaptive Sync: disabled
+";
+
+/// `wlr-randr` while an output is being reconfigured: no mode is marked current
+/// and a disabled output is listed first
+pub const WLR_RANDR_NO_CURRENT: &str = "\
+HDMI-A-2 \"Samsung Electric Company C27F390 (HDMI-A-2)\"
+  Enabled: no
+  Modes:
+    1920x1080 px, 60.000000 Hz (preferred)
+DP-2 \"ASUSTek COMPUTER INC VG27AQ (DP-2)\"
+  Make: ASUSTek COMPUTER INC
+  Model: VG27AQ
+  Enabled: yes
+  Modes:
+    1920x1080 px, 60.000000 Hz (preferred)
+    2560x1440 px, 59.951000 Hz (preferred)
+    3840x2160 px, 30.000000 Hz
+  Position: 0,0
+";
+
+/// `wlr-randr` for a high refresh rate monitor without a current mode and
+/// without a preferred one
+pub const WLR_RANDR_REFRESH_RATES: &str = "\
+DP-3 \"Dell Inc. AW2521H (DP-3)\"
+  Enabled: yes
+  Modes:
+    1920x1080 px, 60.000000 Hz
+    1920x1080 px, 240.001999 Hz
+    1920x1080 px, 144.001007 Hz
+    1280x720 px, 360.000000 Hz
+  Position: 0,0
+";
+
+/// `/sys/class/drm/card0-DP-1/modes`
+pub const DRM_MODES: &str = "\
+2560x1440
+1920x1080
+1920x1080i
+1280x720
+";
+
+/// `/sys/class/drm/card0-VGA-1/modes` for a connector stuck at a safe fallback mode
+pub const DRM_MODES_LOW_RES: &str = "\
+640x480
+";
+
+/// `/sys/class/drm/card0-DP-1/edid` base block of a 27" 2560x1440 monitor: manufacturer
+/// DEL, product 0x4199, 597x336 mm in the detailed timing, 60x34 cm in the basic
+/// parameters, and a "DELL U2722D" monitor name descriptor
+pub const EDID_DELL_U2722D: [u8; 128] = [
+    0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x10, 0xAC, 0x99, 0x41, 0x4C, 0x45, 0x30, 0x31,
+    0x0C, 0x20, 0x01, 0x04, 0xA5, 0x3C, 0x22, 0x78, 0x3A, 0xEE, 0x95, 0xA3, 0x54, 0x4C, 0x99, 0x26,
+    0x0F, 0x50, 0x54, 0xA5, 0x4B, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x56, 0x5E, 0x00, 0xA0, 0xA0, 0xA0, 0x29, 0x50, 0x30, 0x20,
+    0x35, 0x00, 0x55, 0x50, 0x21, 0x00, 0x00, 0x1A, 0x00, 0x00, 0x00, 0xFC, 0x00, 0x44, 0x45, 0x4C,
+    0x4C, 0x20, 0x55, 0x32, 0x37, 0x32, 0x32, 0x44, 0x0A, 0x20, 0x00, 0x00, 0x00, 0xFD, 0x00, 0x31,
+    0x4C, 0x1E, 0x5A, 0x19, 0x00, 0x0A, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0xFF,
+    0x00, 0x37, 0x43, 0x39, 0x4C, 0x43, 0x4B, 0x33, 0x0A, 0x20, 0x20, 0x20, 0x20, 0x20, 0x01, 0xE2,
+];
@@ -1,162 +1,632 @@
-use std::process::Command;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::config::screen::ScreenConfig;
+use crate::crash;
+use crate::env::Env;
+
+pub mod backend;
+pub mod edid;
+pub mod fixtures;
+pub mod prewarm;
+pub mod runner;
+pub mod selection;
+
+use backend::DetectionBackend;
+use runner::{CommandRunner, FileSystem, SystemFileSystem, SystemRunner};
+use selection::MonitorInfo;
+
+#[derive(Debug)]
+pub enum ScreenError {
+    /// Reading a sysfs or config file failed
+    Io(std::io::Error),
+    /// No connected output reported a usable mode
+    NoConnectedOutputs,
+    /// A backend reported a size below `min_width`/`min_height`
+    BelowMinimumThreshold { width: f32, height: f32 },
+}
+
+impl fmt::Display for ScreenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScreenError::Io(e) => write!(f, "I/O error during screen detection: {}", e),
+            ScreenError::NoConnectedOutputs => write!(f, "No connected outputs found"),
+            ScreenError::BelowMinimumThreshold { width, height } => {
+                write!(f, "Detected {}x{} is below the configured minimum", width, height)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScreenError {}
+
+impl From<std::io::Error> for ScreenError {
+    fn from(e: std::io::Error) -> Self {
+        ScreenError::Io(e)
+    }
+}
+
+/// A single connected output as reported by one of the listing backends
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenInfoEntry {
+    /// Connector name, e.g. "DP-1"
+    pub connector: String,
+    /// Display name, e.g. "DELL U2722D"
+    pub name: String,
+    /// Origin in the global layout (0 when the backend doesn't report it)
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// Physical width and height in millimetres, when the backend reports it
+    pub physical_mm: Option<(f32, f32)>,
+}
+
+impl ScreenInfoEntry {
+    /// Connector or display name equals `name`, ignoring case
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.connector.eq_ignore_ascii_case(name) || self.name.eq_ignore_ascii_case(name)
+    }
+}
+
+/// One line of the `Modes:` list in `wlr-randr` output,
+/// e.g. `2560x1440 px, 59.951000 Hz (preferred, current)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WlrRandrMode {
+    pub width: f32,
+    pub height: f32,
+    /// Refresh rate in Hz
+    pub refresh: f32,
+    pub preferred: bool,
+    pub current: bool,
+}
+
+impl WlrRandrMode {
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (resolution, rest) = line.split_once(" px,")?;
+        let (width, height) = resolution.split_once('x')?;
+        let refresh = rest.split_whitespace().next()?;
+        let flags = rest.split_once('(').map_or("", |(_, flags)| flags);
+        Some(WlrRandrMode {
+            width: width.parse().ok()?,
+            height: height.parse().ok()?,
+            refresh: refresh.parse().ok()?,
+            preferred: flags.contains("preferred"),
+            current: flags.contains("current"),
+        })
+    }
+
+    /// The current mode; without one, the largest preferred mode (any mode when
+    /// none is preferred), breaking resolution ties by the highest refresh rate
+    pub fn select(modes: &[WlrRandrMode]) -> Option<WlrRandrMode> {
+        if let Some(current) = modes.iter().find(|mode| mode.current) {
+            return Some(*current);
+        }
+        let any_preferred = modes.iter().any(|mode| mode.preferred);
+        modes
+            .iter()
+            .filter(|mode| mode.preferred || !any_preferred)
+            .max_by(|a, b| {
+                (a.width * a.height)
+                    .total_cmp(&(b.width * b.height))
+                    .then(a.refresh.total_cmp(&b.refresh))
+            })
+            .copied()
+    }
+}
 
 pub struct ScreenInfo {
+    /// Monitor origin in the global layout (0 when the backend doesn't report it)
+    pub x: f32,
+    pub y: f32,
     pub width: f32,
     pub height: f32,
+    /// Physical width and height in millimetres, from EDID when enabled
+    pub physical_mm: Option<(f32, f32)>,
+    /// Mouse pointer in the global layout, when detection looked at it
+    pub cursor_x: Option<f32>,
+    pub cursor_y: Option<f32>,
 }
 
 impl Default for ScreenInfo {
     fn default() -> Self {
+        Self::new(1920.0, 1080.0)
+    }
+}
+
+impl From<&ScreenInfoEntry> for ScreenInfo {
+    fn from(entry: &ScreenInfoEntry) -> Self {
         Self {
-            width: 1920.0,
-            height: 1080.0,
+            x: entry.x,
+            y: entry.y,
+            physical_mm: entry.physical_mm,
+            ..Self::new(entry.width, entry.height)
         }
     }
 }
 
 impl ScreenInfo {
-    pub fn detect() -> Self {
-        let result = Self::detect_linux().unwrap_or_default();
+    /// Screen at the layout origin with unknown physical size
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            width,
+            height,
+            physical_mm: None,
+            cursor_x: None,
+            cursor_y: None,
+        }
+    }
+
+    /// Mouse pointer relative to this monitor's origin, when known
+    pub fn cursor_on_screen(&self) -> Option<(f32, f32)> {
+        Some((self.cursor_x? - self.x, self.cursor_y? - self.y))
+    }
+
+    /// Fill in the pointer position when detection didn't, asking the compositor
+    /// (Hyprland) or the X server. Left unset when neither answers. Under Wayland
+    /// XWayland only sees the pointer over X11 windows, so it isn't asked.
+    pub fn locate_cursor(&mut self, env: &Env, runner: &dyn CommandRunner) {
+        if self.cursor_x.is_some() && self.cursor_y.is_some() {
+            return;
+        }
+        let hyprland = || {
+            let output = runner.run("hyprctl", &["cursorpos"]).ok().filter(|output| output.success)?;
+            Self::parse_hyprctl_cursorpos(&output.stdout)
+        };
+        let x11 = || {
+            let output = runner
+                .run("xdotool", &["getmouselocation", "--shell"])
+                .ok()
+                .filter(|output| output.success)?;
+            Self::parse_xdotool_mouse_location(&output.stdout).map(|(x, y)| (x as f32, y as f32))
+        };
+        let mut cursor = None;
+        if env.is_hyprland() {
+            cursor = hyprland();
+        }
+        if cursor.is_none() && !env.is_wayland() && env.has_x11() {
+            cursor = x11();
+        }
+        if let Some((x, y)) = cursor {
+            self.cursor_x = Some(x);
+            self.cursor_y = Some(y);
+        }
+    }
+
+    /// Parse `hyprctl cursorpos`, e.g. "1234, 567"
+    pub fn parse_hyprctl_cursorpos(output: &str) -> Option<(f32, f32)> {
+        let (x, y) = output.trim().split_once(',')?;
+        Some((x.trim().parse().ok()?, y.trim().parse().ok()?))
+    }
+
+    /// Parse `xdotool getmouselocation --shell`, which prints `X=...` and `Y=...` lines
+    pub fn parse_xdotool_mouse_location(output: &str) -> Option<(i32, i32)> {
+        let mut mouse_x = None;
+        let mut mouse_y = None;
+        for line in output.lines() {
+            if let Some(x) = line.strip_prefix("X=") {
+                mouse_x = x.trim().parse().ok();
+            } else if let Some(y) = line.strip_prefix("Y=") {
+                mouse_y = y.trim().parse().ok();
+            }
+        }
+        Some((mouse_x?, mouse_y?))
+    }
+
+    /// Size in logical pixels of a `width`x`height` mode at `scale`, the unit window
+    /// positions are in. A missing or non-positive scale counts as 1.
+    pub fn logical_size(width: f32, height: f32, scale: f32) -> (f32, f32) {
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+        (width / scale, height / scale)
+    }
+
+    /// Horizontal dots per inch, when the physical size is known
+    pub fn dpi(&self) -> Option<f32> {
+        let (width_mm, _) = self.physical_mm?;
+        if width_mm <= 0.0 {
+            return None;
+        }
+        Some(self.width / (width_mm / 25.4))
+    }
+
+    pub fn detect(config: &ScreenConfig, env: &Env) -> Self {
+        Self::detect_with(config, env, &SystemRunner, &SystemFileSystem)
+    }
+
+    /// Detect using the given command runner and filesystem (mockable for tests and benchmarks)
+    pub fn detect_with(
+        config: &ScreenConfig,
+        env: &Env,
+        runner: &dyn CommandRunner,
+        fs: &dyn FileSystem,
+    ) -> Self {
+        // A fixed resolution skips detection entirely
+        if let Some((width, height)) = config.fixed_resolution {
+            return ScreenInfo::new(width, height);
+        }
+
+        // Only outputs with a known origin can be forced, or the popup would open
+        // at the wrong place on a multi-monitor layout
+        let forced = config.force_monitor.as_ref().and_then(|name| {
+            let monitors: Vec<MonitorInfo> =
+                Self::list_placed(config, env, runner).into_iter().map(MonitorInfo::from).collect();
+            let screen = selection::forced_monitor(&monitors, config).map(|monitor| ScreenInfo::from(&monitor.entry));
+            if screen.is_none() {
+                eprintln!(
+                    "Monitor {:?} not found or its position is unknown, falling back to normal detection",
+                    name
+                );
+            }
+            screen
+        });
+
+        let mut result = match forced {
+            Some(screen) => screen,
+            None => Self::detect_unix(config, env, runner, fs).unwrap_or_else(|e| {
+                let fallback = ScreenInfo::default();
+                eprintln!("{}, using {}x{}", e, fallback.width, fallback.height);
+                fallback
+            }),
+        };
+
+        if config.detect_physical_size {
+            result.physical_mm = Self::physical_size_from_edid(&result, fs);
+        }
         
         // Debug output to verify detection
         eprintln!("Detected screen resolution: {}x{}", result.width, result.height);
         result
     }
 
-    fn detect_linux() -> Result<Self, Box<dyn std::error::Error>> {
-        // First try Wayland focused screen detection
-        if let Ok(focused_screen) = Self::detect_wayland_focused_screen() {
-            return Ok(focused_screen);
+    /// List every connected output with its name and current resolution, asking
+    /// only the backends applicable in `env`
+    pub fn list_all(
+        config: &ScreenConfig,
+        env: &Env,
+        runner: &dyn CommandRunner,
+        fs: &dyn FileSystem,
+    ) -> Vec<ScreenInfoEntry> {
+        let placed = Self::list_placed(config, env, runner);
+        let sysfs = Self::applicable_backends(config, env).contains(&DetectionBackend::Sysfs);
+        if !placed.is_empty() || !sysfs {
+            return placed;
         }
 
-        // Fallback to Wayland primary screen detection
-        if let Ok(screen) = Self::detect_wayland_primary_screen() {
-            return Ok(screen);
+        // Deepest fallback: read connectors straight from the kernel. Good for names
+        // only, sysfs knows neither the origin nor the current mode.
+        match Self::detect_from_kms_drm(fs) {
+            Ok(entries) => entries,
+            Err(e) => {
+                eprintln!("KMS/DRM output listing failed: {}", e);
+                Vec::new()
+            }
         }
+    }
 
-        // Only use X11/XWayland as last resort when Wayland fails
-        eprintln!("Wayland detection failed, falling back to X11/XWayland...");
-        if let Ok(focused_screen) = Self::detect_x11_focused() {
-            return Ok(focused_screen);
-        }
+    /// Connected outputs with their origin in the global layout and their current
+    /// mode in logical pixels, from wlr-randr or else xrandr. Empty when neither
+    /// works, as nothing else reports where the outputs are. Like detection, each
+    /// tool is only run when its backend is applicable in `env`.
+    pub fn list_placed(
+        config: &ScreenConfig,
+        env: &Env,
+        runner: &dyn CommandRunner,
+    ) -> Vec<ScreenInfoEntry> {
+        let backends = Self::applicable_backends(config, env);
 
-        // Final fallback: try direct hardware detection
-        if let Ok(resolution) = Self::detect_from_sysfs() {
-            return Ok(resolution);
+        // wlr-randr reports connector, model and current mode in one call
+        if backends.contains(&DetectionBackend::WlrRandr) {
+            if let Ok(output) = runner.run("wlr-randr", &[]) {
+                if output.success {
+                    let entries = Self::parse_wlr_randr_entries(&output.stdout);
+                    if !entries.is_empty() {
+                        return entries;
+                    }
+                }
+            }
         }
 
-        Err("Could not detect screen resolution on Linux".into())
+        if !backends.contains(&DetectionBackend::X11) {
+            return Vec::new();
+        }
+        match runner.run("xrandr", &["--current"]) {
+            Ok(output) if output.success => Self::parse_xrandr_entries(&output.stdout),
+            _ => Vec::new(),
+        }
     }
 
-    fn detect_wayland_focused_screen() -> Result<ScreenInfo, Box<dyn std::error::Error>> {
-        // Try Hyprland focused monitor first (most detailed focus detection)
-        if let Ok(focused) = Self::detect_hyprland_focused() {
-            eprintln!("Using Hyprland focused screen detection");
-            return Ok(focused);
-        }
+    /// Backends worth trying in this session, in precedence order. Without
+    /// `prefer_focused_screen` the focused-monitor backends move behind the
+    /// primary-monitor ones, still ahead of X11 and sysfs.
+    pub fn applicable_backends(config: &ScreenConfig, env: &Env) -> Vec<DetectionBackend> {
+        let mut backends: Vec<DetectionBackend> = DetectionBackend::ALL
+            .into_iter()
+            .filter(|backend| backend.applicable(env))
+            .filter(|&backend| {
+                // X11 on a Wayland session means XWayland, only used when allowed
+                backend != DetectionBackend::X11 || !env.is_wayland() || config.allow_x11_fallback
+            })
+            .collect();
 
-        // Try Sway focused output
-        if let Ok(focused) = Self::detect_sway_focused() {
-            eprintln!("Using Sway focused screen detection");
-            return Ok(focused);
+        if !config.prefer_focused_screen {
+            let focused: Vec<DetectionBackend> =
+                backends.iter().copied().filter(|backend| backend.is_focused()).collect();
+            backends.retain(|backend| !backend.is_focused());
+            let at = backends
+                .iter()
+                .position(|&backend| matches!(backend, DetectionBackend::X11 | DetectionBackend::Sysfs))
+                .unwrap_or(backends.len());
+            backends.splice(at..at, focused);
         }
+        backends
+    }
 
-        Err("No Wayland focused screen detection available".into())
+    /// Err when the screen is smaller than `min_width` x `min_height`
+    pub fn check_minimum(&self, config: &ScreenConfig) -> Result<(), ScreenError> {
+        if self.width < config.min_width || self.height < config.min_height {
+            return Err(ScreenError::BelowMinimumThreshold {
+                width: self.width,
+                height: self.height,
+            });
+        }
+        Ok(())
     }
 
-    fn detect_wayland_primary_screen() -> Result<ScreenInfo, Box<dyn std::error::Error>> {
-        // Try wlr-randr first (works with most wlroots-based compositors)
-        if let Ok(output) = Command::new("wlr-randr").output() {
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                if let Some(resolution) = Self::parse_wlr_randr_output(&output_str) {
-                    eprintln!("Using wlr-randr for primary screen detection");
-                    return Ok(resolution);
-                }
+    /// Try each applicable backend in turn. Runs on Linux and the BSDs alike;
+    /// backends the OS doesn't have are left out by `applicable_backends`.
+    fn detect_unix(
+        config: &ScreenConfig,
+        env: &Env,
+        runner: &dyn CommandRunner,
+        fs: &dyn FileSystem,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut wayland_tried = false;
+        // What each backend did, for crash reports
+        let mut diagnostics = Vec::new();
+
+        for backend in Self::applicable_backends(config, env) {
+            if backend == DetectionBackend::X11 && wayland_tried {
+                eprintln!("Wayland detection failed, falling back to X11/XWayland...");
             }
-        }
+            wayland_tried |= backend.is_wayland();
 
-        // Try hyprctl for Hyprland (primary monitor)
-        if let Ok(output) = Command::new("hyprctl").arg("monitors").arg("-j").output() {
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                if let Some(resolution) = Self::parse_hyprctl_output(&output_str) {
-                    eprintln!("Using Hyprland for primary screen detection");
-                    return Ok(resolution);
+            match Self::detect_with_backend(backend, config, runner, fs) {
+                Ok(screen) => {
+                    // A known-bad size, e.g. a 640x480 fallback mode, counts as a failure
+                    if let Err(e) = screen.check_minimum(config) {
+                        eprintln!("Skipping {} screen detection: {}", backend.name(), e);
+                        diagnostics.push(format!("{}: {}", backend.name(), e));
+                        continue;
+                    }
+                    eprintln!("Using {} screen detection", backend.name());
+                    diagnostics.push(format!("{}: {}x{}", backend.name(), screen.width, screen.height));
+                    crash::update_context(|context| context.detection = diagnostics);
+                    return Ok(screen);
                 }
+                Err(e) => diagnostics.push(format!("{}: {}", backend.name(), e)),
             }
         }
 
-        // Try swaymsg for Sway (primary output)
-        if let Ok(output) = Command::new("swaymsg").arg("-t").arg("get_outputs").output() {
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                if let Some(resolution) = Self::parse_swaymsg_output(&output_str) {
-                    eprintln!("Using Sway for primary screen detection");
-                    return Ok(resolution);
-                }
+        crash::update_context(|context| context.detection = diagnostics);
+        Err("Could not detect screen resolution".into())
+    }
+
+    fn detect_with_backend(
+        backend: DetectionBackend,
+        config: &ScreenConfig,
+        runner: &dyn CommandRunner,
+        fs: &dyn FileSystem,
+    ) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+        match backend {
+            DetectionBackend::HyprlandActiveWindow => Self::detect_hyprland_active_window_monitor(runner),
+            DetectionBackend::HyprlandFocused => {
+                Self::detect_from_command(runner, "hyprctl", &["monitors", "-j"], Self::parse_hyprctl_focused)
+            }
+            DetectionBackend::SwayFocused => Self::detect_sway_focused(runner),
+            DetectionBackend::WlrRandr => {
+                Self::detect_from_command(runner, "wlr-randr", &[], Self::parse_wlr_randr_output)
             }
+            DetectionBackend::HyprlandMonitors => {
+                Self::detect_from_monitors(runner, "hyprctl", &["monitors", "-j"], Self::parse_hyprctl_monitors, config)
+            }
+            DetectionBackend::SwayOutputs => {
+                Self::detect_from_monitors(runner, "swaymsg", &["-t", "get_outputs"], Self::parse_sway_monitors, config)
+            }
+            DetectionBackend::X11 => Self::detect_x11_focused(config, runner),
+            DetectionBackend::Kscreen => Self::detect_from_kscreen(fs),
+            #[cfg(target_os = "linux")]
+            DetectionBackend::Sysfs => Self::detect_from_sysfs(fs),
+            #[cfg(not(target_os = "linux"))]
+            DetectionBackend::Sysfs => Err("sysfs detection is only available on Linux".into()),
         }
+    }
 
-        Err("No Wayland primary screen detection available".into())
+    /// Run `program` and parse its output with `parse`
+    fn detect_from_command(
+        runner: &dyn CommandRunner,
+        program: &str,
+        args: &[&str],
+        parse: fn(&str) -> Option<ScreenInfo>,
+    ) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+        let output = runner.run(program, args)?;
+        if !output.success {
+            return Err(format!("{} command failed", program).into());
+        }
+        parse(&output.stdout).ok_or_else(|| format!("Could not parse {} output", program).into())
     }
 
-    fn detect_hyprland_focused() -> Result<ScreenInfo, Box<dyn std::error::Error>> {
-        // Get active monitor from Hyprland
-        let output = Command::new("hyprctl")
-            .arg("monitors")
-            .arg("-j")
-            .output()?;
+    /// Run `program`, list its monitors with `parse` and pick one with `selection::select_monitor`
+    fn detect_from_monitors(
+        runner: &dyn CommandRunner,
+        program: &str,
+        args: &[&str],
+        parse: fn(&str) -> Vec<MonitorInfo>,
+        config: &ScreenConfig,
+    ) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+        let output = runner.run(program, args)?;
+        if !output.success {
+            return Err(format!("{} command failed", program).into());
+        }
+        let monitors = parse(&output.stdout);
+        selection::select_monitor(&monitors, config, None)
+            .map(|monitor| ScreenInfo::from(&monitor.entry))
+            .ok_or_else(|| format!("Could not parse {} output", program).into())
+    }
 
-        if !output.status.success() {
-            return Err("hyprctl command failed".into());
+    /// Monitor hosting the active window: `hyprctl activewindow -j` names its
+    /// monitor id, looked up in `hyprctl monitors -j`. With focus-follows-mouse
+    /// this can differ from the monitor marked focused.
+    fn detect_hyprland_active_window_monitor(
+        runner: &dyn CommandRunner,
+    ) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+        let window = runner.run("hyprctl", &["activewindow", "-j"])?;
+        if !window.success {
+            return Err("hyprctl activewindow failed".into());
+        }
+        let id = Self::parse_hyprctl_active_window_monitor(&window.stdout).ok_or("No active window")?;
+        let monitors = runner.run("hyprctl", &["monitors", "-j"])?;
+        if !monitors.success {
+            return Err("hyprctl monitors failed".into());
         }
+        Self::parse_hyprctl_monitor_by_id(&monitors.stdout, id)
+            .ok_or_else(|| format!("Active window's monitor {} not found", id).into())
+    }
 
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        
-        // Look for focused monitor (the one with "focused": true)
-        let mut in_focused_monitor = false;
+    /// Monitor id of the active window in `hyprctl activewindow -j` output. None
+    /// when no window is focused, which Hyprland reports as `{}` or "Invalid".
+    pub fn parse_hyprctl_active_window_monitor(output: &str) -> Option<i64> {
+        // Only the window object's own key, not one of a nested object
+        let mut depth = 0;
+        for line in output.lines().map(str::trim) {
+            if line.starts_with('}') {
+                depth -= 1;
+            }
+            if line.ends_with('{') {
+                depth += 1;
+            } else if depth == 1 && line.starts_with("\"monitor\":") {
+                let value = line.split(':').nth(1)?.trim().trim_end_matches(',');
+                return value.parse().ok();
+            }
+        }
+        None
+    }
+
+    /// Monitor with `"id": id` in `hyprctl monitors -j` output
+    pub fn parse_hyprctl_monitor_by_id(output: &str, id: i64) -> Option<ScreenInfo> {
+        Self::parse_hyprctl_monitor(output, |monitor_id, _| monitor_id == Some(id))
+    }
+
+    /// Monitor marked `"focused": true` in `hyprctl monitors -j` output
+    pub fn parse_hyprctl_focused(output: &str) -> Option<ScreenInfo> {
+        Self::parse_hyprctl_monitor(output, |_, focused| focused)
+    }
+
+    /// First monitor in `hyprctl monitors -j` output for which `wanted(id, focused)` holds
+    fn parse_hyprctl_monitor(output: &str, wanted: impl Fn(Option<i64>, bool) -> bool) -> Option<ScreenInfo> {
+        Self::hyprctl_monitors(output)
+            .into_iter()
+            .find(|(id, monitor)| wanted(*id, monitor.focused))
+            .map(|(_, monitor)| ScreenInfo::from(&monitor.entry))
+    }
+
+    /// Every monitor in `hyprctl monitors -j` output, focused as Hyprland marks it
+    pub fn parse_hyprctl_monitors(output: &str) -> Vec<MonitorInfo> {
+        Self::hyprctl_monitors(output).into_iter().map(|(_, monitor)| monitor).collect()
+    }
+
+    /// Monitors in `hyprctl monitors -j` output with their ids. The keys can come
+    /// in any order within a monitor (Hyprland prints width before focused), so
+    /// values are only used once the monitor object closes.
+    fn hyprctl_monitors(output: &str) -> Vec<(Option<i64>, MonitorInfo)> {
+        let mut monitors = Vec::new();
+        // Depth 1 is a monitor object, nested objects like activeWorkspace are deeper
+        let mut depth = 0;
+        let mut id = None;
+        let mut connector = String::new();
+        let mut name = String::new();
+        let mut focused = false;
         let mut width = None;
         let mut height = None;
-        
-        for line in output_str.lines() {
-            let line = line.trim();
-            
-            if line.contains("\"focused\": true") {
-                in_focused_monitor = true;
-            } else if in_focused_monitor && line.starts_with("\"width\":") {
-                if let Some(value_str) = line.split(':').nth(1) {
-                    let value_str = value_str.trim().trim_end_matches(',');
-                    width = value_str.parse().ok();
-                }
-            } else if in_focused_monitor && line.starts_with("\"height\":") {
-                if let Some(value_str) = line.split(':').nth(1) {
-                    let value_str = value_str.trim().trim_end_matches(',');
-                    height = value_str.parse().ok();
+        let mut x = 0.0;
+        let mut y = 0.0;
+
+        for line in output.lines().map(str::trim) {
+            let value = || {
+                line.split(':')
+                    .nth(1)
+                    .map(|value| value.trim().trim_end_matches(',').trim_matches('"').to_string())
+            };
+
+            // `},{` closes one monitor and opens the next
+            if line.starts_with('}') {
+                if depth == 1 {
+                    if let (Some(width), Some(height)) = (width, height) {
+                        let entry = ScreenInfoEntry {
+                            connector: std::mem::take(&mut connector),
+                            name: std::mem::take(&mut name),
+                            x,
+                            y,
+                            width,
+                            height,
+                            physical_mm: None,
+                        };
+                        monitors.push((
+                            id,
+                            MonitorInfo {
+                                focused,
+                                ..MonitorInfo::from(entry)
+                            },
+                        ));
+                    }
                 }
-            } else if in_focused_monitor && line == "}" {
-                if let (Some(w), Some(h)) = (width, height) {
-                    return Ok(ScreenInfo { width: w, height: h });
+                depth -= 1;
+            }
+
+            if line.ends_with('{') {
+                depth += 1;
+                if depth == 1 {
+                    id = None;
+                    connector.clear();
+                    name.clear();
+                    focused = false;
+                    width = None;
+                    height = None;
+                    x = 0.0;
+                    y = 0.0;
                 }
-                break;
+            } else if depth == 1 && line.starts_with("\"id\":") {
+                id = value().and_then(|value| value.parse().ok());
+            } else if depth == 1 && line.starts_with("\"name\":") {
+                connector = value().unwrap_or_default();
+            } else if depth == 1 && line.starts_with("\"description\":") {
+                name = value().unwrap_or_default();
+            } else if depth == 1 && line.starts_with("\"focused\":") {
+                focused = value().as_deref() == Some("true");
+            } else if depth == 1 && line.starts_with("\"width\":") {
+                width = value().and_then(|value| value.parse().ok());
+            } else if depth == 1 && line.starts_with("\"height\":") {
+                height = value().and_then(|value| value.parse().ok());
+            } else if depth == 1 && line.starts_with("\"x\":") {
+                x = value().and_then(|value| value.parse().ok()).unwrap_or(0.0);
+            } else if depth == 1 && line.starts_with("\"y\":") {
+                y = value().and_then(|value| value.parse().ok()).unwrap_or(0.0);
             }
         }
-        
-        Err("Could not find focused monitor in Hyprland".into())
+        monitors
     }
 
-    fn detect_sway_focused() -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+    fn detect_sway_focused(runner: &dyn CommandRunner) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
         // Get focused workspace first
-        let workspace_output = Command::new("swaymsg")
-            .arg("-t")
-            .arg("get_workspaces")
-            .output()?;
+        let workspace_output = runner.run("swaymsg", &["-t", "get_workspaces"])?;
 
-        if !workspace_output.status.success() {
+        if !workspace_output.success {
             return Err("swaymsg get_workspaces failed".into());
         }
 
-        let workspace_str = String::from_utf8_lossy(&workspace_output.stdout);
+        let workspace_str = &workspace_output.stdout;
         let mut focused_output_name = None;
 
         // Find the focused workspace and its output
@@ -180,14 +650,10 @@ impl ScreenInfo {
 
         if let Some(output_name) = focused_output_name {
             // Get resolution of that specific output
-            let outputs_result = Command::new("swaymsg")
-                .arg("-t")
-                .arg("get_outputs")
-                .output()?;
-
-            if outputs_result.status.success() {
-                let outputs_str = String::from_utf8_lossy(&outputs_result.stdout);
-                if let Some(resolution) = Self::parse_sway_output_by_name(&outputs_str, &output_name) {
+            let outputs_result = runner.run("swaymsg", &["-t", "get_outputs"])?;
+
+            if outputs_result.success {
+                if let Some(resolution) = Self::parse_sway_output_by_name(&outputs_result.stdout, &output_name) {
                     return Ok(resolution);
                 }
             }
@@ -196,203 +662,229 @@ impl ScreenInfo {
         Err("Could not detect focused output in Sway".into())
     }
 
-    fn parse_sway_output_by_name(output: &str, target_name: &str) -> Option<ScreenInfo> {
-        let mut in_target_output = false;
-        let mut in_current_mode = false;
+    pub fn parse_sway_output_by_name(output: &str, target_name: &str) -> Option<ScreenInfo> {
+        let mut lines = output.lines().map(str::trim);
+        lines.find(|line| line.starts_with("\"name\":") && line.contains(target_name))?;
+        // The target's modes end with the first closing bracket after its name
+        Self::parse_sway_current_mode(lines.take_while(|line| !line.starts_with(']')))
+    }
+
+    /// Width and height of the first `"current": true` mode object in `lines`. The
+    /// keys can come in any order within the object, so values are only used once
+    /// the object closes.
+    fn parse_sway_current_mode<'a>(lines: impl Iterator<Item = &'a str>) -> Option<ScreenInfo> {
+        let mut is_current = false;
         let mut width = None;
         let mut height = None;
-        
-        for line in output.lines() {
-            let line = line.trim();
-            
-            if line.starts_with("\"name\":") && line.contains(target_name) {
-                in_target_output = true;
-            } else if in_target_output && line.contains("\"current\": true") {
-                in_current_mode = true;
-            } else if in_target_output && in_current_mode && line.starts_with("\"width\":") {
-                if let Some(value_str) = line.split(':').nth(1) {
-                    let value_str = value_str.trim().trim_end_matches(',');
-                    width = value_str.parse().ok();
-                }
-            } else if in_target_output && in_current_mode && line.starts_with("\"height\":") {
-                if let Some(value_str) = line.split(':').nth(1) {
-                    let value_str = value_str.trim().trim_end_matches(',');
-                    height = value_str.parse().ok();
-                }
-            } else if in_current_mode && line == "}" {
-                if let (Some(w), Some(h)) = (width, height) {
-                    return Some(ScreenInfo { width: w, height: h });
+
+        for line in lines {
+            let value = || {
+                line.split(':')
+                    .nth(1)
+                    .map(|value| value.trim().trim_end_matches(',').to_string())
+            };
+
+            if line.ends_with('{') {
+                is_current = false;
+                width = None;
+                height = None;
+            } else if line.starts_with("\"current\":") {
+                is_current = value().as_deref() == Some("true");
+            } else if line.starts_with("\"width\":") {
+                width = value().and_then(|value| value.parse().ok());
+            } else if line.starts_with("\"height\":") {
+                height = value().and_then(|value| value.parse().ok());
+            } else if line.starts_with('}') {
+                if let (true, Some(w), Some(h)) = (is_current, width, height) {
+                    return Some(ScreenInfo::new(w, h));
                 }
-                in_current_mode = false;
+                is_current = false;
                 width = None;
                 height = None;
-            } else if in_target_output && line == "]" {
-                break;
             }
         }
         None
     }
 
-    fn detect_x11_focused() -> Result<ScreenInfo, Box<dyn std::error::Error>> {
-        eprintln!("Using X11/XWayland focused screen detection (mouse-based)");
-        
-        // Get mouse cursor position to determine which screen is focused
-        let mouse_output = Command::new("xdotool")
-            .arg("getmouselocation")
-            .arg("--shell")
-            .output();
-
-        if let Ok(mouse_result) = mouse_output {
-            if mouse_result.status.success() {
-                let mouse_str = String::from_utf8_lossy(&mouse_result.stdout);
-                let mut mouse_x = None;
-                let mut mouse_y = None;
-
-                for line in mouse_str.lines() {
-                    if line.starts_with("X=") {
-                        mouse_x = line[2..].parse().ok();
-                    } else if line.starts_with("Y=") {
-                        mouse_y = line[2..].parse().ok();
-                    }
-                }
+    /// The monitor under the mouse pointer when focus is preferred (X11 has no
+    /// focused output, the pointer stands in for it), else the primary one, else
+    /// the first, as `selection::select_monitor` picks them out of xrandr's list
+    fn detect_x11_focused(
+        config: &ScreenConfig,
+        runner: &dyn CommandRunner,
+    ) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+        let cursor = if config.prefer_focused_screen {
+            eprintln!("Using X11/XWayland focused screen detection (mouse-based)");
+            runner
+                .run("xdotool", &["getmouselocation", "--shell"])
+                .ok()
+                .filter(|output| output.success)
+                .and_then(|output| Self::parse_xdotool_mouse_location(&output.stdout))
+        } else {
+            None
+        };
 
-                if let (Some(x), Some(y)) = (mouse_x, mouse_y) {
-                    // Get screen info for the screen containing the mouse cursor
-                    return Self::get_x11_screen_at_position(x, y);
-                }
-            }
+        let xrandr_args: &[&str] = if config.xrandr_verbose {
+            &["--verbose", "--current"]
+        } else {
+            &["--current"]
+        };
+        let xrandr_output = runner.run("xrandr", xrandr_args)?;
+        if !xrandr_output.success {
+            return Err("xrandr command failed".into());
         }
 
-        // Fallback: get primary screen via xrandr
-        eprintln!("Mouse detection failed, using X11 primary screen");
-        let xrandr_output = Command::new("xrandr").arg("--current").output()?;
-        if xrandr_output.status.success() {
-            let output_str = String::from_utf8_lossy(&xrandr_output.stdout);
-            if let Some(resolution) = Self::parse_xrandr_primary(&output_str) {
-                return Ok(resolution);
-            }
-            // If no primary found, try any connected screen
-            if let Some(resolution) = Self::parse_xrandr_any_connected(&output_str) {
-                return Ok(resolution);
+        let mut monitors = Self::parse_xrandr_monitors(&xrandr_output.stdout);
+        if let Some(cursor) = cursor {
+            for monitor in &mut monitors {
+                monitor.focused = monitor.contains(cursor);
             }
         }
+        if let Some(monitor) = selection::select_monitor(&monitors, config, cursor) {
+            let (cursor_x, cursor_y) = cursor.map_or((None, None), |(x, y)| (Some(x as f32), Some(y as f32)));
+            return Ok(ScreenInfo {
+                cursor_x,
+                cursor_y,
+                ..ScreenInfo::from(&monitor.entry)
+            });
+        }
 
-        Err("Could not detect X11 focused screen".into())
+        // Some outputs only carry their geometry on the verbose mode lines
+        Self::parse_xrandr_verbose_primary(&xrandr_output.stdout)
+            .ok_or_else(|| "Could not detect X11 focused screen".into())
     }
 
-    fn get_x11_screen_at_position(x: i32, y: i32) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
-        let output = Command::new("xrandr").arg("--current").output()?;
-        
-        if !output.status.success() {
-            return Err("xrandr command failed".into());
-        }
-
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        eprintln!("X11 mouse position: {},{}  Looking for screen...", x, y);
-        
-        let mut closest_screen = None;
-        let mut closest_distance = i32::MAX;
-        
-        // Parse xrandr output to find which screen contains the coordinates
-        for line in output_str.lines() {
-            if line.contains("connected") && !line.contains("disconnected") {
-                eprintln!("Checking line: {}", line);
+    pub fn parse_xrandr_primary(output: &str) -> Option<ScreenInfo> {
+        for line in output.lines() {
+            if line.contains("primary") && line.contains("connected") {
                 let parts: Vec<&str> = line.split_whitespace().collect();
                 for part in parts {
                     if part.contains("x") && part.contains("+") {
-                        // Parse format like "1920x1080+1920+0" or "1080x1920+3840+0"
                         if let Some((res_part, pos_part)) = part.split_once('+') {
                             if let Some((width_str, height_str)) = res_part.split_once('x') {
-                                if let Some((x_str, y_str)) = pos_part.split_once('+') {
-                                    if let (Ok(width), Ok(height), Ok(screen_x), Ok(screen_y)) = (
-                                        width_str.parse::<f32>(),
-                                        height_str.parse::<f32>(),
-                                        x_str.parse::<i32>(),
-                                        y_str.parse::<i32>()
-                                    ) {
-                                        eprintln!("Found screen: {}x{} at {},{}", width, height, screen_x, screen_y);
-                                        
-                                        // Check if mouse is within this screen (exact match)
-                                        if x >= screen_x && x < screen_x + width as i32 &&
-                                           y >= screen_y && y < screen_y + height as i32 {
-                                            eprintln!("Mouse is exactly on this screen!");
-                                            return Ok(ScreenInfo { width, height });
-                                        }
-                                        
-                                        // Calculate distance to this screen (for closest match)
-                                        let distance_x = if x < screen_x {
-                                            screen_x - x
-                                        } else if x >= screen_x + width as i32 {
-                                            x - (screen_x + width as i32 - 1)
-                                        } else {
-                                            0
-                                        };
-                                        
-                                        let distance_y = if y < screen_y {
-                                            screen_y - y
-                                        } else if y >= screen_y + height as i32 {
-                                            y - (screen_y + height as i32 - 1)
-                                        } else {
-                                            0
-                                        };
-                                        
-                                        let total_distance = distance_x + distance_y;
-                                        if total_distance < closest_distance {
-                                            closest_distance = total_distance;
-                                            closest_screen = Some(ScreenInfo { width, height });
-                                            eprintln!("This is closest screen so far (distance: {})", total_distance);
-                                        }
-                                    }
+                                if let (Ok(width), Ok(height)) = (width_str.parse::<f32>(), height_str.parse::<f32>()) {
+                                    let (x, y) = Self::parse_xrandr_origin(pos_part);
+                                    return Some(ScreenInfo {
+                                        x,
+                                        y,
+                                        ..ScreenInfo::new(width, height)
+                                    });
                                 }
                             }
                         }
-                        break; // Found the resolution part, no need to check other parts of this line
                     }
                 }
             }
         }
-
-        // If we found a closest screen, use that
-        if let Some(screen) = closest_screen {
-            eprintln!("Using closest screen (distance: {})", closest_distance);
-            return Ok(screen);
-        }
-
-        eprintln!("No screen found at mouse position, trying fallback...");
-        Err("Could not find screen at position".into())
+        None
     }
 
-    fn parse_xrandr_primary(output: &str) -> Option<ScreenInfo> {
+    /// Primary output from `xrandr --verbose`, sized by its `*current` mode line
+    /// (e.g. `  2560x1440 (0x48) 241.500MHz ... *current`), so it works even when
+    /// the output line carries no geometry
+    pub fn parse_xrandr_verbose_primary(output: &str) -> Option<ScreenInfo> {
+        let mut in_primary = false;
+        let mut origin = (0.0, 0.0);
+
         for line in output.lines() {
-            if line.contains("primary") && line.contains("connected") {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                for part in parts {
-                    if part.contains("x") && part.contains("+") {
-                        if let Some((res_part, _)) = part.split_once('+') {
-                            if let Some((width_str, height_str)) = res_part.split_once('x') {
-                                if let (Ok(width), Ok(height)) = (width_str.parse::<f32>(), height_str.parse::<f32>()) {
-                                    return Some(ScreenInfo { width, height });
-                                }
-                            }
-                        }
-                    }
+            // Output lines start in column 0; properties and modes are indented
+            if !line.starts_with(char::is_whitespace) {
+                in_primary = line.contains(" connected primary");
+                if in_primary {
+                    origin = line
+                        .split_whitespace()
+                        .find_map(|part| part.split_once('+').filter(|(res, _)| res.contains('x')))
+                        .map(|(_, pos_part)| Self::parse_xrandr_origin(pos_part))
+                        .unwrap_or((0.0, 0.0));
                 }
+                continue;
+            }
+
+            if in_primary && line.contains("*current") {
+                let resolution = line.split_whitespace().next()?;
+                let (width_str, height_str) = resolution.split_once('x')?;
+                let (width, height) = (width_str.parse::<f32>().ok()?, height_str.parse::<f32>().ok()?);
+                return Some(ScreenInfo {
+                    x: origin.0,
+                    y: origin.1,
+                    ..ScreenInfo::new(width, height)
+                });
             }
         }
         None
     }
 
-    fn parse_xrandr_any_connected(output: &str) -> Option<ScreenInfo> {
+    fn parse_xrandr_origin(pos_part: &str) -> (f32, f32) {
+        // Position part of "1920x1080+1920+0" after the first '+', i.e. "1920+0"
+        pos_part
+            .split_once('+')
+            .and_then(|(x_str, y_str)| Some((x_str.parse().ok()?, y_str.parse().ok()?)))
+            .unwrap_or((0.0, 0.0))
+    }
+
+    /// Every connected output with a geometry in `xrandr --current` output, e.g.
+    /// `HDMI-1 connected 1440x2560+2560+0 left ...`. The geometry is already rotated;
+    /// xrandr has no display names, so the connector doubles as the name.
+    pub fn parse_xrandr_entries(output: &str) -> Vec<ScreenInfoEntry> {
+        output
+            .lines()
+            .filter(|line| !line.starts_with(char::is_whitespace))
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let connector = parts.next()?;
+                if parts.next()? != "connected" {
+                    return None;
+                }
+                // Connected but disabled outputs have no geometry
+                let (resolution, pos_part) =
+                    parts.find_map(|part| part.split_once('+').filter(|(res, _)| res.contains('x')))?;
+                let (width_str, height_str) = resolution.split_once('x')?;
+                let (x, y) = Self::parse_xrandr_origin(pos_part);
+                Some(ScreenInfoEntry {
+                    connector: connector.to_string(),
+                    name: connector.to_string(),
+                    x,
+                    y,
+                    width: width_str.parse().ok()?,
+                    height: height_str.parse().ok()?,
+                    physical_mm: None,
+                })
+            })
+            .collect()
+    }
+
+    /// `parse_xrandr_entries` with the output marked `primary` flagged as such
+    pub fn parse_xrandr_monitors(output: &str) -> Vec<MonitorInfo> {
+        let primary: Vec<&str> = output
+            .lines()
+            .filter(|line| line.contains(" connected primary"))
+            .filter_map(|line| line.split_whitespace().next())
+            .collect();
+        Self::parse_xrandr_entries(output)
+            .into_iter()
+            .map(|entry| MonitorInfo {
+                primary: primary.contains(&entry.connector.as_str()),
+                ..MonitorInfo::from(entry)
+            })
+            .collect()
+    }
+
+    pub fn parse_xrandr_any_connected(output: &str) -> Option<ScreenInfo> {
         // Find any connected screen as fallback
         for line in output.lines() {
             if line.contains("connected") && !line.contains("disconnected") {
                 let parts: Vec<&str> = line.split_whitespace().collect();
                 for part in parts {
                     if part.contains("x") && part.contains("+") {
-                        if let Some((res_part, _)) = part.split_once('+') {
+                        if let Some((res_part, pos_part)) = part.split_once('+') {
                             if let Some((width_str, height_str)) = res_part.split_once('x') {
                                 if let (Ok(width), Ok(height)) = (width_str.parse::<f32>(), height_str.parse::<f32>()) {
-                                    return Some(ScreenInfo { width, height });
+                                    let (x, y) = Self::parse_xrandr_origin(pos_part);
+                                    return Some(ScreenInfo {
+                                        x,
+                                        y,
+                                        ..ScreenInfo::new(width, height)
+                                    });
                                 }
                             }
                         }
@@ -403,23 +895,108 @@ impl ScreenInfo {
         None
     }
 
-    fn parse_wlr_randr_output(output: &str) -> Option<ScreenInfo> {
+    /// First mode marked current on any output; without one, the mode
+    /// `WlrRandrMode::select` picks for the first enabled output
+    pub fn parse_wlr_randr_output(output: &str) -> Option<ScreenInfo> {
         for line in output.lines() {
             if line.contains("current") {
                 // Parse line like: "  1920x1080 px, 59.996002 Hz (current)"
                 if let Some(resolution_part) = line.trim().split_whitespace().next() {
                     if let Some((width_str, height_str)) = resolution_part.split_once('x') {
                         if let (Ok(width), Ok(height)) = (width_str.parse::<f32>(), height_str.parse::<f32>()) {
-                            return Some(ScreenInfo { width, height });
+                            return Some(ScreenInfo::new(width, height));
                         }
                     }
                 }
             }
         }
-        None
+
+        Self::parse_wlr_randr_entries(output)
+            .first()
+            .map(|entry| ScreenInfo::new(entry.width, entry.height))
+    }
+
+    /// Every enabled output, at its current mode or the one `WlrRandrMode::select` picks.
+    /// Modes are in device pixels while positions are logical, so the mode is
+    /// rotated by `Transform` and divided by `Scale` to match the positions.
+    pub fn parse_wlr_randr_entries(output: &str) -> Vec<ScreenInfoEntry> {
+        let mut entries = Vec::new();
+        let mut current: Option<ScreenInfoEntry> = None;
+        let mut modes = Vec::new();
+        let mut enabled = true;
+        let mut rotated = false;
+        let mut scale = 1.0;
+
+        let mut finish = |entry: Option<ScreenInfoEntry>,
+                          modes: &mut Vec<WlrRandrMode>,
+                          enabled: bool,
+                          rotated: bool,
+                          scale: f32| {
+            if let (Some(mut entry), Some(mode), true) = (entry, WlrRandrMode::select(modes), enabled) {
+                let (width, height) = if rotated { (mode.height, mode.width) } else { (mode.width, mode.height) };
+                (entry.width, entry.height) = Self::logical_size(width, height, scale);
+                entries.push(entry);
+            }
+            modes.clear();
+        };
+
+        for line in output.lines() {
+            if line.is_empty() {
+                continue;
+            }
+
+            // Output headers are the only unindented lines: `DP-1 "Dell Inc. DELL U2722D (DP-1)"`
+            if !line.starts_with(char::is_whitespace) {
+                finish(current.take(), &mut modes, enabled, rotated, scale);
+                enabled = true;
+                rotated = false;
+                scale = 1.0;
+                let connector = line.split_whitespace().next().unwrap_or_default().to_string();
+                let name = line
+                    .split_once('"')
+                    .map(|(_, rest)| rest.trim_end_matches('"').to_string())
+                    .unwrap_or_else(|| connector.clone());
+                current = Some(ScreenInfoEntry {
+                    connector,
+                    name,
+                    x: 0.0,
+                    y: 0.0,
+                    width: 0.0,
+                    height: 0.0,
+                    physical_mm: None,
+                });
+                continue;
+            }
+
+            let Some(entry) = current.as_mut() else {
+                continue;
+            };
+            let line = line.trim();
+            if let Some(model) = line.strip_prefix("Model:") {
+                entry.name = model.trim().to_string();
+            } else if let Some(position) = line.strip_prefix("Position:") {
+                // `Position: 2560,0`
+                if let Some((x, y)) = position.trim().split_once(',') {
+                    entry.x = x.trim().parse().unwrap_or(0.0);
+                    entry.y = y.trim().parse().unwrap_or(0.0);
+                }
+            } else if let Some(value) = line.strip_prefix("Enabled:") {
+                enabled = value.trim() != "no";
+            } else if let Some(value) = line.strip_prefix("Transform:") {
+                // normal, 90, 180, 270, flipped, flipped-90, ...
+                rotated = value.trim().ends_with("90") || value.trim().ends_with("270");
+            } else if let Some(value) = line.strip_prefix("Scale:") {
+                scale = value.trim().parse().unwrap_or(1.0);
+            } else if let Some(mode) = WlrRandrMode::parse(line) {
+                modes.push(mode);
+            }
+        }
+
+        finish(current, &mut modes, enabled, rotated, scale);
+        entries
     }
 
-    fn parse_hyprctl_output(output: &str) -> Option<ScreenInfo> {
+    pub fn parse_hyprctl_output(output: &str) -> Option<ScreenInfo> {
         // Simple JSON parsing for Hyprland monitor info
         // Look for "width": and "height": fields
         let mut width = None;
@@ -441,81 +1018,325 @@ impl ScreenInfo {
             
             // If we found both, return early
             if let (Some(w), Some(h)) = (width, height) {
-                return Some(ScreenInfo { width: w, height: h });
+                return Some(ScreenInfo::new(w, h));
             }
         }
         None
     }
 
-    fn parse_swaymsg_output(output: &str) -> Option<ScreenInfo> {
-        // Simple JSON parsing for Sway output info
-        // Look for current mode with "width" and "height"
-        let mut in_current_mode = false;
-        let mut width = None;
-        let mut height = None;
-        
-        for line in output.lines() {
-            let line = line.trim();
-            
-            if line.contains("\"current\": true") {
-                in_current_mode = true;
-            } else if in_current_mode && line.starts_with("\"width\":") {
-                if let Some(value_str) = line.split(':').nth(1) {
-                    let value_str = value_str.trim().trim_end_matches(',');
-                    width = value_str.parse().ok();
+    pub fn parse_swaymsg_output(output: &str) -> Option<ScreenInfo> {
+        // Simple JSON parsing for Sway output info: the first current mode wins
+        Self::parse_sway_current_mode(output.lines().map(str::trim))
+    }
+
+    /// Every output in `swaymsg -t get_outputs` output with a current mode,
+    /// focused and primary as Sway marks them. Placed by its `rect` when listed.
+    pub fn parse_sway_monitors(output: &str) -> Vec<MonitorInfo> {
+        let mut monitors = Vec::new();
+        // Depth 1 is an output object, its rect and modes are deeper
+        let mut depth = 0;
+        let mut in_rect = false;
+        let mut object_lines: Vec<&str> = Vec::new();
+        let mut connector = String::new();
+        let mut name = String::new();
+        let mut focused = false;
+        let mut primary = false;
+        let (mut x, mut y) = (0.0, 0.0);
+
+        for line in output.lines().map(str::trim) {
+            let value = || {
+                line.split(':')
+                    .nth(1)
+                    .map(|value| value.trim().trim_end_matches(',').trim_matches('"').to_string())
+            };
+
+            // The modes, for `parse_sway_current_mode` once the output closes
+            let is_close = line.starts_with('}');
+            if is_close && depth >= 2 && !in_rect {
+                object_lines.push(line);
+            }
+
+            if is_close {
+                if depth == 1 {
+                    if let Some(mode) = Self::parse_sway_current_mode(object_lines.drain(..)) {
+                        let entry = ScreenInfoEntry {
+                            connector: std::mem::take(&mut connector),
+                            name: std::mem::take(&mut name),
+                            x,
+                            y,
+                            width: mode.width,
+                            height: mode.height,
+                            physical_mm: None,
+                        };
+                        monitors.push(MonitorInfo {
+                            focused,
+                            primary,
+                            ..MonitorInfo::from(entry)
+                        });
+                    }
                 }
-            } else if in_current_mode && line.starts_with("\"height\":") {
-                if let Some(value_str) = line.split(':').nth(1) {
-                    let value_str = value_str.trim().trim_end_matches(',');
-                    height = value_str.parse().ok();
+                in_rect = false;
+                depth -= 1;
+            }
+
+            if line.ends_with('{') {
+                depth += 1;
+                if depth == 1 {
+                    object_lines.clear();
+                    connector.clear();
+                    name.clear();
+                    focused = false;
+                    primary = false;
+                    (x, y) = (0.0, 0.0);
                 }
+                in_rect = depth == 2 && line.starts_with("\"rect\":");
+            } else if depth == 1 && line.starts_with("\"name\":") {
+                connector = value().unwrap_or_default();
+            } else if depth == 1 && line.starts_with("\"model\":") {
+                name = value().unwrap_or_default();
+            } else if depth == 1 && line.starts_with("\"focused\":") {
+                focused = value().as_deref() == Some("true");
+            } else if depth == 1 && line.starts_with("\"primary\":") {
+                primary = value().as_deref() == Some("true");
+            } else if in_rect && line.starts_with("\"x\":") {
+                x = value().and_then(|value| value.parse().ok()).unwrap_or(0.0);
+            } else if in_rect && line.starts_with("\"y\":") {
+                y = value().and_then(|value| value.parse().ok()).unwrap_or(0.0);
             }
-            
-            // Reset if we exit the current mode block
-            if in_current_mode && line == "}" {
-                if let (Some(w), Some(h)) = (width, height) {
-                    return Some(ScreenInfo { width: w, height: h });
+
+            if !is_close && depth >= 2 && !in_rect {
+                object_lines.push(line);
+            }
+        }
+        monitors
+    }
+
+    /// `$XDG_DATA_HOME/kscreen`, falling back to `~/.local/share/kscreen`
+    fn kscreen_dir() -> Option<PathBuf> {
+        if let Ok(xdg_data) = std::env::var("XDG_DATA_HOME") {
+            Some(PathBuf::from(xdg_data).join("kscreen"))
+        } else {
+            std::env::var("HOME")
+                .ok()
+                .map(|home| PathBuf::from(home).join(".local").join("share").join("kscreen"))
+        }
+    }
+
+    /// kscreen keeps one output config per set of connected monitors, named by a
+    /// hash of that set. The connected DRM connectors tell which one is current.
+    fn detect_from_kscreen(fs: &dyn FileSystem) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+        let dir = Self::kscreen_dir().ok_or("Could not locate the kscreen config directory")?;
+        // Subdirectories like outputs/ fail to read and are skipped
+        let configs: Vec<String> = fs
+            .read_dir(&dir)?
+            .iter()
+            .filter_map(|path| fs.read_to_string(path).ok())
+            .collect();
+        let connected: Vec<String> = Self::detect_from_kms_drm(fs)
+            .map(|entries| entries.into_iter().map(|entry| entry.connector).collect())
+            .unwrap_or_default();
+        Self::select_kscreen_config(&configs, &connected)
+            .ok_or_else(|| "No kscreen config for the connected outputs".into())
+    }
+
+    /// Enabled output of the kscreen config listing exactly the `connected`
+    /// connectors. Without such a config (e.g. sysfs unreadable, or X11 output
+    /// names), a single config with an enabled output is still trusted, but
+    /// several are ambiguous.
+    pub fn select_kscreen_config(configs: &[String], connected: &[String]) -> Option<ScreenInfo> {
+        let mut connected = connected.to_vec();
+        connected.sort();
+        let current = configs.iter().find(|content| {
+            let mut outputs = Self::parse_kscreen_outputs(content);
+            outputs.sort();
+            !outputs.is_empty() && outputs == connected
+        });
+        if let Some(content) = current {
+            return Self::parse_kscreen_config(content);
+        }
+
+        let mut candidates = configs.iter().filter_map(|content| Self::parse_kscreen_config(content));
+        match (candidates.next(), candidates.next()) {
+            (Some(screen), None) => Some(screen),
+            _ => None,
+        }
+    }
+
+    /// Connector names of every output in a kscreen output config, enabled or not
+    pub fn parse_kscreen_outputs(content: &str) -> Vec<String> {
+        let mut depth = 0;
+        let mut names = Vec::new();
+        for line in content.lines().map(str::trim) {
+            if line.ends_with('{') {
+                depth += 1;
+            } else if line.starts_with('}') {
+                depth -= 1;
+            } else if depth == 2 && line.starts_with("\"name\":") {
+                // Only "metadata" has a name at this depth
+                if let Some((_, value)) = line.split_once(':') {
+                    names.push(value.trim().trim_end_matches(',').trim_matches('"').to_string());
                 }
-                in_current_mode = false;
-                width = None;
-                height = None;
             }
         }
-        None
+        names
     }
 
-    fn detect_from_sysfs() -> Result<ScreenInfo, Box<dyn std::error::Error>> {
-        use std::fs;
-        
-        // Try to read from /sys/class/drm/*/modes
-        let drm_dir = "/sys/class/drm";
-        if let Ok(entries) = fs::read_dir(drm_dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                    if name.starts_with("card") && name.contains("-") {
-                        let modes_path = path.join("modes");
-                        if let Ok(modes_content) = fs::read_to_string(&modes_path) {
-                            if let Some(resolution) = Self::parse_drm_modes(&modes_content) {
-                                return Ok(resolution);
-                            }
-                        }
+    /// First enabled output of a kscreen output config. The mode is in device
+    /// pixels, so it is divided by the output's scale to get the logical size the
+    /// window is laid out in, matching the logical `pos`. The mode is also
+    /// unrotated; a rotation of 2 (left) or 8 (right) swaps width and height.
+    pub fn parse_kscreen_config(content: &str) -> Option<ScreenInfo> {
+        // Depth 1 is an output object, "mode" is depth 2 and its "size" depth 3
+        let mut depth = 0;
+        let mut enabled = false;
+        let mut scale: f32 = 1.0;
+        let mut rotation = 1;
+        let mut width: Option<f32> = None;
+        let mut height: Option<f32> = None;
+        let mut x = 0.0;
+        let mut y = 0.0;
+
+        for line in content.lines().map(str::trim) {
+            let value = || {
+                line.split(':')
+                    .nth(1)
+                    .map(|value| value.trim().trim_end_matches(',').to_string())
+            };
+
+            if line.ends_with('{') {
+                depth += 1;
+                if depth == 1 {
+                    enabled = false;
+                    scale = 1.0;
+                    rotation = 1;
+                    width = None;
+                    height = None;
+                    x = 0.0;
+                    y = 0.0;
+                }
+            } else if line.starts_with('}') {
+                if depth == 1 && enabled {
+                    if let (Some(w), Some(h)) = (width, height) {
+                        let (w, h) = if matches!(rotation, 2 | 8) { (h, w) } else { (w, h) };
+                        let (width, height) = Self::logical_size(w, h, scale);
+                        return Some(ScreenInfo {
+                            x,
+                            y,
+                            ..ScreenInfo::new(width, height)
+                        });
                     }
                 }
+                depth -= 1;
+            } else if depth == 1 && line.starts_with("\"enabled\":") {
+                enabled = value().as_deref() == Some("true");
+            } else if depth == 1 && line.starts_with("\"rotation\":") {
+                rotation = value().and_then(|value| value.parse().ok()).unwrap_or(1);
+            } else if depth == 1 && line.starts_with("\"scale\":") {
+                scale = value().and_then(|value| value.parse().ok()).unwrap_or(1.0);
+            } else if depth == 3 && line.starts_with("\"width\":") {
+                width = value().and_then(|value| value.parse().ok());
+            } else if depth == 3 && line.starts_with("\"height\":") {
+                height = value().and_then(|value| value.parse().ok());
+            } else if depth == 2 && line.starts_with("\"x\":") {
+                x = value().and_then(|value| value.parse().ok()).unwrap_or(0.0);
+            } else if depth == 2 && line.starts_with("\"y\":") {
+                y = value().and_then(|value| value.parse().ok()).unwrap_or(0.0);
             }
         }
-        
-        Err("Could not detect from sysfs".into())
+        None
+    }
+
+    #[cfg(target_os = "linux")]
+    fn detect_from_sysfs(fs: &dyn FileSystem) -> Result<ScreenInfo, Box<dyn std::error::Error>> {
+        let entries = Self::detect_from_kms_drm(fs)?;
+        match entries.first() {
+            Some(entry) => Ok(ScreenInfo::from(entry)),
+            None => Err("Could not detect from sysfs".into()),
+        }
+    }
+
+    /// Physical size of the connector whose preferred mode matches `screen`, from its EDID.
+    /// Rotated screens match with width and height swapped.
+    fn physical_size_from_edid(screen: &ScreenInfo, fs: &dyn FileSystem) -> Option<(f32, f32)> {
+        let entries = Self::detect_from_kms_drm(fs).ok()?;
+        entries.iter().find_map(|entry| {
+            let (width_mm, height_mm) = entry.physical_mm?;
+            if entry.width == screen.width && entry.height == screen.height {
+                Some((width_mm, height_mm))
+            } else if entry.width == screen.height && entry.height == screen.width {
+                Some((height_mm, width_mm))
+            } else {
+                None
+            }
+        })
     }
 
-    fn parse_drm_modes(content: &str) -> Option<ScreenInfo> {
+    /// List connected DRM connectors from `/sys/class/drm`, named from their EDID
+    pub fn detect_from_kms_drm(fs: &dyn FileSystem) -> Result<Vec<ScreenInfoEntry>, ScreenError> {
+        let drm_dir = Path::new("/sys/class/drm");
+        let mut entries = Vec::new();
+
+        for path in fs.read_dir(drm_dir)? {
+            let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            // Connector directories look like "card0-DP-1"
+            let Some((card, connector)) = dir_name.split_once('-') else {
+                continue;
+            };
+            if !card.starts_with("card") {
+                continue;
+            }
+
+            let status = fs.read_to_string(&path.join("status")).unwrap_or_default();
+            if status.trim() == "disconnected" {
+                continue;
+            }
+
+            let Ok(modes_content) = fs.read_to_string(&path.join("modes")) else {
+                continue;
+            };
+            let Some(resolution) = Self::parse_drm_modes(&modes_content) else {
+                continue;
+            };
+
+            let edid_info = fs
+                .read(&path.join("edid"))
+                .ok()
+                .and_then(|bytes| edid::parse_edid(&bytes));
+            let name = edid_info
+                .as_ref()
+                .map(|info| info.display_name())
+                .unwrap_or_else(|| connector.to_string());
+
+            entries.push(ScreenInfoEntry {
+                connector: connector.to_string(),
+                name,
+                x: 0.0,
+                y: 0.0,
+                width: resolution.width,
+                height: resolution.height,
+                physical_mm: edid_info.and_then(|info| info.physical_mm),
+            });
+        }
+
+        if entries.is_empty() {
+            return Err(ScreenError::NoConnectedOutputs);
+        }
+
+        // Directory order depends on the filesystem, keep results stable between runs
+        entries.sort_by(|a, b| a.connector.cmp(&b.connector));
+        Ok(entries)
+    }
+
+    pub fn parse_drm_modes(content: &str) -> Option<ScreenInfo> {
         // Parse lines like "1920x1080"
         for line in content.lines() {
             let line = line.trim();
             if let Some((width_str, height_str)) = line.split_once('x') {
                 if let (Ok(width), Ok(height)) = (width_str.parse::<f32>(), height_str.parse::<f32>()) {
                     // Return the first (usually highest) resolution
-                    return Some(ScreenInfo { width, height });
+                    return Some(ScreenInfo::new(width, height));
                 }
             }
         }
@@ -523,3 +1344,194 @@ impl ScreenInfo {
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::{
+        DRM_MODES, HYPRCTL_ACTIVEWINDOW, HYPRCTL_MONITORS, SWAYMSG_OUTPUTS, WLR_RANDR, WLR_RANDR_NO_CURRENT,
+        WLR_RANDR_REFRESH_RATES, WLR_RANDR_TWO_OUTPUTS, XRANDR_CURRENT,
+    };
+    use super::{ScreenInfo, WlrRandrMode};
+
+    fn size(screen: &ScreenInfo) -> (f32, f32) {
+        (screen.width, screen.height)
+    }
+
+    fn origin(screen: &ScreenInfo) -> (f32, f32) {
+        (screen.x, screen.y)
+    }
+
+    #[test]
+    fn test_parse_wlr_randr_single_monitor() {
+        let screen = ScreenInfo::parse_wlr_randr_output(WLR_RANDR).unwrap();
+        assert_eq!(size(&screen), (2560.0, 1440.0));
+
+        let entries = ScreenInfo::parse_wlr_randr_entries(WLR_RANDR);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].connector, "DP-1");
+        assert_eq!(entries[0].name, "DELL U2722D");
+    }
+
+    #[test]
+    fn test_parse_wlr_randr_two_monitors() {
+        let entries = ScreenInfo::parse_wlr_randr_entries(WLR_RANDR_TWO_OUTPUTS);
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.connector.as_str(), entry.width, entry.height, entry.x))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("eDP-1", 1920.0, 1080.0, 0.0), ("HDMI-A-1", 2560.0, 1440.0, 1920.0)]
+        );
+    }
+
+    #[test]
+    fn test_parse_wlr_randr_without_current_mode() {
+        // Largest preferred mode of the first enabled output, not the larger 4K mode
+        let screen = ScreenInfo::parse_wlr_randr_output(WLR_RANDR_NO_CURRENT).unwrap();
+        assert_eq!(size(&screen), (2560.0, 1440.0));
+
+        let entries = ScreenInfo::parse_wlr_randr_entries(WLR_RANDR_NO_CURRENT);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].connector, "DP-2");
+    }
+
+    #[test]
+    fn test_parse_wlr_randr_same_resolution_highest_refresh() {
+        let screen = ScreenInfo::parse_wlr_randr_output(WLR_RANDR_REFRESH_RATES).unwrap();
+        assert_eq!(size(&screen), (1920.0, 1080.0));
+
+        let modes: Vec<WlrRandrMode> = WLR_RANDR_REFRESH_RATES.lines().filter_map(WlrRandrMode::parse).collect();
+        assert_eq!(modes.len(), 4);
+        let selected = WlrRandrMode::select(&modes).unwrap();
+        assert_eq!((selected.width, selected.height, selected.refresh), (1920.0, 1080.0, 240.001999));
+    }
+
+    #[test]
+    fn test_wlr_randr_current_mode_wins() {
+        let modes: Vec<WlrRandrMode> = WLR_RANDR_TWO_OUTPUTS.lines().filter_map(WlrRandrMode::parse).collect();
+        let selected = WlrRandrMode::select(&modes[1..]).unwrap();
+        assert!(selected.current);
+        assert_eq!((selected.width, selected.height), (2560.0, 1440.0));
+    }
+
+    #[test]
+    fn test_parse_hyprctl_two_monitors_second_focused() {
+        let focused = ScreenInfo::parse_hyprctl_focused(HYPRCTL_MONITORS).unwrap();
+        assert_eq!(size(&focused), (1440.0, 2560.0));
+        assert_eq!(origin(&focused), (2560.0, 0.0));
+
+        // The unfocused fallback takes the first monitor
+        let first = ScreenInfo::parse_hyprctl_output(HYPRCTL_MONITORS).unwrap();
+        assert_eq!(size(&first), (2560.0, 1440.0));
+    }
+
+    #[test]
+    fn test_parse_hyprctl_without_focused_monitor() {
+        let output = HYPRCTL_MONITORS.replace("\"focused\": true", "\"focused\": false");
+        assert!(ScreenInfo::parse_hyprctl_focused(&output).is_none());
+    }
+
+    #[test]
+    fn test_parse_hyprctl_active_window_monitor() {
+        // The window sits on DP-1 although HDMI-A-1 is marked focused
+        let id = ScreenInfo::parse_hyprctl_active_window_monitor(HYPRCTL_ACTIVEWINDOW).unwrap();
+        assert_eq!(id, 0);
+        let screen = ScreenInfo::parse_hyprctl_monitor_by_id(HYPRCTL_MONITORS, id).unwrap();
+        assert_eq!(size(&screen), (2560.0, 1440.0));
+        assert_eq!(origin(&screen), (0.0, 0.0));
+
+        let screen = ScreenInfo::parse_hyprctl_monitor_by_id(HYPRCTL_MONITORS, 1).unwrap();
+        assert_eq!(origin(&screen), (2560.0, 0.0));
+        assert!(ScreenInfo::parse_hyprctl_monitor_by_id(HYPRCTL_MONITORS, 7).is_none());
+    }
+
+    #[test]
+    fn test_parse_hyprctl_without_active_window() {
+        assert_eq!(ScreenInfo::parse_hyprctl_active_window_monitor("{}"), None);
+        assert_eq!(ScreenInfo::parse_hyprctl_active_window_monitor("Invalid"), None);
+        // The workspace's id is nested, not the window's monitor
+        assert_eq!(
+            ScreenInfo::parse_hyprctl_active_window_monitor("{\n\"workspace\": {\n\"monitor\": 3\n}\n}"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_hyprctl_monitors_with_focus() {
+        let monitors = ScreenInfo::parse_hyprctl_monitors(HYPRCTL_MONITORS);
+        let summary: Vec<_> = monitors
+            .iter()
+            .map(|monitor| (monitor.entry.connector.as_str(), monitor.entry.x, monitor.focused))
+            .collect();
+        assert_eq!(summary, vec![("DP-1", 0.0, false), ("HDMI-A-1", 2560.0, true)]);
+        assert_eq!(monitors[0].entry.name, "Dell Inc. DELL U2722D 8GXXXX3");
+    }
+
+    #[test]
+    fn test_parse_sway_monitors_at_current_mode() {
+        let monitors = ScreenInfo::parse_sway_monitors(SWAYMSG_OUTPUTS);
+        let summary: Vec<_> = monitors
+            .iter()
+            .map(|monitor| (monitor.entry.connector.as_str(), monitor.entry.width, monitor.entry.height))
+            .collect();
+        assert_eq!(summary, vec![("eDP-1", 1920.0, 1080.0), ("HDMI-A-1", 2560.0, 1440.0)]);
+        assert!(monitors.iter().all(|monitor| !monitor.focused && !monitor.primary));
+    }
+
+    #[test]
+    fn test_parse_xrandr_monitors_marks_primary() {
+        let primary: Vec<_> = ScreenInfo::parse_xrandr_monitors(XRANDR_CURRENT)
+            .iter()
+            .map(|monitor| (monitor.entry.connector.clone(), monitor.primary))
+            .collect();
+        assert_eq!(primary, vec![("DP-1".to_string(), true), ("HDMI-1".to_string(), false)]);
+    }
+
+    #[test]
+    fn test_parse_swaymsg_with_current_mode() {
+        let screen = ScreenInfo::parse_swaymsg_output(SWAYMSG_OUTPUTS).unwrap();
+        assert_eq!(size(&screen), (1920.0, 1080.0));
+    }
+
+    #[test]
+    fn test_parse_sway_output_by_name_found() {
+        // HDMI-A-1 lists a non-current 1080p mode before its current 1440p one
+        let screen = ScreenInfo::parse_sway_output_by_name(SWAYMSG_OUTPUTS, "HDMI-A-1").unwrap();
+        assert_eq!(size(&screen), (2560.0, 1440.0));
+    }
+
+    #[test]
+    fn test_parse_sway_output_by_name_not_found() {
+        assert!(ScreenInfo::parse_sway_output_by_name(SWAYMSG_OUTPUTS, "DP-9").is_none());
+    }
+
+    #[test]
+    fn test_parse_xrandr_primary_with_portrait_secondary() {
+        let screen = ScreenInfo::parse_xrandr_primary(XRANDR_CURRENT).unwrap();
+        assert_eq!(size(&screen), (2560.0, 1440.0));
+        assert_eq!(origin(&screen), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_parse_xrandr_any_connected() {
+        let without_primary = XRANDR_CURRENT.replace("DP-1 connected primary", "DP-1 disconnected");
+        assert!(ScreenInfo::parse_xrandr_primary(&without_primary).is_none());
+
+        let screen = ScreenInfo::parse_xrandr_any_connected(&without_primary).unwrap();
+        assert_eq!(size(&screen), (1440.0, 2560.0));
+        assert_eq!(origin(&screen), (2560.0, 0.0));
+    }
+
+    #[test]
+    fn test_parse_drm_modes_with_interlaced() {
+        let screen = ScreenInfo::parse_drm_modes(DRM_MODES).unwrap();
+        assert_eq!(size(&screen), (2560.0, 1440.0));
+
+        // Interlaced modes don't parse and are skipped
+        let screen = ScreenInfo::parse_drm_modes("1920x1080i\n1280x720\n").unwrap();
+        assert_eq!(size(&screen), (1280.0, 720.0));
+
+        assert!(ScreenInfo::parse_drm_modes("").is_none());
+    }
+}
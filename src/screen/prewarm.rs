@@ -0,0 +1,51 @@
+//! Screen detection on a background thread, overlapping with the rest of startup.
+//! Detection only reads, so it needs nothing but owned copies of its inputs.
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::config::screen::ScreenConfig;
+use crate::env::Env;
+use crate::screen::ScreenInfo;
+use crate::screen::runner::{CommandRunner, FileSystem, SystemFileSystem, SystemRunner};
+
+/// Longest startup waits for a background detection before using defaults
+pub const DETECTION_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Detection running in the background, collected with `join`
+pub struct PendingDetection {
+    receiver: mpsc::Receiver<ScreenInfo>,
+}
+
+impl PendingDetection {
+    pub fn spawn(config: ScreenConfig, env: Env) -> Self {
+        Self::spawn_with(config, env, SystemRunner, SystemFileSystem)
+    }
+
+    /// Detect using the given command runner and filesystem (mockable for tests)
+    pub fn spawn_with<R, F>(config: ScreenConfig, env: Env, runner: R, fs: F) -> Self
+    where
+        R: CommandRunner + Send + 'static,
+        F: FileSystem + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            // The receiver is gone when join already timed out
+            let _ = sender.send(ScreenInfo::detect_with(&config, &env, &runner, &fs));
+        });
+        Self { receiver }
+    }
+
+    /// Wait up to `timeout` for the result. A hung detection is left behind and
+    /// the default screen is used instead.
+    pub fn join(self, timeout: Duration) -> ScreenInfo {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(screen) => screen,
+            Err(_) => {
+                eprintln!("Screen detection did not finish within {:?}, using defaults", timeout);
+                ScreenInfo::default()
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Captured result of an external command
+#[derive(Debug, Clone)]
+pub struct CommandOutput {
+    pub success: bool,
+    pub stdout: String,
+}
+
+/// Runs the external tools used for detection (hyprctl, swaymsg, xrandr, ...)
+pub trait CommandRunner {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput>;
+
+    /// Like `run`, with `input` written to the command's stdin.
+    /// Runners that can't feed stdin (e.g. test fakes) just run the command.
+    fn run_with_input(&self, program: &str, args: &[&str], _input: &str) -> io::Result<CommandOutput> {
+        self.run(program, args)
+    }
+
+    /// Like `run`, but reads at most `max_bytes` of stdout and gives up after `timeout`.
+    /// Output cut off at the limit is reported as unsuccessful.
+    fn run_limited(
+        &self,
+        program: &str,
+        args: &[&str],
+        max_bytes: usize,
+        _timeout: Duration,
+    ) -> io::Result<CommandOutput> {
+        let mut output = self.run(program, args)?;
+        if output.stdout.len() >= max_bytes {
+            let mut end = max_bytes;
+            while !output.stdout.is_char_boundary(end) {
+                end -= 1;
+            }
+            output.stdout.truncate(end);
+            output.success = false;
+        }
+        Ok(output)
+    }
+}
+
+/// Runs commands for real via `std::process::Command`
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        let output = Command::new(program).args(args).output()?;
+        Ok(CommandOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        })
+    }
+
+    fn run_with_input(&self, program: &str, args: &[&str], input: &str) -> io::Result<CommandOutput> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(input.as_bytes())?;
+            // Dropping stdin closes it so the command sees end of input
+        }
+        let output = child.wait_with_output()?;
+        Ok(CommandOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        })
+    }
+
+    fn run_limited(
+        &self,
+        program: &str,
+        args: &[&str],
+        max_bytes: usize,
+        timeout: Duration,
+    ) -> io::Result<CommandOutput> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| io::Error::other("stdout was not captured"))?;
+
+        // Read on a thread so a child that never closes stdout can't block us
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut bytes = Vec::with_capacity(max_bytes);
+            let result = stdout.take(max_bytes as u64).read_to_end(&mut bytes).map(|_| bytes);
+            let _ = sender.send(result);
+        });
+
+        let result = receiver.recv_timeout(timeout);
+        if let Ok(Ok(bytes)) = &result {
+            if bytes.len() < max_bytes {
+                // Reached end of output, the child is done
+                let status = child.wait()?;
+                return Ok(CommandOutput {
+                    success: status.success(),
+                    stdout: String::from_utf8_lossy(bytes).into_owned(),
+                });
+            }
+        }
+
+        // Still writing past the limit or stuck; killing it also ends the reader thread
+        let _ = child.kill();
+        let _ = child.wait();
+        match result {
+            Ok(Ok(bytes)) => Ok(CommandOutput {
+                success: false,
+                stdout: String::from_utf8_lossy(&bytes).into_owned(),
+            }),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("{} did not finish within {:?}", program, timeout),
+            )),
+        }
+    }
+}
+
+/// Read-only filesystem access used by the sysfs probes
+pub trait FileSystem {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        let bytes = self.read(path)?;
+        String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Reads from the real filesystem
+pub struct SystemFileSystem;
+
+impl FileSystem for SystemFileSystem {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(path)?
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+}
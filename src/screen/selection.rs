@@ -0,0 +1,68 @@
+use crate::config::screen::ScreenConfig;
+
+use super::ScreenInfoEntry;
+
+/// A listed output plus what the session said about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    pub entry: ScreenInfoEntry,
+    /// Has keyboard focus (or the active window); on X11, the mouse pointer
+    pub focused: bool,
+    /// Marked primary by the compositor or xrandr
+    pub primary: bool,
+}
+
+impl From<ScreenInfoEntry> for MonitorInfo {
+    fn from(entry: ScreenInfoEntry) -> Self {
+        Self {
+            entry,
+            focused: false,
+            primary: false,
+        }
+    }
+}
+
+impl MonitorInfo {
+    /// Connector or display name equals `name`, ignoring case
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.entry.matches_name(name)
+    }
+
+    /// `(x, y)` in the global layout lies on this monitor
+    pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+        let (x, y) = (x as f32, y as f32);
+        let entry = &self.entry;
+        x >= entry.x && x < entry.x + entry.width && y >= entry.y && y < entry.y + entry.height
+    }
+}
+
+/// The monitor named by `force_monitor`, None when it isn't connected
+pub fn forced_monitor<'a>(monitors: &'a [MonitorInfo], config: &ScreenConfig) -> Option<&'a MonitorInfo> {
+    let name = config.force_monitor.as_deref()?;
+    monitors.iter().find(|monitor| monitor.matches_name(name))
+}
+
+/// Pick the monitor to open on: the forced one when connected, then focused
+/// and primary (primary first without `prefer_focused_screen`), then the one
+/// under the cursor, then the first listed.
+pub fn select_monitor<'a>(
+    monitors: &'a [MonitorInfo],
+    config: &ScreenConfig,
+    cursor: Option<(i32, i32)>,
+) -> Option<&'a MonitorInfo> {
+    if let Some(forced) = forced_monitor(monitors, config) {
+        return Some(forced);
+    }
+
+    let focused = || monitors.iter().find(|monitor| monitor.focused);
+    let primary = || monitors.iter().find(|monitor| monitor.primary);
+    let marked = if config.prefer_focused_screen {
+        focused().or_else(primary)
+    } else {
+        primary().or_else(focused)
+    };
+
+    marked
+        .or_else(|| cursor.and_then(|cursor| monitors.iter().find(|monitor| monitor.contains(cursor))))
+        .or_else(|| monitors.first())
+}
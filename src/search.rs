@@ -0,0 +1,89 @@
+use crate::accents::{self, entities, names};
+
+/// Whether a typed character goes into the search filter. Digits stay number
+/// key shortcuts and whitespace or control characters are never searched for.
+pub fn accepts(ch: char) -> bool {
+    !ch.is_ascii_digit() && !ch.is_whitespace() && !ch.is_control()
+}
+
+/// Whether `ch` is shown for `filter`: the character contains it, or a word of
+/// its name starts with it, so "c" keeps ç, č and ĉ-like variants ("cedilla",
+/// "caron", "circumflex"). An empty filter keeps everything.
+pub fn matches(ch: char, filter: &str, case_insensitive: bool) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+    let fold = |text: String| if case_insensitive { text.to_lowercase() } else { text };
+    let filter = fold(filter.to_string());
+    if fold(ch.to_string()).contains(&filter) {
+        return true;
+    }
+    names::variant_name(ch).is_some_and(|name| fold(name).split(' ').any(|word| word.starts_with(&filter)))
+}
+
+/// `groups` with only the variants matching `filter`, dropping groups left empty
+pub fn filter_groups(groups: &[Vec<char>], filter: &str, case_insensitive: bool) -> Vec<Vec<char>> {
+    groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .copied()
+                .filter(|&ch| matches(ch, filter, case_insensitive))
+                .collect::<Vec<_>>()
+        })
+        .filter(|group| !group.is_empty())
+        .collect()
+}
+
+/// How well `ch` matches `filter`, lower is better: the exact character, the
+/// character up to case, then a word of its name. None when it doesn't match.
+pub fn rank(ch: char, filter: &str, case_insensitive: bool) -> Option<u8> {
+    if ch.to_string() == filter {
+        Some(0)
+    } else if case_insensitive && ch.to_lowercase().eq(filter.to_lowercase().chars()) {
+        Some(1)
+    } else if matches(ch, filter, case_insensitive) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Best match for `filter` among `groups`; equally good matches keep display order
+pub fn top_match(groups: &[Vec<char>], filter: &str, case_insensitive: bool) -> Option<char> {
+    groups
+        .iter()
+        .flatten()
+        .filter_map(|&ch| rank(ch, filter, case_insensitive).map(|rank| (rank, ch)))
+        .min_by_key(|&(rank, _)| rank)
+        .map(|(_, ch)| ch)
+}
+
+/// Character a trigger word stands for: its variant name without the space
+/// ("eacute", "Eacute") or its HTML entity name ("szlig")
+pub fn resolve_trigger(word: &str) -> Option<char> {
+    if word.is_empty() {
+        return None;
+    }
+    let entity = format!("&{};", word);
+    ('a'..='z')
+        .chain('A'..='Z')
+        .chain("-.!?\"'".chars())
+        .flat_map(|base| accents::groups_for(base, true, true).into_iter().flatten())
+        .find(|&ch| {
+            names::variant_name(ch).is_some_and(|name| name.replace(' ', "") == word)
+                || entities::html_entity(ch).is_some_and(|html| html == entity)
+        })
+}
+
+/// Text to insert for `typed` once it ends in a space: the trigger word's
+/// character followed by the space, or `typed` unchanged when it isn't a
+/// trigger. None while no space has been typed.
+pub fn expand_on_space(typed: &str) -> Option<String> {
+    let word = typed.strip_suffix(' ')?;
+    match resolve_trigger(word) {
+        Some(ch) => Some(format!("{} ", ch)),
+        None => Some(typed.to_string()),
+    }
+}
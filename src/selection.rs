@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use crate::accents;
+use crate::config::tool::ToolConfig;
+use crate::env::Env;
+use crate::screen::runner::CommandRunner;
+
+/// Bytes read from the selection; a single character is at most 4 bytes of UTF-8,
+/// so anything longer is rejected without reading a multi-megabyte selection
+const READ_LIMIT: usize = 16;
+
+/// The popup should not wait on a slow or stuck selection owner
+const READ_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Base character taken from the primary selection, when the selection is a
+/// single character that has variants in the enabled sets
+pub fn base_from_primary_selection(
+    config: &ToolConfig,
+    env: &Env,
+    runner: &dyn CommandRunner,
+) -> Option<char> {
+    let (program, args): (&str, &[&str]) = if env.is_wayland() {
+        ("wl-paste", &["--primary", "--no-newline"])
+    } else if env.has_x11() {
+        ("xclip", &["-o", "-selection", "primary"])
+    } else {
+        return None;
+    };
+
+    let output = match runner.run_limited(program, args, READ_LIMIT, READ_TIMEOUT) {
+        Ok(output) if output.success => output,
+        Ok(_) => return None,
+        Err(e) => {
+            eprintln!("Could not read primary selection via {}: {}", program, e);
+            return None;
+        }
+    };
+
+    parse_selection(&output.stdout, config)
+}
+
+/// The selected character, if `text` is exactly one character with variants
+pub fn parse_selection(text: &str, config: &ToolConfig) -> Option<char> {
+    // xclip keeps a trailing newline, wl-paste is told to drop it
+    let mut chars = text.trim().chars();
+    let ch = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+
+    let groups = accents::groups_for(ch, config.include_typographic, config.include_ipa);
+    if groups.is_empty() { None } else { Some(ch) }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::accents;
+use crate::clipboard_ring::ClipboardRing;
+use crate::config::app::AppConfig;
+use crate::env::Env;
+use crate::geometry::Rect;
+use crate::output::OutputBackend;
+use crate::screen::{ScreenInfo, fixtures};
+
+/// Result of one self-test section
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub name: &'static str,
+    /// What went wrong, empty when the section passed
+    pub failures: Vec<String>,
+}
+
+impl Section {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Run every section without opening a window. Files are only written inside `scratch_dir`.
+pub fn run(scratch_dir: &Path) -> Vec<Section> {
+    vec![
+        section("config", || check_config(scratch_dir)),
+        section("character sets", check_character_sets),
+        section("geometry", check_geometry),
+        section("state file", || check_state_file(scratch_dir)),
+        section("parsers", check_parsers),
+        section("output selection", check_output_selection),
+    ]
+}
+
+fn section(name: &'static str, check: impl FnOnce() -> Vec<String>) -> Section {
+    Section {
+        name,
+        failures: check(),
+    }
+}
+
+fn check_config(scratch_dir: &Path) -> Vec<String> {
+    let mut failures = Vec::new();
+    let path = scratch_dir.join("config.ron");
+
+    // The first load writes the default config, the second reads it back
+    for attempt in ["create", "reload"] {
+        match AppConfig::load_from_path(&path) {
+            Ok(config) => {
+                match config.validate() {
+                    Ok(warnings) => failures.extend(
+                        warnings
+                            .iter()
+                            .map(|warning| format!("{}: default config warns: {}", attempt, warning)),
+                    ),
+                    Err(e) => failures.push(format!("{}: default config is invalid: {}", attempt, e)),
+                }
+            }
+            Err(e) => failures.push(format!("{}: {}", attempt, e)),
+        }
+    }
+    failures
+}
+
+fn check_character_sets() -> Vec<String> {
+    let mut failures = Vec::new();
+
+    for base in ('a'..='z').chain('A'..='Z').chain("-.!?\"'".chars()) {
+        let groups = accents::groups_for(base, true, true);
+        if groups.iter().any(Vec::is_empty) {
+            failures.push(format!("{:?}: empty group", base));
+        }
+
+        let mut seen = HashSet::new();
+        for &ch in groups.iter().flatten() {
+            if !seen.insert(ch) {
+                failures.push(format!("{:?}: {:?} appears twice", base, ch));
+            }
+        }
+
+        let lower = base.to_lowercase().next().unwrap_or(base);
+        if base != lower && groups.len() != accents::groups_for(lower, true, true).len() {
+            failures.push(format!("{:?}: different groups than {:?}", base, lower));
+        }
+    }
+    failures
+}
+
+fn check_geometry() -> Vec<String> {
+    // Origin in logical pixels, mode in device pixels, and scale, as compositors report them
+    let monitors = [
+        ("1080p", (0.0, 0.0), (1920.0, 1080.0), 1.0),
+        ("1440p right of it", (1920.0, 0.0), (2560.0, 1440.0), 1.0),
+        ("rotated portrait", (4480.0, 0.0), (1440.0, 2560.0), 1.0),
+        ("4K at scale 2", (-1920.0, 0.0), (3840.0, 2160.0), 2.0),
+        ("4K at scale 1.5", (0.0, 1080.0), (3840.0, 2160.0), 1.5),
+        ("1440p at scale 1.25", (2560.0, 1440.0), (2560.0, 1440.0), 1.25),
+        ("small", (0.0, 0.0), (800.0, 600.0), 1.0),
+    ];
+    let config = AppConfig::default();
+    let mut failures = Vec::new();
+
+    for (name, (x, y), (mode_width, mode_height), scale) in monitors {
+        let (width, height) = ScreenInfo::logical_size(mode_width, mode_height, scale);
+        if (width * scale - mode_width).abs() > 0.5 || (height * scale - mode_height).abs() > 0.5 {
+            failures.push(format!("{}: logical size {}x{} does not scale back to the mode", name, width, height));
+        }
+        let monitor = Rect::new(x, y, width, height);
+
+        let (window_width, window_height) = config.calculate_window_size(monitor.width, monitor.height);
+        let (window_x, window_y) =
+            config.calculate_window_position(monitor.width, monitor.height, window_width, None);
+        let window = config.ensure_visible(
+            Rect::new(monitor.x + window_x, monitor.y + window_y, window_width, window_height),
+            monitor,
+        );
+
+        if window.visible_fraction_in(&monitor) < 1.0 {
+            failures.push(format!("{}: window {:?} is not fully on {:?}", name, window, monitor));
+        }
+    }
+    failures
+}
+
+fn check_state_file(scratch_dir: &Path) -> Vec<String> {
+    let path = scratch_dir.join("history.ron");
+    let mut ring = ClipboardRing::new(5);
+    for text in ["é", "ß", "€"] {
+        ring.push(text.to_string());
+    }
+
+    if let Err(e) = ring.save_to_path(&path) {
+        return vec![format!("save: {}", e)];
+    }
+    match ClipboardRing::load_from_path(&path, 5) {
+        Ok(loaded) if loaded == ring => Vec::new(),
+        Ok(loaded) => vec![format!("read back {:?}, wrote {:?}", loaded, ring)],
+        Err(e) => vec![format!("load: {}", e)],
+    }
+}
+
+fn check_parsers() -> Vec<String> {
+    let results = [
+        ("hyprctl", ScreenInfo::parse_hyprctl_output(fixtures::HYPRCTL_MONITORS), (2560.0, 1440.0)),
+        (
+            "hyprctl focused",
+            ScreenInfo::parse_hyprctl_focused(fixtures::HYPRCTL_MONITORS),
+            (1440.0, 2560.0),
+        ),
+        ("swaymsg", ScreenInfo::parse_swaymsg_output(fixtures::SWAYMSG_OUTPUTS), (1920.0, 1080.0)),
+        (
+            "swaymsg by name",
+            ScreenInfo::parse_sway_output_by_name(fixtures::SWAYMSG_OUTPUTS, "HDMI-A-1"),
+            (2560.0, 1440.0),
+        ),
+        ("xrandr primary", ScreenInfo::parse_xrandr_primary(fixtures::XRANDR_CURRENT), (2560.0, 1440.0)),
+        (
+            "xrandr any connected",
+            ScreenInfo::parse_xrandr_any_connected(fixtures::XRANDR_CURRENT),
+            (2560.0, 1440.0),
+        ),
+        (
+            "xrandr verbose",
+            ScreenInfo::parse_xrandr_verbose_primary(fixtures::XRANDR_VERBOSE),
+            (2560.0, 1440.0),
+        ),
+        ("wlr-randr", ScreenInfo::parse_wlr_randr_output(fixtures::WLR_RANDR), (2560.0, 1440.0)),
+        ("drm modes", ScreenInfo::parse_drm_modes(fixtures::DRM_MODES), (2560.0, 1440.0)),
+    ];
+
+    let mut failures = Vec::new();
+    for (name, parsed, expected) in results {
+        match parsed {
+            Some(screen) if (screen.width, screen.height) == expected => {}
+            Some(screen) => failures.push(format!(
+                "{}: got {}x{}, expected {}x{}",
+                name, screen.width, screen.height, expected.0, expected.1
+            )),
+            None => failures.push(format!("{}: no screen parsed", name)),
+        }
+    }
+
+    if ScreenInfo::parse_wlr_randr_entries(fixtures::WLR_RANDR).is_empty() {
+        failures.push("wlr-randr entries: no outputs parsed".to_string());
+    }
+    failures
+}
+
+fn check_output_selection() -> Vec<String> {
+    let wayland = Env {
+        wayland_display: Some("wayland-1".to_string()),
+        ..Env::default()
+    };
+    let x11 = Env {
+        display: Some(":0".to_string()),
+        ..Env::default()
+    };
+    let xwayland = Env {
+        display: Some(":0".to_string()),
+        ..wayland.clone()
+    };
+    let x11_session_only = Env {
+        session_type: Some("x11".to_string()),
+        ..Env::default()
+    };
+
+    let matrix = [
+        ("wayland", wayland, OutputBackend::Wtype, Some(OutputBackend::WlCopy)),
+        ("x11", x11, OutputBackend::Xdotool, Some(OutputBackend::Xclip)),
+        ("xwayland", xwayland, OutputBackend::Wtype, Some(OutputBackend::WlCopy)),
+        ("x11 session type", x11_session_only, OutputBackend::Xdotool, Some(OutputBackend::Xclip)),
+        ("no display", Env::default(), OutputBackend::Stdout, None),
+    ];
+
+    let mut failures = Vec::new();
+    for (name, env, typing, clipboard) in matrix {
+        let selected = OutputBackend::auto_select(&env);
+        if selected != typing {
+            failures.push(format!("{}: selected {:?}, expected {:?}", name, selected, typing));
+        }
+        let selected = OutputBackend::clipboard_for(&env);
+        if selected != clipboard {
+            failures.push(format!("{}: clipboard {:?}, expected {:?}", name, selected, clipboard));
+        }
+    }
+    failures
+}
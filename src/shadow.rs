@@ -0,0 +1,98 @@
+use iced::widget::canvas;
+use iced::{Color, Point, Rectangle, Renderer, Size, Theme, mouse};
+
+use crate::config::shadow::ShadowConfig;
+use crate::env::Env;
+use crate::geometry::Rect;
+use crate::screen::runner::CommandRunner;
+
+/// Corner radius of the popup panel when no border sets one
+pub const CORNER_RADIUS: f32 = 8.0;
+
+/// X11 compositing managers that make transparent windows work
+const X11_COMPOSITORS: &str = "picom|compton|xcompmgr";
+
+/// Whether the session can show a transparent window, which the shadow needs.
+/// Wayland compositors always composite, as do the KDE and GNOME window managers;
+/// other X11 sessions need a compositing manager running. When that can't be
+/// checked the shadow is kept.
+pub fn transparency_supported(env: &Env, runner: &dyn CommandRunner) -> bool {
+    if env.is_wayland() || env.is_kde() || env.is_gnome() {
+        return true;
+    }
+    match runner.run("pgrep", &["-x", X11_COMPOSITORS]) {
+        Ok(output) => output.success,
+        Err(_) => true,
+    }
+}
+
+/// Concentric rectangles used to approximate the blur
+const BLUR_STEPS: usize = 8;
+
+/// Shadow layers for a window of the given size, outermost first, each with the
+/// alpha (0.0 to 1.0) it is filled with. The layers overlap, so the alpha adds up
+/// towards the middle and fades out over `blur_radius`.
+pub fn layers(shadow: &ShadowConfig, window_width: f32, window_height: f32) -> Vec<(Rect, f32)> {
+    let margin = shadow.margin();
+    let content_width = (window_width - 2.0 * margin).max(0.0);
+    let content_height = (window_height - 2.0 * margin).max(0.0);
+    let alpha = f32::from(shadow.color[3]) / 255.0 / BLUR_STEPS as f32;
+
+    (0..BLUR_STEPS)
+        .map(|step| {
+            let spread = margin * (BLUR_STEPS - step) as f32 / BLUR_STEPS as f32;
+            let rect = Rect::new(
+                margin + shadow.offset_x - spread,
+                margin + shadow.offset_y - spread,
+                content_width + 2.0 * spread,
+                content_height + 2.0 * spread,
+            );
+            (rect, alpha)
+        })
+        .collect()
+}
+
+/// Canvas program drawing the shadow behind the popup panel
+pub struct ShadowLayer {
+    shadow: ShadowConfig,
+    /// Corner radius of the popup casting the shadow
+    corner_radius: f32,
+}
+
+impl ShadowLayer {
+    pub fn new(shadow: ShadowConfig, corner_radius: f32) -> Self {
+        Self {
+            shadow,
+            corner_radius,
+        }
+    }
+}
+
+impl<Message> canvas::Program<Message> for ShadowLayer {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let [r, g, b, _] = self.shadow.color;
+
+        for (rect, alpha) in layers(&self.shadow, bounds.width, bounds.height) {
+            // Rounder corners on the outer layers keep the fade even around the corners
+            let spread = self.shadow.margin() + self.shadow.offset_x - rect.x;
+            let path = canvas::Path::rounded_rectangle(
+                Point::new(rect.x, rect.y),
+                Size::new(rect.width, rect.height),
+                (self.corner_radius + spread).into(),
+            );
+            frame.fill(&path, Color::from_rgba8(r, g, b, alpha));
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,31 @@
+/// Joins the picks of one sticky session, where the popup comes back after a
+/// pick: every pick after the first is preceded by the separator
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StickySession {
+    separator: String,
+    picked_any: bool,
+}
+
+impl StickySession {
+    pub fn new(separator: String) -> Self {
+        Self {
+            separator,
+            picked_any: false,
+        }
+    }
+
+    /// Text to insert for `pick`: the pick itself the first time, the separator
+    /// and the pick afterwards
+    pub fn text_for(&mut self, pick: &str) -> String {
+        if std::mem::replace(&mut self.picked_any, true) {
+            format!("{}{}", self.separator, pick)
+        } else {
+            pick.to_string()
+        }
+    }
+
+    /// Start over when the window closes, so the next pick is a first pick again
+    pub fn reset(&mut self) {
+        self.picked_any = false;
+    }
+}
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use crate::env::Env;
+use crate::output::OutputBackend;
+use crate::screen::runner::CommandRunner;
+
+/// Text output by `test-output`: an accent, CJK and a symbol, which trip up different backends
+pub const MARKER: &str = "café 日本 €";
+
+/// Seconds given to focus a text field before typing
+pub const COUNTDOWN_SECS: u64 = 3;
+
+/// Which output path `test-output` exercises
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestOutputMode {
+    /// Type the marker into the focused app
+    Type,
+    /// Copy the marker to the clipboard
+    Clipboard,
+}
+
+impl TestOutputMode {
+    pub fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "type" => Some(TestOutputMode::Type),
+            "clipboard" => Some(TestOutputMode::Clipboard),
+            _ => None,
+        }
+    }
+
+    /// Backend to test: the configured (or auto-selected) one for typing,
+    /// the session's clipboard tool otherwise
+    pub fn backend(self, configured: Option<OutputBackend>, env: &Env) -> Option<OutputBackend> {
+        match self {
+            TestOutputMode::Type => Some(configured.unwrap_or_else(|| OutputBackend::auto_select(env))),
+            TestOutputMode::Clipboard => OutputBackend::clipboard_for(env),
+        }
+    }
+}
+
+/// Countdown lines printed one second apart before typing, e.g. "3...", "2...", "1..."
+pub fn countdown_lines(secs: u64) -> Vec<String> {
+    (1..=secs).rev().map(|remaining| format!("{}...", remaining)).collect()
+}
+
+/// Output `MARKER` through `backend`, counting down first when typing
+pub fn run(
+    mode: TestOutputMode,
+    backend: OutputBackend,
+    runner: &dyn CommandRunner,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if mode == TestOutputMode::Type {
+        println!("Focus a text field, typing {:?} via {:?} in", MARKER, backend);
+        for line in countdown_lines(COUNTDOWN_SECS) {
+            println!("{}", line);
+            std::thread::sleep(Duration::from_secs(1));
+        }
+    }
+
+    backend.emit(MARKER, runner)?;
+
+    match mode {
+        TestOutputMode::Type => println!("Typed {:?} via {:?}, check that it arrived intact", MARKER, backend),
+        TestOutputMode::Clipboard => println!("Copied {:?} to the clipboard via {:?}", MARKER, backend),
+    }
+    Ok(())
+}
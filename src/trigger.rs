@@ -0,0 +1,119 @@
+//! Triggers that bring back a `SingletonDaemon` window: one line each on stdin,
+//! e.g. from a compositor keybinding writing into the daemon's input pipe, or on
+//! the control socket, where later launches forward their `accent:` URI.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::uri::AccentUri;
+
+/// How often the app checks for queued triggers
+pub const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Triggers read but not yet handled by the app, oldest first
+static PENDING: Mutex<Vec<Trigger>> = Mutex::new(Vec::new());
+
+/// One request to show the picker
+#[derive(Debug, Clone, PartialEq)]
+pub enum Trigger {
+    /// Empty line or `show`: the configured base character
+    Show,
+    /// A single character: the variants of that base
+    Base(char),
+    /// An `accent:` URI, its options apply to this use only
+    Uri(AccentUri),
+}
+
+impl Trigger {
+    /// Parse one input line, surrounding whitespace ignored
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line == "show" {
+            return Some(Trigger::Show);
+        }
+        if AccentUri::is_uri(line) {
+            return AccentUri::parse(line).ok().map(Trigger::Uri);
+        }
+        let mut chars = line.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Some(Trigger::Base(ch)),
+            _ => None,
+        }
+    }
+}
+
+/// Queue the trigger on each line of `reader` until it ends
+pub fn read_lines(reader: impl BufRead) {
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        match Trigger::parse(&line) {
+            Some(trigger) => push(trigger),
+            None => eprintln!(
+                "Ignoring trigger {:?}, expected \"show\", one character or a valid accent: URI",
+                line
+            ),
+        }
+    }
+}
+
+/// Read triggers from stdin on a background thread
+pub fn spawn_stdin_reader() {
+    std::thread::spawn(|| read_lines(std::io::stdin().lock()));
+}
+
+/// `quick-accent.sock` in the runtime dir, or the temp dir without one
+pub fn socket_path() -> PathBuf {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("quick-accent.sock")
+}
+
+/// Read triggers from connections to the socket at `path` on a background thread.
+/// Errs when another daemon already answers there; a socket left by one that
+/// exited is replaced.
+pub fn spawn_socket_listener(path: &Path) -> io::Result<()> {
+    if UnixStream::connect(path).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("another daemon is listening on {:?}", path),
+        ));
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let _ = std::fs::remove_file(path);
+    let listener = UnixListener::bind(path)?;
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            read_lines(BufReader::new(stream));
+        }
+    });
+    Ok(())
+}
+
+/// Hand `line` to the daemon listening at `path`. Errs when none is running.
+pub fn forward(path: &Path, line: &str) -> io::Result<()> {
+    let mut stream = UnixStream::connect(path)?;
+    writeln!(stream, "{}", line)
+}
+
+pub fn push(trigger: Trigger) {
+    if let Ok(mut pending) = PENDING.lock() {
+        pending.push(trigger);
+    }
+}
+
+/// Triggers queued since the last call, oldest first
+pub fn take_pending() -> Vec<Trigger> {
+    PENDING
+        .lock()
+        .map(|mut pending| std::mem::take(&mut *pending))
+        .unwrap_or_default()
+}
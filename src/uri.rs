@@ -0,0 +1,212 @@
+use std::fmt;
+
+use crate::config::tool::ToolConfig;
+use crate::output::{OutputBackend, OutputRepresentation};
+
+/// Scheme launchers use to open the picker, e.g. `accent:e?uppercase=1&set=ipa`
+pub const SCHEME: &str = "accent:";
+
+/// Which character groups to show, the `set` query key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterSet {
+    /// Accented variants only
+    Accents,
+    /// Accents and typographic symbols
+    Typographic,
+    /// Accents and IPA letters
+    Ipa,
+    /// Every group
+    All,
+}
+
+impl CharacterSet {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "accents" => Some(CharacterSet::Accents),
+            "typographic" => Some(CharacterSet::Typographic),
+            "ipa" => Some(CharacterSet::Ipa),
+            "all" => Some(CharacterSet::All),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed `accent:` URI
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccentUri {
+    pub base: char,
+    /// `uppercase=1|0`: force the base's case
+    pub uppercase: Option<bool>,
+    /// `set=accents|typographic|ipa|all`
+    pub set: Option<CharacterSet>,
+    /// `output=wtype|xdotool|stdout|wl-copy|xclip`
+    pub output: Option<OutputBackend>,
+    /// `print=1`: print the pick instead of typing it, same as `output=stdout`
+    pub print: bool,
+    /// `as=glyph|html|latex`: emit the character, its HTML entity or LaTeX command
+    pub representation: Option<OutputRepresentation>,
+    /// Query keys that were not recognized and are ignored
+    pub ignored_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UriError(pub String);
+
+impl fmt::Display for UriError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for UriError {}
+
+impl AccentUri {
+    pub fn is_uri(arg: &str) -> bool {
+        arg.starts_with(SCHEME)
+    }
+
+    pub fn parse(uri: &str) -> Result<Self, UriError> {
+        let rest = uri
+            .strip_prefix(SCHEME)
+            .ok_or_else(|| UriError(format!("{:?} does not start with {:?}", uri, SCHEME)))?;
+        let (base_part, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let base = percent_decode(base_part)?;
+        let mut chars = base.chars();
+        let base = match (chars.next(), chars.next()) {
+            (Some(ch), None) if !ch.is_whitespace() && !ch.is_control() => ch,
+            (None, _) => return Err(UriError(format!("{:?} has no base character", uri))),
+            _ => {
+                return Err(UriError(format!(
+                    "Base {:?} must be a single printable character",
+                    base
+                )));
+            }
+        };
+
+        let mut parsed = AccentUri {
+            base,
+            uppercase: None,
+            set: None,
+            output: None,
+            print: false,
+            representation: None,
+            ignored_keys: Vec::new(),
+        };
+
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = percent_decode(key)?;
+            let value = percent_decode(value)?;
+            match key.as_str() {
+                "uppercase" => parsed.uppercase = Some(parse_flag(&key, &value)?),
+                "print" => parsed.print = parse_flag(&key, &value)?,
+                "set" => {
+                    parsed.set = Some(CharacterSet::parse(&value).ok_or_else(|| {
+                        UriError(format!(
+                            "Unknown set {:?}, expected accents, typographic, ipa or all",
+                            value
+                        ))
+                    })?);
+                }
+                "output" => {
+                    parsed.output = Some(parse_backend(&value).ok_or_else(|| {
+                        UriError(format!(
+                            "Unknown output {:?}, expected wtype, xdotool, stdout, wl-copy or xclip",
+                            value
+                        ))
+                    })?);
+                }
+                "as" => {
+                    parsed.representation = Some(parse_representation(&value).ok_or_else(|| {
+                        UriError(format!("Unknown representation {:?}, expected glyph, html or latex", value))
+                    })?);
+                }
+                _ => parsed.ignored_keys.push(key),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Apply the URI on top of the loaded config
+    pub fn apply(&self, tool: &mut ToolConfig) {
+        tool.base_character = match self.uppercase {
+            Some(true) => single_char(self.base.to_uppercase()).unwrap_or(self.base),
+            Some(false) => single_char(self.base.to_lowercase()).unwrap_or(self.base),
+            None => self.base,
+        };
+
+        if let Some(set) = self.set {
+            tool.include_typographic = matches!(set, CharacterSet::Typographic | CharacterSet::All);
+            tool.include_ipa = matches!(set, CharacterSet::Ipa | CharacterSet::All);
+        }
+
+        if self.print {
+            tool.output_backend = Some(OutputBackend::Stdout);
+        } else if let Some(output) = self.output {
+            tool.output_backend = Some(output);
+        }
+
+        if let Some(representation) = self.representation {
+            tool.output_representation = representation;
+        }
+    }
+}
+
+/// Decode `%XX` escapes; the result must be valid UTF-8
+pub fn percent_decode(input: &str) -> Result<String, UriError> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let byte = input
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| UriError(format!("Invalid percent escape in {:?}", input)))?;
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| UriError(format!("{:?} does not decode to UTF-8", input)))
+}
+
+fn parse_flag(key: &str, value: &str) -> Result<bool, UriError> {
+    match value {
+        "1" | "true" => Ok(true),
+        "0" | "false" => Ok(false),
+        _ => Err(UriError(format!("{} must be 1 or 0, got {:?}", key, value))),
+    }
+}
+
+fn parse_backend(name: &str) -> Option<OutputBackend> {
+    match name {
+        "wtype" => Some(OutputBackend::Wtype),
+        "xdotool" => Some(OutputBackend::Xdotool),
+        "stdout" => Some(OutputBackend::Stdout),
+        "wl-copy" => Some(OutputBackend::WlCopy),
+        "xclip" => Some(OutputBackend::Xclip),
+        _ => None,
+    }
+}
+
+fn parse_representation(name: &str) -> Option<OutputRepresentation> {
+    match name {
+        "glyph" => Some(OutputRepresentation::Glyph),
+        "html" => Some(OutputRepresentation::Html),
+        "latex" => Some(OutputRepresentation::Latex),
+        _ => None,
+    }
+}
+
+/// Keep case mappings like 'ß' -> "SS" that produce more than one char unchanged
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Some(ch),
+        _ => None,
+    }
+}
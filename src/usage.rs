@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::clipboard_ring::ClipboardRing;
+
+const SECONDS_PER_DAY: f32 = 24.0 * 60.0 * 60.0;
+
+/// Ranking weight of a character picked `count` times, last at `last_used`: the
+/// count halves for every `half_life_days` since then, so a burst long ago
+/// ranks below a few recent picks. A `last_used` in the future (clock changes)
+/// counts as now. `validate` only allows a positive half-life; anything else
+/// leaves the count undecayed rather than dividing by zero.
+pub fn decayed_score(count: u32, last_used: SystemTime, now: SystemTime, half_life_days: f32) -> f32 {
+    if half_life_days <= 0.0 {
+        return count as f32;
+    }
+    let age = now.duration_since(last_used).unwrap_or(Duration::ZERO);
+    let age_days = age.as_secs_f32() / SECONDS_PER_DAY;
+    count as f32 * 0.5_f32.powf(age_days / half_life_days)
+}
+
+/// How often a character was picked, and when last
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct Usage {
+    pub count: u32,
+    pub last_used: SystemTime,
+}
+
+/// Picks per character, kept between runs so the variants used most lately come first
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct UsageHistory {
+    entries: HashMap<char, Usage>,
+}
+
+impl UsageHistory {
+    /// Count a pick of `ch` at `now`
+    pub fn record(&mut self, ch: char, now: SystemTime) {
+        let usage = self.entries.entry(ch).or_insert(Usage {
+            count: 0,
+            last_used: now,
+        });
+        usage.count = usage.count.saturating_add(1);
+        usage.last_used = usage.last_used.max(now);
+    }
+
+    pub fn get(&self, ch: char) -> Option<Usage> {
+        self.entries.get(&ch).copied()
+    }
+
+    /// `decayed_score` of `ch`, 0 when it was never picked
+    pub fn score(&self, ch: char, now: SystemTime, half_life_days: f32) -> f32 {
+        self.get(ch)
+            .map_or(0.0, |usage| decayed_score(usage.count, usage.last_used, now, half_life_days))
+    }
+
+    /// Order `group` by score, highest first. Characters never picked keep their
+    /// order behind the picked ones.
+    pub fn rank(&self, group: &mut [char], now: SystemTime, half_life_days: f32) {
+        group.sort_by(|a, b| {
+            self.score(*b, now, half_life_days)
+                .total_cmp(&self.score(*a, now, half_life_days))
+        });
+    }
+
+    /// `usage.ron` next to the clipboard ring's history in the data dir
+    pub fn default_path() -> PathBuf {
+        ClipboardRing::default_path().with_file_name("usage.ron")
+    }
+
+    /// Load the history from `path`, starting empty when the file does not exist yet
+    pub fn load_from_path<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+
+        // Same write-then-rename as the clipboard ring
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use iced::Size;
+use quick_accent::accents;
+use quick_accent::app::{App, Message, RESIZE_TIMEOUT};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+
+/// Three columns: 'z' fits one row, 'a' and 'e' need three
+fn config(base: char) -> AppConfig {
+    let mut config = AppConfig::default();
+    config.tool.base_character = base;
+    config.tool.grid_columns = Some(3);
+    config.tool.include_typographic = false;
+    config.tool.include_ipa = false;
+    config
+}
+
+/// Window sized for `base` like `main` does, but showing `shown`
+fn app_sized_for(base: char, shown: char) -> App {
+    let size = window_size(base);
+    App::new(config(shown), Size::new(size.0, size.1), ClipboardRing::default(), Arc::default())
+}
+
+fn window_size(base: char) -> (f32, f32) {
+    let cells = accents::groups_for(base, false, false).iter().map(Vec::len).sum();
+    config(base).calculate_grid_window_size(800.0, 1080.0, cells)
+}
+
+fn variants(base: char) -> Vec<char> {
+    accents::groups_for(base, false, false).concat()
+}
+
+#[test]
+fn growing_waits_for_the_resize() {
+    let mut app = app_sized_for('z', 'z');
+    let _ = app.update(Message::TriggerForKey('a'));
+    assert_eq!(app.visible_variants(), variants('z'));
+
+    let (width, height) = window_size('a');
+    let _ = app.update(Message::WindowResized(Size::new(width, height)));
+    assert_eq!(app.visible_variants(), variants('a'));
+}
+
+#[test]
+fn growing_gives_up_waiting_after_the_timeout() {
+    let mut app = app_sized_for('z', 'z');
+    let _ = app.update(Message::TriggerForKey('a'));
+
+    let _ = app.update(Message::ResizeTimedOut(Instant::now()));
+    assert_eq!(app.visible_variants(), variants('z'));
+
+    let _ = app.update(Message::ResizeTimedOut(Instant::now() + RESIZE_TIMEOUT));
+    assert_eq!(app.visible_variants(), variants('a'));
+}
+
+#[test]
+fn same_height_switches_right_away() {
+    let mut app = app_sized_for('a', 'a');
+    let _ = app.update(Message::TriggerForKey('e'));
+    assert_eq!(app.visible_variants(), variants('e'));
+}
+
+#[test]
+fn shrinking_switches_right_away() {
+    let mut app = app_sized_for('a', 'a');
+    let _ = app.update(Message::TriggerForKey('z'));
+    assert_eq!(app.visible_variants(), variants('z'));
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use iced::Size;
+use quick_accent::accents::{self, Category};
+use quick_accent::app::{App, Message};
+use quick_accent::category::CategoryStack;
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::layout;
+
+#[test]
+fn push_and_pop() {
+    let mut stack = CategoryStack::default();
+    assert_eq!(stack.current(), None);
+    assert_eq!(stack.breadcrumb(), None);
+
+    stack.push(Category::Typographic);
+    stack.push(Category::Typographic);
+    assert_eq!(stack.current(), Some(Category::Typographic));
+    assert_eq!(stack.breadcrumb().as_deref(), Some("Typographic \u{203a}"));
+
+    assert_eq!(stack.pop(), Some(Category::Typographic));
+    assert!(stack.is_empty());
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+fn top_level_shows_every_group() {
+    let stack = CategoryStack::default();
+    let groups = stack.scope(accents::categorized_groups('e', true, true));
+    assert_eq!(groups, accents::groups_for('e', true, true));
+}
+
+#[test]
+fn new_base_stays_within_the_category() {
+    let mut stack = CategoryStack::default();
+    stack.push(Category::Ipa);
+
+    let groups = stack.scope(accents::categorized_groups('o', true, true));
+    assert_eq!(groups, vec![vec!['ɔ']]);
+
+    // 'c' has no IPA letters, so nothing is left to show
+    assert!(stack.scope(accents::categorized_groups('c', true, true)).is_empty());
+}
+
+#[test]
+fn app_breadcrumb_follows_messages() {
+    let mut config = AppConfig::default();
+    config.tool.include_typographic = true;
+    let mut app = App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default());
+
+    let _ = app.update(Message::EnterCategory(Category::Typographic));
+    assert_eq!(app.breadcrumb().as_deref(), Some("Typographic \u{203a}"));
+
+    let _ = app.update(Message::PopCategory);
+    assert_eq!(app.breadcrumb(), None);
+}
+
+#[test]
+fn tab_without_a_highlight_does_nothing() {
+    let mut config = AppConfig::default();
+    config.tool.include_typographic = true;
+    let mut app = App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default());
+
+    let _ = app.update(Message::EnterHoveredCategory);
+    assert_eq!(app.breadcrumb(), None);
+}
+
+#[test]
+fn breadcrumb_adds_to_the_window_height() {
+    let mut config = AppConfig::default();
+    config.tool.grid_columns = Some(1);
+    let mut app = App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default());
+    let top_level = app.required_window_height();
+
+    let _ = app.update(Message::EnterCategory(Category::Accents));
+    assert_eq!(app.required_window_height(), top_level + layout::breadcrumb_height());
+}
@@ -0,0 +1,69 @@
+use quick_accent::config::app::AppConfig;
+use quick_accent::layout;
+
+#[test]
+fn default_padding_keeps_the_labelled_width() {
+    assert_eq!(
+        layout::padded_cell_width(32.0, 1, 0, layout::CELL_PADDING),
+        layout::labelled_cell_width(32.0, 1, 0)
+    );
+}
+
+#[test]
+fn padding_widens_the_cell_on_both_sides() {
+    let narrow = layout::padded_cell_width(32.0, 1, 0, 0.0);
+    assert_eq!(layout::padded_cell_width(32.0, 1, 0, 6.0), narrow + 12.0);
+}
+
+#[test]
+fn spacing_goes_between_cells_only() {
+    // 4 cells of 50 with 3 gaps of 10 need exactly 230
+    assert_eq!(layout::cells_per_row(230.0, 50.0, 10.0), 4);
+    assert_eq!(layout::cells_per_row(229.0, 50.0, 10.0), 3);
+    assert_eq!(layout::cells_per_row(200.0, 50.0, 0.0), 4);
+}
+
+#[test]
+fn padding_and_spacing_reduce_cells_per_row() {
+    let width = 800.0;
+    let default_cell = layout::padded_cell_width(32.0, 1, 0, layout::CELL_PADDING);
+    let default_fit = layout::cells_per_row(width, default_cell, layout::CELL_SPACING);
+
+    let roomy_cell = layout::padded_cell_width(32.0, 1, 0, 20.0);
+    assert!(layout::cells_per_row(width, roomy_cell, layout::CELL_SPACING) < default_fit);
+    assert!(layout::cells_per_row(width, default_cell, 24.0) < default_fit);
+    assert!(layout::cells_per_row(width, default_cell, 0.0) > default_fit);
+}
+
+#[test]
+fn at_least_one_cell_fits() {
+    assert_eq!(layout::cells_per_row(10.0, 50.0, 10.0), 1);
+}
+
+#[test]
+fn spacing_changes_the_grid_height() {
+    let mut config = AppConfig::default();
+    config.window.height = 10.0;
+    config.tool.grid_columns = Some(3);
+    config.theme.cell_spacing = 20.0;
+
+    let cell_height = config.tool.cell_height();
+    let (_, height) = config.calculate_grid_window_size(1920.0, 1080.0, 7);
+    assert_eq!(height, 3.0 * cell_height + 4.0 * 20.0);
+}
+
+#[test]
+fn negative_values_are_rejected() {
+    let mut config = AppConfig::default();
+    config.theme.cell_padding = -1.0;
+    assert!(config.validate().is_err());
+
+    let mut config = AppConfig::default();
+    config.theme.cell_spacing = -0.5;
+    assert!(config.validate().is_err());
+
+    let mut config = AppConfig::default();
+    config.theme.cell_padding = 0.0;
+    config.theme.cell_spacing = 0.0;
+    assert!(config.validate().is_ok());
+}
@@ -0,0 +1,39 @@
+use quick_accent::config::tool::{CharacterDisplay, ToolConfig};
+use quick_accent::layout;
+
+#[test]
+fn glyph_shows_the_character() {
+    assert_eq!(CharacterDisplay::Glyph.label('é'), "é");
+}
+
+#[test]
+fn hex_codepoint_is_zero_padded() {
+    assert_eq!(CharacterDisplay::HexCodepoint.label('é'), "00E9");
+    assert_eq!(CharacterDisplay::HexCodepoint.label('€'), "20AC");
+    // Invisible characters get a visible label
+    assert_eq!(CharacterDisplay::HexCodepoint.label('\u{200b}'), "200B");
+    assert_eq!(CharacterDisplay::HexCodepoint.label('\u{1f600}'), "1F600");
+}
+
+#[test]
+fn dec_codepoint() {
+    assert_eq!(CharacterDisplay::DecCodepoint.label('é'), "233");
+    assert_eq!(CharacterDisplay::DecCodepoint.label('€'), "8364");
+}
+
+#[test]
+fn html_entity_is_numeric() {
+    assert_eq!(CharacterDisplay::HtmlEntity.label('é'), "&#233;");
+    assert_eq!(CharacterDisplay::HtmlEntity.label('€'), "&#8364;");
+}
+
+#[test]
+fn default_is_glyph() {
+    assert_eq!(ToolConfig::default().character_display, CharacterDisplay::Glyph);
+}
+
+#[test]
+fn longer_labels_widen_cells() {
+    assert_eq!(layout::labelled_cell_width(32.0, 1, 0), layout::cell_width(32.0, 0));
+    assert!(layout::labelled_cell_width(32.0, 4, 0) > layout::cell_width(32.0, 0));
+}
@@ -0,0 +1,67 @@
+use quick_accent::accents;
+use quick_accent::accents::names::{SHORT_NAME_CHARS, short_name};
+use quick_accent::config::app::AppConfig;
+use quick_accent::layout;
+
+#[test]
+fn letters_show_their_accent() {
+    assert_eq!(short_name('é').as_deref(), Some("ACUTE"));
+    assert_eq!(short_name('É').as_deref(), Some("ACUTE"));
+    assert_eq!(short_name('æ').as_deref(), Some("LIGATURE"));
+}
+
+#[test]
+fn long_names_are_truncated() {
+    assert_eq!(short_name('ê').as_deref(), Some("CIRCUMFL"));
+    assert_eq!(short_name('ö').as_deref(), Some("DIAERESI"));
+}
+
+#[test]
+fn symbols_show_the_first_word_of_their_name() {
+    assert_eq!(short_name('€').as_deref(), Some("EURO"));
+    assert_eq!(short_name('©').as_deref(), Some("COPYRIGH"));
+    assert_eq!(short_name('ə').as_deref(), Some("SCHWA"));
+}
+
+#[test]
+fn plain_letters_have_no_name() {
+    assert_eq!(short_name('q'), None);
+}
+
+#[test]
+fn every_shown_character_has_a_short_name() {
+    for base in ('a'..='z').chain("-.!?\"'".chars()) {
+        for ch in accents::groups_for(base, true, true).into_iter().flatten() {
+            let name = short_name(ch).unwrap_or_else(|| panic!("{:?} has no short name", ch));
+            assert!(name.chars().count() <= SHORT_NAME_CHARS, "{:?}: {}", ch, name);
+        }
+    }
+}
+
+#[test]
+fn name_line_makes_cells_taller() {
+    let mut config = AppConfig::default();
+    let without = config.tool.cell_height();
+    config.tool.show_character_name = true;
+    assert_eq!(
+        config.tool.cell_height(),
+        without + layout::character_name_height(config.tool.character_name_font_size)
+    );
+
+    config.tool.grid_columns = Some(4);
+    let (_, height) = config.calculate_grid_window_size(1920.0, 1080.0, 12);
+    assert_eq!(height, layout::rows_height(3, config.tool.cell_height(), layout::CELL_SPACING));
+}
+
+#[test]
+fn window_must_fit_a_row_with_names() {
+    let mut config = AppConfig::default();
+    config.tool.show_character_name = true;
+    assert!(config.validate().is_ok());
+
+    config.window.height = 60.0;
+    assert!(config.validate().is_err());
+
+    config.tool.show_character_name = false;
+    assert!(config.validate().is_ok());
+}
@@ -0,0 +1,40 @@
+use quick_accent::clipboard_ring::ClipboardRing;
+
+#[test]
+fn push_evicts_oldest_at_capacity() {
+    let mut ring = ClipboardRing::new(3);
+    for text in ["é", "è", "ê", "ë"] {
+        ring.push(text.to_string());
+    }
+
+    assert_eq!(ring.len(), 3);
+    assert_eq!(ring.iter().collect::<Vec<_>>(), vec!["ë", "ê", "è"]);
+}
+
+#[test]
+fn repeated_pick_moves_to_front_without_evicting() {
+    let mut ring = ClipboardRing::new(3);
+    for text in ["é", "è", "ê", "é"] {
+        ring.push(text.to_string());
+    }
+
+    assert_eq!(ring.iter().collect::<Vec<_>>(), vec!["é", "ê", "è"]);
+}
+
+#[test]
+fn zero_capacity_keeps_nothing() {
+    let mut ring = ClipboardRing::new(0);
+    ring.push("é".to_string());
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn index_zero_is_most_recent() {
+    let mut ring = ClipboardRing::new(5);
+    ring.push("é".to_string());
+    ring.push("ß".to_string());
+
+    assert_eq!(ring.get(0), Some("ß"));
+    assert_eq!(ring.get(1), Some("é"));
+    assert_eq!(ring.get(2), None);
+}
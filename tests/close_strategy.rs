@@ -0,0 +1,30 @@
+use quick_accent::close::{CloseStep, CloseStrategy};
+
+#[test]
+fn exit_only_stops_the_runtime() {
+    assert_eq!(CloseStrategy::Exit.steps(), &[CloseStep::ExitRuntime]);
+    assert!(!CloseStrategy::Exit.exits_process());
+}
+
+#[test]
+fn close_then_exit_closes_the_window_first() {
+    assert_eq!(
+        CloseStrategy::CloseThenExit.steps(),
+        &[CloseStep::CloseWindow, CloseStep::ExitRuntime]
+    );
+    assert!(!CloseStrategy::CloseThenExit.exits_process());
+}
+
+#[test]
+fn process_exit_ends_the_process_last() {
+    assert_eq!(
+        CloseStrategy::ProcessExit.steps(),
+        &[CloseStep::CloseWindow, CloseStep::ExitRuntime, CloseStep::ExitProcess]
+    );
+    assert!(CloseStrategy::ProcessExit.exits_process());
+}
+
+#[test]
+fn default_keeps_plain_exit() {
+    assert_eq!(CloseStrategy::default(), CloseStrategy::Exit);
+}
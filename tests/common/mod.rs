@@ -0,0 +1,205 @@
+//! Fakes for the command runner and filesystem shared by the integration tests
+//! and benchmarks. Each test binary uses a different subset.
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Duration;
+
+use quick_accent::env::Env;
+use quick_accent::screen::runner::{CommandOutput, CommandRunner, FileSystem};
+
+fn not_found(what: impl std::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, what.to_string())
+}
+
+/// Answers commands from canned output and records every command line it was asked
+/// to run. Commands without an answer fail as if the program wasn't installed.
+/// Clones share the record, so a test can keep one after handing the other over.
+#[derive(Default, Clone)]
+pub struct FixtureRunner {
+    /// Command line prefix, whether the command succeeds, and its stdout
+    answers: Vec<(String, bool, String)>,
+    delay: Duration,
+    calls: Rc<RefCell<Vec<String>>>,
+}
+
+impl FixtureRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer command lines starting with `command`, e.g. "hyprctl" or
+    /// "hyprctl activewindow", with `stdout`. The first matching answer wins.
+    pub fn answer(mut self, command: &str, stdout: &str) -> Self {
+        self.answers.push((command.to_string(), true, stdout.to_string()));
+        self
+    }
+
+    /// Run command lines starting with `command`, but report failure
+    pub fn fail(mut self, command: &str) -> Self {
+        self.answers.push((command.to_string(), false, String::new()));
+        self
+    }
+
+    /// Sleep this long before answering, like a slow compositor
+    pub fn delayed(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Every command line run so far, e.g. "swaymsg -t get_outputs"
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.borrow().clone()
+    }
+
+    /// Program of every command run so far
+    pub fn programs(&self) -> Vec<String> {
+        self.calls()
+            .iter()
+            .map(|call| call.split(' ').next().unwrap_or_default().to_string())
+            .collect()
+    }
+}
+
+impl CommandRunner for FixtureRunner {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        let line = format!("{} {}", program, args.join(" ")).trim_end().to_string();
+        self.calls.borrow_mut().push(line.clone());
+        std::thread::sleep(self.delay);
+
+        let answer = self.answers.iter().find(|(command, _, _)| {
+            line == *command || line.starts_with(&format!("{} ", command))
+        });
+        match answer {
+            Some((_, success, stdout)) => Ok(CommandOutput {
+                success: *success,
+                stdout: stdout.clone(),
+            }),
+            None => Err(not_found(program)),
+        }
+    }
+}
+
+/// A filesystem with nothing in it
+pub struct EmptyFileSystem;
+
+impl FileSystem for EmptyFileSystem {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        Err(not_found(path.display()))
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        Err(not_found(path.display()))
+    }
+}
+
+/// In-memory files and directory listings; counts every access
+#[derive(Default)]
+pub struct FakeFileSystem {
+    dirs: Vec<(PathBuf, Vec<PathBuf>)>,
+    files: Vec<(PathBuf, Vec<u8>)>,
+    accesses: Cell<usize>,
+}
+
+impl FakeFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `path` with `content`, listed in its parent directory
+    pub fn with_file(mut self, path: &str, content: impl Into<Vec<u8>>) -> Self {
+        let path = PathBuf::from(path);
+        if let Some(parent) = path.parent() {
+            self.list(parent, &path);
+        }
+        self.files.push((path, content.into()));
+        self
+    }
+
+    /// Add a connected DRM connector, e.g. "card0-DP-1", with its `modes` file
+    pub fn with_drm_connector(mut self, name: &str, modes: &str) -> Self {
+        let dir = Path::new("/sys/class/drm").join(name);
+        self.list(Path::new("/sys/class/drm"), &dir);
+        self.files.push((dir.join("status"), b"connected\n".to_vec()));
+        self.files.push((dir.join("modes"), modes.as_bytes().to_vec()));
+        self
+    }
+
+    /// Like `with_drm_connector`, with the connector's EDID
+    pub fn with_drm_edid(mut self, name: &str, modes: &str, edid: &[u8]) -> Self {
+        self = self.with_drm_connector(name, modes);
+        self.files.push((Path::new("/sys/class/drm").join(name).join("edid"), edid.to_vec()));
+        self
+    }
+
+    /// Number of `read_dir` and `read` calls so far
+    pub fn accesses(&self) -> usize {
+        self.accesses.get()
+    }
+
+    fn list(&mut self, dir: &Path, entry: &Path) {
+        match self.dirs.iter_mut().find(|(path, _)| path == dir) {
+            Some((_, entries)) => entries.push(entry.to_path_buf()),
+            None => self.dirs.push((dir.to_path_buf(), vec![entry.to_path_buf()])),
+        }
+    }
+}
+
+impl FileSystem for FakeFileSystem {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.accesses.set(self.accesses.get() + 1);
+        self.dirs
+            .iter()
+            .find(|(dir, _)| dir == path)
+            .map(|(_, entries)| entries.clone())
+            .ok_or_else(|| not_found(path.display()))
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.accesses.set(self.accesses.get() + 1);
+        self.files
+            .iter()
+            .find(|(file, _)| file == path)
+            .map(|(_, content)| content.clone())
+            .ok_or_else(|| not_found(path.display()))
+    }
+}
+
+pub fn wayland() -> Env {
+    Env {
+        wayland_display: Some("wayland-1".to_string()),
+        ..Env::default()
+    }
+}
+
+pub fn hyprland() -> Env {
+    Env {
+        hyprland_instance_signature: Some("abc123_1700000000_123456".to_string()),
+        ..wayland()
+    }
+}
+
+pub fn sway() -> Env {
+    Env {
+        swaysock: Some("/run/user/1000/sway-ipc.sock".to_string()),
+        ..wayland()
+    }
+}
+
+pub fn x11() -> Env {
+    Env {
+        display: Some(":0".to_string()),
+        ..Env::default()
+    }
+}
+
+/// XWayland: a Wayland session that also runs an X server
+pub fn xwayland() -> Env {
+    Env {
+        display: Some(":0".to_string()),
+        ..wayland()
+    }
+}
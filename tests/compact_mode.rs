@@ -0,0 +1,60 @@
+use std::sync::{Arc, Mutex};
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::search;
+
+fn compact_app(picked: Arc<Mutex<Option<String>>>) -> App {
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    config.tool.compact_mode = true;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), picked)
+}
+
+#[test]
+fn exact_character_beats_a_name_match() {
+    let groups = vec![vec!['ä', 'a']];
+    // 'ä' matches "a" through its name "a diaeresis", 'a' is the character itself
+    assert_eq!(search::top_match(&groups, "a", true), Some('a'));
+}
+
+#[test]
+fn case_folded_character_beats_a_name_match() {
+    let groups = vec![vec!['ä', 'à']];
+    assert_eq!(search::top_match(&groups, "À", true), Some('à'));
+    assert_eq!(search::top_match(&groups, "À", false), None);
+}
+
+#[test]
+fn equal_matches_keep_display_order() {
+    let groups = vec![vec!['è', 'é', 'ê', 'ë']];
+    assert_eq!(search::top_match(&groups, "", true), Some('è'));
+    assert_eq!(search::top_match(&groups, "c", true), Some('ê'));
+    assert_eq!(search::top_match(&groups, "z", true), None);
+}
+
+#[test]
+fn enter_inserts_the_top_match() {
+    let picked = Arc::default();
+    let mut app = compact_app(Arc::clone(&picked));
+
+    let _ = app.update(Message::FilterTyped('a'));
+    assert_eq!(app.top_match(), Some('é'));
+
+    let _ = app.update(Message::ConfirmHovered);
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("é"));
+}
+
+#[test]
+fn enter_without_a_match_inserts_nothing() {
+    let picked = Arc::default();
+    let mut app = compact_app(Arc::clone(&picked));
+
+    let _ = app.update(Message::FilterTyped('x'));
+    assert_eq!(app.top_match(), None);
+
+    let _ = app.update(Message::ConfirmHovered);
+    assert_eq!(*picked.lock().unwrap(), None);
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use quick_accent::cli::Cli;
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+
+// The only test in this binary touching the environment, so no other test races it
+#[test]
+fn env_var_overrides_default_config_path() {
+    let history = ClipboardRing::default_path();
+    unsafe { std::env::set_var("QUICK_ACCENT_CONFIG", "/tmp/test.ron") };
+    assert_eq!(AppConfig::default_config_path(), PathBuf::from("/tmp/test.ron"));
+    // The history stays put whichever config is used
+    assert_eq!(ClipboardRing::default_path(), history);
+
+    // Empty counts as unset
+    unsafe { std::env::set_var("QUICK_ACCENT_CONFIG", "") };
+    assert_ne!(AppConfig::default_config_path(), PathBuf::from(""));
+
+    unsafe { std::env::remove_var("QUICK_ACCENT_CONFIG") };
+}
+
+#[test]
+fn config_flag_is_parsed_before_the_command() {
+    let cli = Cli::parse(["--config", "/tmp/french.ron", "config", "upgrade"]).unwrap();
+    assert_eq!(cli.config_path, Some(PathBuf::from("/tmp/french.ron")));
+
+    let cli = Cli::parse(["--config=/tmp/french.ron"]).unwrap();
+    assert_eq!(cli.config_path, Some(PathBuf::from("/tmp/french.ron")));
+
+    assert!(Cli::parse(["--config"]).is_err());
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::path::PathBuf;
+
+use quick_accent::config::app::AppConfig;
+
+fn scratch_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("quick-accent-upgrade-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir.join("config.ron")
+}
+
+/// A config written by an older version: two customized values, most fields missing
+const PARTIAL_CONFIG: &str = "(
+    window: (
+        width_fraction: 0.5,
+    ),
+    tool: (
+        base_character: 'o',
+    ),
+)";
+
+#[test]
+fn missing_fields_are_added_and_saved() {
+    let path = scratch_file("partial");
+    fs::write(&path, PARTIAL_CONFIG).unwrap();
+
+    let added = AppConfig::upgrade_file(&path).unwrap();
+    assert!(added.contains(&"window.height".to_string()), "{:?}", added);
+    assert!(added.contains(&"tool.font_size".to_string()), "{:?}", added);
+    assert!(added.contains(&"screen".to_string()), "{:?}", added);
+    assert!(!added.contains(&"window.width_fraction".to_string()));
+    assert!(!added.contains(&"tool.base_character".to_string()));
+
+    // The customizations survive, the new fields hold their defaults
+    let config = AppConfig::load_from_path(&path).unwrap();
+    assert_eq!(config.window.width_fraction, 0.5);
+    assert_eq!(config.tool.base_character, 'o');
+    assert_eq!(config.window.height, AppConfig::default().window.height);
+    assert_eq!(config.tool.font_size, AppConfig::default().tool.font_size);
+
+    // The written file is complete, so a second upgrade has nothing to add
+    assert_eq!(AppConfig::upgrade_file(&path).unwrap(), Vec::<String>::new());
+    let _ = fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn complete_file_is_not_rewritten() {
+    let path = scratch_file("complete");
+    AppConfig::default().save_to_path(&path).unwrap();
+    // Comments only survive when the file is left alone
+    let content = format!("// my settings\n{}", fs::read_to_string(&path).unwrap());
+    fs::write(&path, &content).unwrap();
+
+    assert!(AppConfig::upgrade_file(&path).unwrap().is_empty());
+    assert_eq!(fs::read_to_string(&path).unwrap(), content);
+    let _ = fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn malformed_file_is_an_error_and_left_alone() {
+    let path = scratch_file("malformed");
+    fs::write(&path, "(window: (width_fraction: ").unwrap();
+
+    assert!(AppConfig::upgrade_file(&path).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "(window: (width_fraction: ");
+    let _ = fs::remove_dir_all(path.parent().unwrap());
+}
@@ -0,0 +1,44 @@
+use quick_accent::config::app::AppConfig;
+
+fn warned_fields(config: &AppConfig) -> Vec<&'static str> {
+    config
+        .validate()
+        .expect("config should be valid")
+        .iter()
+        .map(|warning| warning.field)
+        .collect()
+}
+
+#[test]
+fn default_config_has_no_warnings() {
+    assert!(warned_fields(&AppConfig::default()).is_empty());
+}
+
+#[test]
+fn suspicious_values_warn_but_stay_valid() {
+    let mut config = AppConfig::default();
+    config.window.width_fraction = 0.99;
+    config.tool.font_size = 300.0;
+    config.tool.auto_close_idle_ms = Some(100);
+    assert_eq!(
+        warned_fields(&config),
+        vec!["window.width_fraction", "tool.font_size", "tool.auto_close_idle_ms"]
+    );
+}
+
+#[test]
+fn values_at_the_limits_do_not_warn() {
+    let mut config = AppConfig::default();
+    config.window.width_fraction = 0.95;
+    config.tool.font_size = 128.0;
+    config.tool.auto_close_idle_ms = Some(500);
+    assert!(warned_fields(&config).is_empty());
+}
+
+#[test]
+fn hard_errors_win_over_warnings() {
+    let mut config = AppConfig::default();
+    config.window.width_fraction = 0.99;
+    config.tool.grid_columns = Some(0);
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use quick_accent::crash;
+
+/// Set in the child process spawned by `panic_cleans_up_and_writes_report`
+const CHILD_DIR_VAR: &str = "QUICK_ACCENT_CRASH_TEST_DIR";
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("quick-accent-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Only does something when run as the child of the test below
+#[test]
+fn panicking_child() {
+    let Ok(dir) = std::env::var(CHILD_DIR_VAR) else {
+        return;
+    };
+    let dir = PathBuf::from(dir);
+
+    crash::register_artifact(dir.join("quick-accent.sock"));
+    crash::register_artifact(dir.join("quick-accent.lock"));
+    crash::update_context(|context| context.backend = Some("Wtype".to_string()));
+    crash::install_panic_hook(dir.join("state"));
+
+    panic!("simulated crash");
+}
+
+#[test]
+fn panic_cleans_up_and_writes_report() {
+    let dir = scratch_dir("crash");
+    fs::write(dir.join("quick-accent.sock"), "").unwrap();
+    fs::write(dir.join("quick-accent.lock"), "").unwrap();
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "panicking_child", "--nocapture", "--test-threads=1"])
+        .env(CHILD_DIR_VAR, &dir)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("report:"));
+
+    assert!(!dir.join("quick-accent.sock").exists());
+    assert!(!dir.join("quick-accent.lock").exists());
+
+    let reports: Vec<PathBuf> = fs::read_dir(dir.join("state"))
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.file_name().unwrap().to_string_lossy().starts_with("crash-"))
+        .collect();
+    assert_eq!(reports.len(), 1);
+    let report = fs::read_to_string(&reports[0]).unwrap();
+    assert!(report.contains("simulated crash"));
+    assert!(report.contains("backend: Wtype"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn cleanup_is_idempotent() {
+    let dir = scratch_dir("cleanup");
+    let lock = dir.join("quick-accent.lock");
+    fs::write(&lock, "").unwrap();
+    crash::register_artifact(lock.clone());
+
+    assert!(crash::cleanup_artifacts().contains(&lock));
+    assert!(!lock.exists());
+    assert!(!crash::cleanup_artifacts().contains(&lock));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn report_includes_the_context() {
+    let context = crash::CrashContext {
+        backend: Some("Wtype".to_string()),
+        config_path: Some(PathBuf::from("/home/user/.config/quick-accent/config.ron")),
+        monitor: Some("2560x1440 at (0, 0)".to_string()),
+        session: Some("Wayland (Hyprland)".to_string()),
+        detection: vec!["Hyprland focused: no focused monitor".to_string(), "wlr-randr: 2560x1440".to_string()],
+    };
+    let report = crash::format_report("panicked at src/app.rs:1:1:\nunexpected output", &context, &[]);
+
+    assert!(report.contains("unexpected output"));
+    assert!(report.contains("session: Wayland (Hyprland)"));
+    assert!(report.contains("backend: Wtype"));
+    assert!(report.contains("config.ron"));
+    assert!(report.contains("monitor: 2560x1440 at (0, 0)"));
+    assert!(report.contains("  Hyprland focused: no focused monitor\n  wlr-randr: 2560x1440"));
+}
+
+#[test]
+fn report_marks_missing_context_unknown() {
+    let report = crash::format_report("boom", &crash::CrashContext::default(), &[]);
+    assert!(report.contains("session: unknown"));
+    assert!(report.contains("backend: unknown"));
+    assert!(!report.contains("screen detection:"));
+}
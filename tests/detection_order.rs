@@ -0,0 +1,218 @@
+mod common;
+
+use common::{EmptyFileSystem, FixtureRunner, hyprland, sway, x11};
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::env::Env;
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::backend::DetectionBackend;
+use quick_accent::screen::fixtures;
+
+/// Answers `hyprctl` and `xrandr` from fixtures and records every command line
+fn logging_runner() -> FixtureRunner {
+    FixtureRunner::new()
+        .answer("hyprctl", fixtures::HYPRCTL_MONITORS)
+        .answer("xrandr", fixtures::XRANDR_CURRENT)
+}
+
+fn config(prefer_focused_screen: bool) -> ScreenConfig {
+    ScreenConfig {
+        prefer_focused_screen,
+        ..ScreenConfig::default()
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn focused_backends_come_first_by_default() {
+    assert_eq!(
+        ScreenInfo::applicable_backends(&config(true), &hyprland()),
+        vec![
+            DetectionBackend::HyprlandActiveWindow,
+            DetectionBackend::HyprlandFocused,
+            DetectionBackend::WlrRandr,
+            DetectionBackend::HyprlandMonitors,
+            DetectionBackend::Sysfs,
+        ]
+    );
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn primary_backends_come_first_without_focus_preference() {
+    assert_eq!(
+        ScreenInfo::applicable_backends(&config(false), &hyprland()),
+        vec![
+            DetectionBackend::WlrRandr,
+            DetectionBackend::HyprlandMonitors,
+            DetectionBackend::HyprlandActiveWindow,
+            DetectionBackend::HyprlandFocused,
+            DetectionBackend::Sysfs,
+        ]
+    );
+}
+
+#[test]
+fn sysfs_is_linux_only() {
+    for os in ["freebsd", "openbsd", "netbsd", "dragonfly"] {
+        assert!(!DetectionBackend::Sysfs.supported_on(os), "{}", os);
+        assert!(DetectionBackend::WlrRandr.supported_on(os), "{}", os);
+        assert!(DetectionBackend::X11.supported_on(os), "{}", os);
+    }
+    assert!(DetectionBackend::Sysfs.supported_on("linux"));
+}
+
+#[test]
+fn sysfs_is_listed_only_on_linux() {
+    // Even an unidentified session, which tries everything else
+    let backends = ScreenInfo::applicable_backends(&config(true), &Env::default());
+    assert_eq!(backends.contains(&DetectionBackend::Sysfs), cfg!(target_os = "linux"));
+    assert!(backends.contains(&DetectionBackend::WlrRandr));
+}
+
+#[test]
+fn sway_commands_flip_order() {
+    let runner = logging_runner();
+    let _ = ScreenInfo::detect_with(&config(true), &sway(), &runner, &EmptyFileSystem);
+    assert_eq!(
+        runner.calls(),
+        vec!["swaymsg -t get_workspaces", "wlr-randr", "swaymsg -t get_outputs"]
+    );
+
+    let runner = logging_runner();
+    let _ = ScreenInfo::detect_with(&config(false), &sway(), &runner, &EmptyFileSystem);
+    assert_eq!(
+        runner.calls(),
+        vec!["wlr-randr", "swaymsg -t get_outputs", "swaymsg -t get_workspaces"]
+    );
+}
+
+#[test]
+fn hyprland_picks_the_focused_monitor() {
+    // The fixture's first monitor is 2560x1440, the focused one is a rotated 1440x2560
+    let screen = ScreenInfo::detect_with(&config(true), &hyprland(), &logging_runner(), &EmptyFileSystem);
+    assert_eq!((screen.width, screen.height), (1440.0, 2560.0));
+
+    // Hyprland marks no monitor primary, so focus decides even without the preference
+    let runner = logging_runner();
+    let screen = ScreenInfo::detect_with(&config(false), &hyprland(), &runner, &EmptyFileSystem);
+    assert_eq!(runner.calls()[..2], ["wlr-randr", "hyprctl monitors -j"]);
+    assert_eq!((screen.width, screen.height), (1440.0, 2560.0));
+}
+
+/// Hyprland whose active window is on the monitor not marked focused
+fn active_window_runner() -> FixtureRunner {
+    FixtureRunner::new()
+        .answer("hyprctl activewindow", fixtures::HYPRCTL_ACTIVEWINDOW)
+        .answer("hyprctl monitors", fixtures::HYPRCTL_MONITORS)
+}
+
+#[test]
+fn hyprland_prefers_the_active_window_monitor() {
+    let screen = ScreenInfo::detect_with(&config(true), &hyprland(), &active_window_runner(), &EmptyFileSystem);
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+}
+
+#[test]
+fn x11_skips_the_mouse_without_focus_preference() {
+    let runner = logging_runner();
+    let _ = ScreenInfo::detect_with(&config(true), &x11(), &runner, &EmptyFileSystem);
+    assert_eq!(runner.calls()[0], "xdotool getmouselocation --shell");
+
+    let runner = logging_runner();
+    let screen = ScreenInfo::detect_with(&config(false), &x11(), &runner, &EmptyFileSystem);
+    assert_eq!(runner.calls(), vec!["xrandr --current"]);
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+}
+
+/// Every backend but sysfs, whose applicability only depends on the OS
+fn applicable_without_sysfs(env: &Env) -> Vec<DetectionBackend> {
+    DetectionBackend::ALL
+        .into_iter()
+        .filter(|&backend| backend != DetectionBackend::Sysfs && backend.applicable(env))
+        .collect()
+}
+
+fn desktop(env: Env, desktop: &str) -> Env {
+    Env {
+        current_desktop: Some(desktop.to_string()),
+        ..env
+    }
+}
+
+#[test]
+fn applicable_backends_per_session() {
+    use DetectionBackend::*;
+
+    let session_type = |session: &str| Env {
+        session_type: Some(session.to_string()),
+        ..Env::default()
+    };
+    let matrix = [
+        ("x11", x11(), vec![X11]),
+        ("XDG_SESSION_TYPE=x11 only", session_type("x11"), vec![X11]),
+        ("KDE on X11", desktop(x11(), "KDE"), vec![X11, Kscreen]),
+        ("unknown Wayland compositor", common::wayland(), vec![WlrRandr]),
+        ("XDG_SESSION_TYPE=wayland only", session_type("wayland"), vec![WlrRandr]),
+        ("Hyprland", hyprland(), vec![HyprlandActiveWindow, HyprlandFocused, WlrRandr, HyprlandMonitors]),
+        (
+            "Hyprland by desktop name",
+            desktop(common::wayland(), "Hyprland"),
+            vec![HyprlandActiveWindow, HyprlandFocused, WlrRandr, HyprlandMonitors],
+        ),
+        ("sway", sway(), vec![SwayFocused, WlrRandr, SwayOutputs]),
+        (
+            "sway by desktop list",
+            desktop(common::wayland(), "sway:wlroots"),
+            vec![SwayFocused, WlrRandr, SwayOutputs],
+        ),
+        ("KDE on Wayland", desktop(common::wayland(), "KDE"), vec![WlrRandr, Kscreen]),
+        ("GNOME on Wayland", desktop(common::wayland(), "GNOME"), vec![WlrRandr]),
+        ("XWayland", common::xwayland(), vec![WlrRandr, X11]),
+        (
+            "sway with XWayland",
+            Env {
+                display: Some(":0".to_string()),
+                ..sway()
+            },
+            vec![SwayFocused, WlrRandr, SwayOutputs, X11],
+        ),
+        ("KDE on Wayland with XWayland", desktop(common::xwayland(), "KDE"), vec![WlrRandr, X11, Kscreen]),
+        // Nothing identifies the display server, so everything is worth a try
+        (
+            "stripped environment",
+            Env::default(),
+            DetectionBackend::ALL
+                .into_iter()
+                .filter(|&backend| backend != Sysfs)
+                .collect(),
+        ),
+        (
+            "Hyprland signature without a display",
+            Env {
+                hyprland_instance_signature: Some("abc".to_string()),
+                ..Env::default()
+            },
+            DetectionBackend::ALL
+                .into_iter()
+                .filter(|&backend| backend != Sysfs)
+                .collect(),
+        ),
+    ];
+
+    for (name, env, expected) in matrix {
+        assert_eq!(applicable_without_sysfs(&env), expected, "{}", name);
+    }
+}
+
+#[test]
+fn xwayland_needs_x11_fallback() {
+    let config = ScreenConfig {
+        allow_x11_fallback: false,
+        ..ScreenConfig::default()
+    };
+    assert!(!ScreenInfo::applicable_backends(&config, &common::xwayland()).contains(&DetectionBackend::X11));
+    assert!(ScreenInfo::applicable_backends(&ScreenConfig::default(), &common::xwayland())
+        .contains(&DetectionBackend::X11));
+    // Plain X11 doesn't depend on the fallback setting
+    assert!(ScreenInfo::applicable_backends(&config, &x11()).contains(&DetectionBackend::X11));
+}
@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+
+fn app(double_click: bool, timeout_ms: u64, picked: Arc<Mutex<Option<String>>>) -> App {
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    config.tool.double_click_to_select = double_click;
+    config.tool.double_click_timeout_ms = timeout_ms;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), picked)
+}
+
+fn picked_text(picked: &Arc<Mutex<Option<String>>>) -> Option<String> {
+    picked.lock().unwrap().clone()
+}
+
+#[test]
+fn single_click_selects_by_default() {
+    let picked = Arc::default();
+    let mut app = app(false, 400, Arc::clone(&picked));
+    let _ = app.update(Message::VariantClicked(1));
+    assert_eq!(picked_text(&picked).as_deref(), Some("é"));
+}
+
+#[test]
+fn first_click_only_marks_the_variant() {
+    let picked = Arc::default();
+    let mut app = app(true, 400, Arc::clone(&picked));
+    let _ = app.update(Message::VariantClicked(1));
+    assert_eq!(app.pending_selection(), Some(1));
+    assert_eq!(picked_text(&picked), None);
+}
+
+#[test]
+fn second_click_selects() {
+    let picked = Arc::default();
+    let mut app = app(true, 60_000, Arc::clone(&picked));
+    let _ = app.update(Message::VariantClicked(1));
+    let _ = app.update(Message::VariantClicked(1));
+    assert_eq!(picked_text(&picked).as_deref(), Some("é"));
+}
+
+#[test]
+fn clicking_another_variant_moves_the_mark() {
+    let picked = Arc::default();
+    let mut app = app(true, 60_000, Arc::clone(&picked));
+    let _ = app.update(Message::VariantClicked(1));
+    let _ = app.update(Message::VariantClicked(0));
+    assert_eq!(app.pending_selection(), Some(0));
+    assert_eq!(picked_text(&picked), None);
+}
+
+#[test]
+fn expiry_clears_the_mark() {
+    let picked = Arc::default();
+    let mut app = app(true, 60_000, Arc::clone(&picked));
+    let _ = app.update(Message::VariantClicked(1));
+    let _ = app.update(Message::PendingSelectionExpired(Instant::now() + Duration::from_secs(61)));
+    assert_eq!(app.pending_selection(), None);
+
+    // The next click starts over
+    let _ = app.update(Message::VariantClicked(1));
+    assert_eq!(app.pending_selection(), Some(1));
+    assert_eq!(picked_text(&picked), None);
+}
+
+#[test]
+fn late_second_click_starts_over() {
+    let picked = Arc::default();
+    let mut app = app(true, 1, Arc::clone(&picked));
+    let _ = app.update(Message::VariantClicked(1));
+    std::thread::sleep(Duration::from_millis(5));
+    let _ = app.update(Message::VariantClicked(1));
+    assert_eq!(app.pending_selection(), Some(1));
+    assert_eq!(picked_text(&picked), None);
+}
+
+#[test]
+fn early_expiry_check_keeps_the_mark() {
+    let picked = Arc::default();
+    let mut app = app(true, 60_000, Arc::clone(&picked));
+    let _ = app.update(Message::VariantClicked(1));
+    let _ = app.update(Message::PendingSelectionExpired(Instant::now()));
+    assert_eq!(app.pending_selection(), Some(1));
+
+    let _ = app.update(Message::VariantClicked(1));
+    assert_eq!(picked_text(&picked).as_deref(), Some("é"));
+}
+
+#[test]
+fn zero_timeout_is_rejected() {
+    let mut config = AppConfig::default();
+    config.tool.double_click_timeout_ms = 0;
+    assert!(config.validate().is_err());
+}
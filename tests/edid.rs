@@ -0,0 +1,110 @@
+mod common;
+
+use common::{FakeFileSystem, FixtureRunner, wayland};
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::edid::parse_edid;
+use quick_accent::screen::fixtures;
+
+/// The fixture with bytes replaced and the checksum fixed up again
+fn patched(changes: &[(usize, u8)]) -> Vec<u8> {
+    let mut bytes = fixtures::EDID_DELL_U2722D.to_vec();
+    for &(offset, value) in changes {
+        bytes[127] = bytes[127].wrapping_add(bytes[offset]).wrapping_sub(value);
+        bytes[offset] = value;
+    }
+    bytes
+}
+
+#[test]
+fn parses_identification_and_name() {
+    let info = parse_edid(&fixtures::EDID_DELL_U2722D).unwrap();
+    assert_eq!(info.manufacturer, "DEL");
+    assert_eq!(info.product_code, 0x4199);
+    assert_eq!(info.monitor_name.as_deref(), Some("DELL U2722D"));
+    assert_eq!(info.display_name(), "DELL U2722D");
+}
+
+#[test]
+fn physical_size_comes_from_the_detailed_timing() {
+    let info = parse_edid(&fixtures::EDID_DELL_U2722D).unwrap();
+    assert_eq!(info.physical_mm, Some((597.0, 336.0)));
+}
+
+#[test]
+fn physical_size_falls_back_to_centimetres() {
+    // A zero image size in the detailed timing leaves bytes 21-22
+    let bytes = patched(&[(66, 0), (67, 0), (68, 0)]);
+    assert_eq!(parse_edid(&bytes).unwrap().physical_mm, Some((600.0, 340.0)));
+}
+
+#[test]
+fn projector_has_no_physical_size() {
+    // No detailed timing and an undefined size
+    let bytes = patched(&[(54, 0), (55, 0), (21, 0), (22, 0)]);
+    assert_eq!(parse_edid(&bytes).unwrap().physical_mm, None);
+}
+
+#[test]
+fn display_name_without_name_descriptor() {
+    // Turn the name descriptor into a dummy one
+    let bytes = patched(&[(75, 0x10)]);
+    assert_eq!(parse_edid(&bytes).unwrap().display_name(), "DEL 4199");
+}
+
+#[test]
+fn extension_blocks_are_ignored() {
+    let mut bytes = fixtures::EDID_DELL_U2722D.to_vec();
+    bytes.extend([0xAB; 128]);
+    assert_eq!(parse_edid(&bytes), parse_edid(&fixtures::EDID_DELL_U2722D));
+}
+
+#[test]
+fn bad_checksum_is_rejected() {
+    let mut bytes = fixtures::EDID_DELL_U2722D;
+    bytes[127] = bytes[127].wrapping_add(1);
+    assert_eq!(parse_edid(&bytes), None);
+
+    // A changed byte without a fixed-up checksum, e.g. a flipped bit on the wire
+    let mut bytes = fixtures::EDID_DELL_U2722D;
+    bytes[80] ^= 0x01;
+    assert_eq!(parse_edid(&bytes), None);
+}
+
+#[test]
+fn short_buffer_is_rejected() {
+    assert_eq!(parse_edid(&[]), None);
+    assert_eq!(parse_edid(&fixtures::EDID_DELL_U2722D[..127]), None);
+    assert_eq!(parse_edid(&fixtures::EDID_DELL_U2722D[..8]), None);
+}
+
+#[test]
+fn wrong_header_is_rejected() {
+    let bytes = patched(&[(0, 0x01)]);
+    assert_eq!(parse_edid(&bytes), None);
+}
+
+fn detect_physical_size(enabled: bool, modes: &str) -> ScreenInfo {
+    let config = ScreenConfig {
+        detect_physical_size: enabled,
+        ..ScreenConfig::default()
+    };
+    let runner = FixtureRunner::new().answer("wlr-randr", fixtures::WLR_RANDR);
+    let fs = FakeFileSystem::new().with_drm_edid("card0-DP-1", modes, &fixtures::EDID_DELL_U2722D);
+    ScreenInfo::detect_with(&config, &wayland(), &runner, &fs)
+}
+
+#[test]
+fn detection_reads_the_physical_size_when_enabled() {
+    let screen = detect_physical_size(true, "2560x1440\n");
+    assert_eq!(screen.physical_mm, Some((597.0, 336.0)));
+    let dpi = screen.dpi().unwrap();
+    assert!((dpi - 108.9).abs() < 0.1, "{}", dpi);
+
+    assert_eq!(detect_physical_size(false, "2560x1440\n").physical_mm, None);
+}
+
+#[test]
+fn physical_size_needs_a_matching_connector() {
+    assert_eq!(detect_physical_size(true, "1920x1080\n").physical_mm, None);
+}
@@ -0,0 +1,58 @@
+use quick_accent::accents::entities;
+use quick_accent::output::OutputRepresentation;
+
+#[test]
+fn char_with_both_representations() {
+    assert_eq!(entities::html_entity('é').as_deref(), Some("&eacute;"));
+    assert_eq!(entities::latex('é').as_deref(), Some("\\'e"));
+
+    assert_eq!(OutputRepresentation::Html.render('é'), "&eacute;");
+    assert_eq!(OutputRepresentation::Latex.render('é'), "\\'e");
+    assert_eq!(OutputRepresentation::Glyph.render('é'), "é");
+}
+
+#[test]
+fn uppercase_letters_keep_their_case() {
+    assert_eq!(OutputRepresentation::Html.render('É'), "&Eacute;");
+    assert_eq!(OutputRepresentation::Latex.render('É'), "\\'E");
+    assert_eq!(OutputRepresentation::Html.render('Æ'), "&AElig;");
+    assert_eq!(OutputRepresentation::Latex.render('Œ'), "{\\OE}");
+}
+
+#[test]
+fn letter_commands_are_braced() {
+    assert_eq!(OutputRepresentation::Latex.render('ç'), "\\c{c}");
+    assert_eq!(OutputRepresentation::Latex.render('Š'), "\\v{S}");
+    assert_eq!(OutputRepresentation::Html.render('š'), "&scaron;");
+}
+
+#[test]
+fn control_words_do_not_swallow_the_next_letter() {
+    let latex: String = "Straße".chars().map(|ch| OutputRepresentation::Latex.render(ch)).collect();
+    assert_eq!(latex, "Stra{\\ss}e");
+    assert_eq!(OutputRepresentation::Latex.render('İ'), "\\.I");
+    assert_eq!(OutputRepresentation::Latex.render('&'), "\\&");
+}
+
+#[test]
+fn char_with_neither_falls_back_to_the_glyph() {
+    assert_eq!(entities::html_entity('ə'), None);
+    assert_eq!(entities::latex('ə'), None);
+
+    assert_eq!(OutputRepresentation::Html.render('ə'), "ə");
+    assert_eq!(OutputRepresentation::Latex.render('ə'), "ə");
+}
+
+#[test]
+fn every_accent_variant_has_both_representations() {
+    for base in 'a'..='z' {
+        for ch in quick_accent::accents::variants_for(base) {
+            // No LaTeX command for the ij ligature in text mode
+            if ch == 'ĳ' {
+                continue;
+            }
+            assert!(entities::html_entity(ch).is_some(), "{:?} has no HTML entity", ch);
+            assert!(entities::latex(ch).is_some(), "{:?} has no LaTeX form", ch);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use common::{FakeFileSystem, FixtureRunner};
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::env::Env;
+use quick_accent::screen::ScreenInfo;
+
+#[test]
+fn fixed_resolution_skips_all_detection() {
+    let config = ScreenConfig {
+        fixed_resolution: Some((2560.0, 1440.0)),
+        ..ScreenConfig::default()
+    };
+    let runner = FixtureRunner::new();
+    let fs = FakeFileSystem::new();
+
+    let screen = ScreenInfo::detect_with(&config, &Env::default(), &runner, &fs);
+
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+    assert_eq!(runner.calls().len(), 0);
+    assert_eq!(fs.accesses(), 0);
+}
+
+#[test]
+fn detection_without_fixed_resolution_probes() {
+    let runner = FixtureRunner::new();
+    let fs = FakeFileSystem::new();
+
+    let screen = ScreenInfo::detect_with(&ScreenConfig::default(), &Env::default(), &runner, &fs);
+
+    // Nothing answered, so the built-in default is used after trying the backends
+    assert_eq!((screen.width, screen.height), (1920.0, 1080.0));
+    assert!(runner.calls().len() > 0);
+    assert!(fs.accesses() > 0);
+}
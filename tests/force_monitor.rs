@@ -0,0 +1,114 @@
+mod common;
+
+use common::{EmptyFileSystem, FakeFileSystem, FixtureRunner, wayland, x11};
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::fixtures;
+
+/// Answers `wlr-randr` with a laptop panel first and an external monitor second
+fn two_outputs() -> FixtureRunner {
+    FixtureRunner::new().answer("wlr-randr", fixtures::WLR_RANDR_TWO_OUTPUTS)
+}
+
+#[test]
+fn forced_monitor_skips_focus() {
+    let config = ScreenConfig {
+        force_monitor: Some("HDMI-A-1".to_string()),
+        ..ScreenConfig::default()
+    };
+    let screen = ScreenInfo::detect_with(&config, &wayland(), &two_outputs(), &EmptyFileSystem);
+    // Normal detection would pick the first output, the laptop panel
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+    assert_eq!((screen.x, screen.y), (1920.0, 0.0));
+}
+
+#[test]
+fn forced_monitor_matches_display_name() {
+    let config = ScreenConfig {
+        force_monitor: Some("0x095f".to_string()),
+        ..ScreenConfig::default()
+    };
+    let screen = ScreenInfo::detect_with(&config, &wayland(), &two_outputs(), &EmptyFileSystem);
+    assert_eq!((screen.width, screen.height), (1920.0, 1080.0));
+    assert_eq!((screen.x, screen.y), (0.0, 0.0));
+}
+
+#[test]
+fn missing_forced_monitor_falls_back_to_detection() {
+    let config = ScreenConfig {
+        force_monitor: Some("DP-9".to_string()),
+        ..ScreenConfig::default()
+    };
+    let screen = ScreenInfo::detect_with(&config, &wayland(), &two_outputs(), &EmptyFileSystem);
+    assert_eq!((screen.width, screen.height), (1920.0, 1080.0));
+}
+
+fn forcing(name: &str) -> ScreenConfig {
+    ScreenConfig {
+        force_monitor: Some(name.to_string()),
+        ..ScreenConfig::default()
+    }
+}
+
+#[test]
+fn forced_monitor_uses_logical_size() {
+    let scaled = fixtures::WLR_RANDR_TWO_OUTPUTS.replace(
+        "Position: 1920,0\n  Transform: normal\n  Scale: 1.000000",
+        "Position: 1920,0\n  Transform: 90\n  Scale: 2.000000",
+    );
+    let runner = FixtureRunner::new().answer("wlr-randr", &scaled);
+    let screen = ScreenInfo::detect_with(&forcing("HDMI-A-1"), &wayland(), &runner, &EmptyFileSystem);
+    // 2560x1440 rotated, at scale 2
+    assert_eq!((screen.width, screen.height), (720.0, 1280.0));
+    assert_eq!((screen.x, screen.y), (1920.0, 0.0));
+}
+
+#[test]
+fn forced_monitor_on_x11_comes_from_xrandr() {
+    let runner = FixtureRunner::new().answer("xrandr --current", fixtures::XRANDR_CURRENT);
+    let screen = ScreenInfo::detect_with(&forcing("HDMI-1"), &x11(), &runner, &EmptyFileSystem);
+    assert_eq!((screen.width, screen.height), (1440.0, 2560.0));
+    assert_eq!((screen.x, screen.y), (2560.0, 0.0));
+}
+
+#[test]
+fn xrandr_lists_connected_outputs_with_geometry() {
+    let entries = ScreenInfo::parse_xrandr_entries(fixtures::XRANDR_CURRENT);
+    let placed: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry.connector.as_str(), entry.x, entry.y, entry.width, entry.height))
+        .collect();
+    assert_eq!(
+        placed,
+        [("DP-1", 0.0, 0.0, 2560.0, 1440.0), ("HDMI-1", 2560.0, 0.0, 1440.0, 2560.0)]
+    );
+}
+
+#[test]
+fn monitor_with_unknown_origin_is_not_forced() {
+    // Only sysfs knows the connectors, not where they are
+    let fs = FakeFileSystem::new()
+        .with_drm_connector("card0-eDP-1", "1920x1080\n")
+        .with_drm_connector("card0-HDMI-A-1", "3840x2160\n");
+    let forced = ScreenInfo::detect_with(&forcing("HDMI-A-1"), &wayland(), &FixtureRunner::new(), &fs);
+    let normal = ScreenInfo::detect_with(&ScreenConfig::default(), &wayland(), &FixtureRunner::new(), &fs);
+    assert_eq!(
+        (forced.x, forced.y, forced.width, forced.height),
+        (normal.x, normal.y, normal.width, normal.height)
+    );
+    assert_eq!(
+        ScreenInfo::list_all(&ScreenConfig::default(), &wayland(), &FixtureRunner::new(), &fs).len(),
+        2
+    );
+}
+
+#[test]
+fn forced_monitor_only_asks_the_session_tools() {
+    let runner = two_outputs();
+    let _ = ScreenInfo::detect_with(&forcing("DP-9"), &wayland(), &runner, &EmptyFileSystem);
+    assert!(!runner.programs().contains(&"xrandr".to_string()), "{:?}", runner.programs());
+
+    let runner = FixtureRunner::new().answer("xrandr --current", fixtures::XRANDR_CURRENT);
+    let _ = ScreenInfo::detect_with(&forcing("HDMI-1"), &x11(), &runner, &EmptyFileSystem);
+    assert!(!runner.programs().contains(&"wlr-randr".to_string()), "{:?}", runner.programs());
+}
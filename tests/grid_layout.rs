@@ -0,0 +1,106 @@
+use quick_accent::config::app::AppConfig;
+use quick_accent::{accents, layout};
+
+#[test]
+fn three_columns_fill_rows_first() {
+    assert_eq!(layout::grid_rows(7, 3), 3);
+    assert_eq!(layout::grid_rows(6, 3), 2);
+
+    assert_eq!(layout::grid_position(0, 3), (0, 0));
+    assert_eq!(layout::grid_position(2, 3), (0, 2));
+    assert_eq!(layout::grid_position(3, 3), (1, 0));
+    assert_eq!(layout::grid_position(8, 3), (2, 2));
+}
+
+#[test]
+fn separators_take_a_cell_between_groups() {
+    let groups = vec![vec!['é', 'è'], vec!['ê'], vec!['ë']];
+    assert_eq!(layout::cell_count(&groups, false), 4);
+    assert_eq!(layout::cell_count(&groups, true), 6);
+}
+
+#[test]
+fn three_column_grid_grows_window_to_fit_rows() {
+    let mut config = AppConfig::default();
+    config.window.height = 10.0;
+    config.tool.grid_columns = Some(3);
+
+    let cell_height = layout::cell_height(config.tool.font_size, config.tool.show_variant_names);
+    let (width, height) = config.calculate_grid_window_size(1920.0, 1080.0, 7);
+
+    assert_eq!(width, 1920.0 * config.window.width_fraction);
+    assert_eq!(height, layout::rows_height(3, cell_height, layout::CELL_SPACING));
+}
+
+#[test]
+fn grid_never_shrinks_below_configured_height() {
+    let mut config = AppConfig::default();
+    config.window.height = 1000.0;
+    config.tool.grid_columns = Some(3);
+
+    let (_, height) = config.calculate_grid_window_size(1920.0, 1080.0, 3);
+    assert_eq!(height, 1000.0);
+}
+
+#[test]
+fn without_grid_columns_size_is_unchanged() {
+    let config = AppConfig::default();
+    assert_eq!(
+        config.calculate_grid_window_size(1920.0, 1080.0, 40),
+        config.calculate_window_size(1920.0, 1080.0)
+    );
+}
+
+#[test]
+fn zero_grid_columns_is_rejected() {
+    let mut config = AppConfig::default();
+    config.tool.grid_columns = Some(0);
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn preview_adds_its_height_only_when_enabled() {
+    let mut config = AppConfig::default();
+    let (_, without_preview) = config.calculate_window_size(1920.0, 1080.0);
+    assert_eq!(without_preview, config.window.height);
+
+    config.tool.preview_character = true;
+    let (_, with_preview) = config.calculate_window_size(1920.0, 1080.0);
+    assert_eq!(with_preview, config.window.height + config.window.preview_height);
+}
+
+#[test]
+fn preview_sits_above_a_grown_grid() {
+    let mut config = AppConfig::default();
+    config.window.height = 10.0;
+    config.tool.grid_columns = Some(3);
+    config.tool.preview_character = true;
+
+    let cell_height = layout::cell_height(config.tool.font_size, config.tool.show_variant_names);
+    let (_, height) = config.calculate_grid_window_size(1920.0, 1080.0, 7);
+    assert_eq!(height, layout::rows_height(3, cell_height, layout::CELL_SPACING) + config.window.preview_height);
+}
+
+#[test]
+fn names_fit_fewer_cells_per_row() {
+    let font_size = 32.0;
+    let longest_name = accents::variants_for('e')
+        .into_iter()
+        .filter_map(accents::names::variant_name)
+        .map(|name| name.chars().count())
+        .max()
+        .unwrap();
+    // "e circumflex" and "e diaeresis" are wider than the glyph
+    assert_eq!(longest_name, 12);
+
+    let hidden = layout::cells_per_row(1000.0, layout::cell_width(font_size, 0), layout::CELL_SPACING);
+    let shown = layout::cells_per_row(1000.0, layout::cell_width(font_size, longest_name), layout::CELL_SPACING);
+    assert_eq!(hidden, 17);
+    assert_eq!(shown, 10);
+}
+
+#[test]
+fn short_names_keep_the_glyph_width() {
+    // Narrower than the glyph and its padding
+    assert_eq!(layout::cell_width(32.0, 2), layout::cell_width(32.0, 0));
+}
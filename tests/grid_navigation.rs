@@ -0,0 +1,58 @@
+use quick_accent::layout::{self, Direction};
+
+#[test]
+fn linear_index_maps_row_major() {
+    let positions: Vec<_> = (0..7).map(|index| layout::grid_position(index, 3)).collect();
+    assert_eq!(
+        positions,
+        vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2), (2, 0)]
+    );
+}
+
+#[test]
+fn single_row_when_columns_exceed_count() {
+    assert_eq!(layout::grid_position(4, 10), (0, 4));
+}
+
+#[test]
+fn left_and_right_continue_across_rows() {
+    assert_eq!(layout::navigate(2, Direction::Right, 3, 7), 3);
+    assert_eq!(layout::navigate(3, Direction::Left, 3, 7), 2);
+}
+
+#[test]
+fn left_and_right_stop_at_the_ends() {
+    assert_eq!(layout::navigate(0, Direction::Left, 3, 7), 0);
+    assert_eq!(layout::navigate(6, Direction::Right, 3, 7), 6);
+}
+
+#[test]
+fn up_and_down_keep_the_column() {
+    assert_eq!(layout::navigate(1, Direction::Down, 3, 7), 4);
+    assert_eq!(layout::navigate(4, Direction::Up, 3, 7), 1);
+}
+
+#[test]
+fn up_and_down_stop_at_the_edges() {
+    assert_eq!(layout::navigate(1, Direction::Up, 3, 7), 1);
+    assert_eq!(layout::navigate(6, Direction::Down, 3, 7), 6);
+}
+
+#[test]
+fn down_into_short_last_row_lands_on_its_last_cell() {
+    // 7 cells in 3 columns: the last row only has index 6
+    assert_eq!(layout::navigate(5, Direction::Down, 3, 7), 6);
+}
+
+#[test]
+fn separators_get_their_own_cell() {
+    let groups = vec![vec!['é', 'è'], vec!['€']];
+    assert_eq!(
+        layout::cells(&groups, true),
+        vec![Some('é'), Some('è'), None, Some('€')]
+    );
+    assert_eq!(
+        layout::cells(&groups, false),
+        vec![Some('é'), Some('è'), Some('€')]
+    );
+}
@@ -0,0 +1,41 @@
+use quick_accent::config::app::AppConfig;
+
+fn config(fraction: f32) -> AppConfig {
+    let mut config = AppConfig::default();
+    config.tool.horizontal_padding_fraction = fraction;
+    config
+}
+
+#[test]
+fn padding_scales_with_window_width() {
+    let config = config(0.05);
+    assert_eq!(config.tool.horizontal_padding(1000.0), 50.0);
+    assert_eq!(config.tool.horizontal_padding(1440.0), 72.0);
+    assert_eq!(config.tool.horizontal_padding(1920.0), 96.0);
+    assert_eq!(config.tool.horizontal_padding(0.0), 0.0);
+}
+
+#[test]
+fn zero_fraction_means_no_padding() {
+    assert_eq!(config(0.0).tool.horizontal_padding(1920.0), 0.0);
+}
+
+#[test]
+fn range_includes_zero() {
+    assert!(config(0.0).validate().is_ok());
+    assert!(config(0.25).validate().is_ok());
+    assert!(config(0.49).validate().is_ok());
+}
+
+#[test]
+fn half_and_more_is_rejected() {
+    // Padding on both sides would leave no room for the row
+    assert!(config(0.5).validate().is_err());
+    assert!(config(0.8).validate().is_err());
+}
+
+#[test]
+fn negative_fraction_is_rejected() {
+    assert!(config(-0.01).validate().is_err());
+    assert!(config(f32::NAN).validate().is_err());
+}
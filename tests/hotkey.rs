@@ -0,0 +1,80 @@
+use quick_accent::config::app::AppConfig;
+use quick_accent::hotkey::{HotkeyError, Key, Modifiers, Shortcut};
+
+fn parse(shortcut: &str) -> Result<Shortcut, HotkeyError> {
+    shortcut.parse()
+}
+
+fn modifiers(super_key: bool, ctrl: bool, alt: bool, shift: bool) -> Modifiers {
+    Modifiers {
+        super_key,
+        ctrl,
+        alt,
+        shift,
+    }
+}
+
+#[test]
+fn super_grave() {
+    let shortcut = parse("Super+Grave").unwrap();
+    assert_eq!(shortcut.modifiers, modifiers(true, false, false, false));
+    assert_eq!(shortcut.key, Key::Grave);
+}
+
+#[test]
+fn ctrl_alt_letter() {
+    let shortcut = parse("Ctrl+Alt+A").unwrap();
+    assert_eq!(shortcut.modifiers, modifiers(false, true, true, false));
+    assert_eq!(shortcut.key, Key::Letter('A'));
+}
+
+#[test]
+fn shift_digit() {
+    let shortcut = parse("Shift+1").unwrap();
+    assert_eq!(shortcut.modifiers, modifiers(false, false, false, true));
+    assert_eq!(shortcut.key, Key::Digit('1'));
+}
+
+#[test]
+fn alt_function_key() {
+    let shortcut = parse("Alt+F5").unwrap();
+    assert_eq!(shortcut.modifiers, modifiers(false, false, true, false));
+    assert_eq!(shortcut.key, Key::Function(5));
+}
+
+#[test]
+fn every_modifier_with_space() {
+    let shortcut = parse("Super+Ctrl+Alt+Shift+Space").unwrap();
+    assert_eq!(shortcut.modifiers, modifiers(true, true, true, true));
+    assert_eq!(shortcut.key, Key::Space);
+}
+
+#[test]
+fn names_are_case_insensitive_with_aliases() {
+    assert_eq!(parse("meta + `"), parse("Super+Grave"));
+    assert_eq!(parse("control+shift+e"), parse("Ctrl+Shift+E"));
+}
+
+#[test]
+fn key_without_modifiers() {
+    let shortcut = parse("F12").unwrap();
+    assert_eq!(shortcut.modifiers, Modifiers::default());
+    assert_eq!(shortcut.key, Key::Function(12));
+}
+
+#[test]
+fn invalid_shortcuts() {
+    for shortcut in ["", "Ctrl+", "Ctrl", "Ctrl+Ctrl+A", "Hyper+A", "Ctrl+F13", "Ctrl+AB", "A+Ctrl"] {
+        assert_eq!(parse(shortcut), Err(HotkeyError::InvalidFormat(shortcut.to_string())), "{}", shortcut);
+    }
+}
+
+#[test]
+fn config_rejects_an_invalid_shortcut() {
+    let mut config = AppConfig::default();
+    config.tool.keyboard_shortcut = Some("Super+Grave".to_string());
+    assert!(config.validate().is_ok());
+
+    config.tool.keyboard_shortcut = Some("Super+".to_string());
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,54 @@
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::hover;
+
+fn app() -> App {
+    let mut config = AppConfig::default();
+    config.tool.animate_character_on_hover = true;
+    App::new(
+        config,
+        Size::new(800.0, 100.0),
+        ClipboardRing::default(),
+        Default::default(),
+    )
+}
+
+#[test]
+fn scale_reaches_hover_scale_after_enough_ticks() {
+    let mut app = app();
+    assert_eq!(app.hover_scale(), 1.0);
+
+    let _ = app.update(Message::HoverEntered(0));
+    let ticks = hover::HOVER_DURATION.as_millis().div_ceil(hover::TICK.as_millis());
+    for _ in 0..ticks {
+        let _ = app.update(Message::HoverAnimationTick);
+    }
+    assert!((app.hover_scale() - hover::HOVER_SCALE).abs() < 1e-6);
+
+    // Further ticks don't overshoot
+    let _ = app.update(Message::HoverAnimationTick);
+    assert!((app.hover_scale() - hover::HOVER_SCALE).abs() < 1e-6);
+}
+
+#[test]
+fn scale_returns_to_normal_after_leaving() {
+    let mut app = app();
+    let _ = app.update(Message::HoverEntered(0));
+    for _ in 0..10 {
+        let _ = app.update(Message::HoverAnimationTick);
+    }
+
+    let _ = app.update(Message::HoverLeft);
+    for _ in 0..10 {
+        let _ = app.update(Message::HoverAnimationTick);
+    }
+    assert_eq!(app.hover_scale(), 1.0);
+}
+
+#[test]
+fn step_is_partial() {
+    let scale = hover::step_scale(1.0, hover::HOVER_SCALE);
+    assert!(scale > 1.0 && scale < hover::HOVER_SCALE);
+}
@@ -0,0 +1,39 @@
+use std::time::{Duration, Instant};
+
+use quick_accent::idle::IdleTimer;
+
+const TIMEOUT: Duration = Duration::from_millis(5000);
+
+#[test]
+fn expires_after_the_timeout() {
+    let start = Instant::now();
+    let timer = IdleTimer::new(TIMEOUT, start);
+    assert!(!timer.is_expired(start));
+    assert!(!timer.is_expired(start + TIMEOUT - Duration::from_millis(1)));
+    assert!(timer.is_expired(start + TIMEOUT));
+    assert!(timer.is_expired(start + TIMEOUT * 2));
+}
+
+#[test]
+fn input_pushes_the_deadline_out() {
+    let start = Instant::now();
+    let mut timer = IdleTimer::new(TIMEOUT, start);
+
+    let input = start + Duration::from_millis(4000);
+    timer.reset(input);
+    assert!(!timer.is_expired(start + TIMEOUT));
+    assert!(!timer.is_expired(input + TIMEOUT - Duration::from_millis(1)));
+    assert!(timer.is_expired(input + TIMEOUT));
+}
+
+#[test]
+fn reset_after_expiry_rearms() {
+    let start = Instant::now();
+    let mut timer = IdleTimer::new(TIMEOUT, start);
+    let late = start + TIMEOUT * 3;
+    assert!(timer.is_expired(late));
+
+    timer.reset(late);
+    assert!(!timer.is_expired(late));
+    assert!(timer.is_expired(late + TIMEOUT));
+}
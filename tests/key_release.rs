@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::config::tool::{WindowBehavior, WindowState};
+
+fn app(dismiss_on_key_release: bool, behavior: WindowBehavior) -> App {
+    let mut config = AppConfig::default();
+    config.tool.dismiss_on_key_release = dismiss_on_key_release;
+    config.tool.window_behavior = behavior;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default())
+}
+
+#[test]
+fn releases_are_ignored_by_default() {
+    let mut app = app(false, WindowBehavior::CloseAndReopenEachTime);
+    for _ in 0..3 {
+        let _ = app.update(Message::KeyReleased);
+    }
+    assert_eq!(app.window_state(), WindowState::Shown);
+}
+
+#[test]
+fn release_of_the_trigger_key_keeps_the_popup() {
+    let mut app = app(true, WindowBehavior::CloseAndReopenEachTime);
+    let _ = app.update(Message::KeyReleased);
+    assert_eq!(app.window_state(), WindowState::Shown);
+}
+
+#[test]
+fn next_release_closes_the_popup() {
+    let mut app = app(true, WindowBehavior::CloseAndReopenEachTime);
+    let _ = app.update(Message::KeyReleased);
+    let _ = app.update(Message::KeyReleased);
+    assert_eq!(app.window_state(), WindowState::Closed);
+}
+
+#[test]
+fn next_release_hides_a_kept_window() {
+    let mut app = app(true, WindowBehavior::HideAndShow);
+    let _ = app.update(Message::KeyReleased);
+    let _ = app.update(Message::KeyReleased);
+    assert_eq!(app.window_state(), WindowState::Hidden);
+}
+
+#[test]
+fn trigger_waits_for_its_key_release_again() {
+    let mut app = app(true, WindowBehavior::HideAndShow);
+    let _ = app.update(Message::KeyReleased);
+    let _ = app.update(Message::KeyReleased);
+
+    let _ = app.update(Message::TriggerForKey('a'));
+    let _ = app.update(Message::KeyReleased);
+    assert_eq!(app.window_state(), WindowState::Shown);
+    let _ = app.update(Message::KeyReleased);
+    assert_eq!(app.window_state(), WindowState::Hidden);
+}
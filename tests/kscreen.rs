@@ -0,0 +1,48 @@
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::fixtures::{KSCREEN_CONFIG, KSCREEN_CONFIG_LAPTOP};
+
+#[test]
+fn skips_disabled_output_and_applies_scale() {
+    let screen = ScreenInfo::parse_kscreen_config(KSCREEN_CONFIG).unwrap();
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+    assert_eq!((screen.x, screen.y), (1920.0, 0.0));
+}
+
+#[test]
+fn no_enabled_output() {
+    let config = KSCREEN_CONFIG.replace("\"enabled\": true", "\"enabled\": false");
+    assert!(ScreenInfo::parse_kscreen_config(&config).is_none());
+}
+
+#[test]
+fn rotated_output_swaps_width_and_height() {
+    let config = KSCREEN_CONFIG.replace("\"rotation\": 1,\n        \"scale\": 1.5", "\"rotation\": 2,\n        \"scale\": 1.5");
+    let screen = ScreenInfo::parse_kscreen_config(&config).unwrap();
+    assert_eq!((screen.width, screen.height), (1440.0, 2560.0));
+}
+
+#[test]
+fn lists_every_output() {
+    assert_eq!(ScreenInfo::parse_kscreen_outputs(KSCREEN_CONFIG), ["eDP-1", "HDMI-A-1"]);
+}
+
+#[test]
+fn picks_the_config_for_the_connected_outputs() {
+    let configs = [KSCREEN_CONFIG_LAPTOP.to_string(), KSCREEN_CONFIG.to_string()];
+
+    let docked = ["HDMI-A-1".to_string(), "eDP-1".to_string()];
+    let screen = ScreenInfo::select_kscreen_config(&configs, &docked).unwrap();
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+
+    let screen = ScreenInfo::select_kscreen_config(&configs, &["eDP-1".to_string()]).unwrap();
+    assert_eq!((screen.width, screen.height), (1920.0, 1080.0));
+}
+
+#[test]
+fn unknown_outputs_only_trust_a_single_config() {
+    let configs = [KSCREEN_CONFIG_LAPTOP.to_string(), KSCREEN_CONFIG.to_string()];
+    assert!(ScreenInfo::select_kscreen_config(&configs, &[]).is_none());
+
+    let screen = ScreenInfo::select_kscreen_config(&configs[1..], &[]).unwrap();
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+}
@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+
+use iced::Size;
+use quick_accent::accents::{self, locale};
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+
+fn app(locale_aware: bool) -> App {
+    app_with_picked(locale_aware, Arc::default())
+}
+
+fn app_with_picked(locale_aware: bool, picked: Arc<Mutex<Option<String>>>) -> App {
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    config.tool.locale_aware = locale_aware;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), picked)
+}
+
+#[test]
+fn language_of_a_locale() {
+    assert_eq!(locale::language("fr_FR.UTF-8"), Some("fr"));
+    assert_eq!(locale::language("de_DE"), Some("de"));
+    assert_eq!(locale::language("pt"), Some("pt"));
+    assert_eq!(locale::language("sr_RS@latin"), Some("sr"));
+    assert_eq!(locale::language("C.UTF-8"), None);
+    assert_eq!(locale::language("POSIX"), None);
+    assert_eq!(locale::language(""), None);
+}
+
+#[test]
+fn locales_map_to_their_variants() {
+    assert_eq!(locale::preferred_variants("de_DE.UTF-8"), ['ä', 'ö', 'ü', 'ß']);
+    assert_eq!(locale::preferred_variants("sv_SE"), ['å', 'ä', 'ö']);
+    assert_eq!(locale::preferred_variants("fr_CA.UTF-8")[0], 'é');
+    assert_eq!(locale::preferred_variants("nb_NO"), locale::preferred_variants("da_DK"));
+    assert!(locale::preferred_variants("en_US.UTF-8").is_empty());
+    assert!(locale::preferred_variants("C").is_empty());
+}
+
+#[test]
+fn boost_moves_preferred_variants_first() {
+    let mut group = accents::variants_for('e');
+    locale::boost(&mut group, locale::preferred_variants("pl_PL"));
+    assert_eq!(group, vec!['ę', 'è', 'é', 'ê', 'ë', 'ē', 'ė']);
+
+    let mut group = accents::variants_for('e');
+    locale::boost(&mut group, locale::preferred_variants("fr_FR"));
+    assert_eq!(group, vec!['é', 'è', 'ê', 'ë', 'ē', 'ė', 'ę']);
+}
+
+#[test]
+fn boost_matches_uppercase_variants() {
+    let mut group = accents::variants_for('O');
+    locale::boost(&mut group, locale::preferred_variants("da_DK"));
+    assert_eq!(group[0], 'Ø');
+}
+
+#[test]
+fn app_shows_locale_variants_first() {
+    let app = app(true).with_locale("pl_PL.UTF-8");
+    assert_eq!(app.visible_variants()[0], 'ę');
+    assert_eq!(app.visible_variants().len(), accents::variants_for('e').len());
+}
+
+#[test]
+fn boost_applies_to_a_new_base() {
+    let mut app = app(true).with_locale("de_DE.UTF-8");
+    let _ = app.update(Message::TriggerForKey('A'));
+    assert_eq!(app.visible_variants()[0], 'Ä');
+}
+
+#[test]
+fn locale_is_ignored_when_off() {
+    let app = app(false).with_locale("pl_PL.UTF-8");
+    assert_eq!(app.visible_variants(), accents::variants_for('e'));
+}
+
+#[test]
+fn locale_decides_uppercase_forms() {
+    let mut app = app(true).with_locale("tr_TR.UTF-8");
+    let _ = app.update(Message::TriggerForKey('I'));
+    assert!(app.visible_variants().contains(&'İ'));
+
+    let mut app = app(false).with_locale("tr_TR.UTF-8");
+    let _ = app.update(Message::TriggerForKey('I'));
+    assert!(!app.visible_variants().contains(&'İ'));
+}
+
+#[test]
+fn dutch_ij_is_typed_as_two_capitals() {
+    let picked = Arc::default();
+    let mut app = app_with_picked(true, Arc::clone(&picked)).with_locale("nl_NL.UTF-8");
+    let _ = app.update(Message::TriggerForKey('I'));
+    assert!(app.visible_variants().contains(&'Ĳ'));
+
+    let _ = app.update(Message::CharacterSelected('Ĳ'));
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("IJ"));
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::{FakeFileSystem, FixtureRunner};
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::env::Env;
+use quick_accent::screen::{ScreenError, ScreenInfo, fixtures};
+
+/// `wlr-randr` reporting a low-resolution mode; every other command is missing
+fn low_res_wlr_randr() -> FixtureRunner {
+    FixtureRunner::new().answer(
+        "wlr-randr",
+        "Virtual-1 \"Unknown\"\n  Modes:\n    800x600 px, 60.000000 Hz (current)\n",
+    )
+}
+
+/// One connected DRM connector with the given `modes` file
+fn drm(modes: &str) -> FakeFileSystem {
+    FakeFileSystem::new().with_drm_connector("card0-VGA-1", modes)
+}
+
+fn config(min_width: f32) -> ScreenConfig {
+    ScreenConfig {
+        min_width,
+        ..ScreenConfig::default()
+    }
+}
+
+fn size(screen: &ScreenInfo) -> (f32, f32) {
+    (screen.width, screen.height)
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn low_resolution_is_used_without_a_minimum() {
+    let fs = drm(fixtures::DRM_MODES_LOW_RES);
+    let screen = ScreenInfo::detect_with(&config(0.0), &Env::default(), &FixtureRunner::new(), &fs);
+    assert_eq!(size(&screen), (640.0, 480.0));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn low_resolution_below_the_minimum_is_rejected() {
+    let fs = drm(fixtures::DRM_MODES_LOW_RES);
+    let screen = ScreenInfo::detect_with(&config(1000.0), &Env::default(), &FixtureRunner::new(), &fs);
+    // Nothing else detected a screen, so this is the default
+    assert_eq!(size(&screen), size(&ScreenInfo::default()));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn detection_continues_past_a_rejected_backend() {
+    let wayland = common::wayland();
+    let fs = drm(fixtures::DRM_MODES);
+
+    let screen = ScreenInfo::detect_with(&config(0.0), &wayland, &low_res_wlr_randr(), &fs);
+    assert_eq!(size(&screen), (800.0, 600.0));
+
+    let screen = ScreenInfo::detect_with(&config(1000.0), &wayland, &low_res_wlr_randr(), &fs);
+    assert_eq!(size(&screen), (2560.0, 1440.0));
+}
+
+#[test]
+fn threshold_error_reports_the_size() {
+    let config = ScreenConfig {
+        min_width: 1000.0,
+        min_height: 700.0,
+        ..ScreenConfig::default()
+    };
+    assert!(ScreenInfo::new(1920.0, 1080.0).check_minimum(&config).is_ok());
+    assert!(matches!(
+        ScreenInfo::new(1920.0, 600.0).check_minimum(&config),
+        Err(ScreenError::BelowMinimumThreshold { width: 1920.0, height: 600.0 })
+    ));
+}
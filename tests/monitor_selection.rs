@@ -0,0 +1,133 @@
+mod common;
+
+use common::{EmptyFileSystem, FixtureRunner, x11};
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::screen::selection::{MonitorInfo, select_monitor};
+use quick_accent::screen::{ScreenInfo, ScreenInfoEntry, fixtures};
+
+/// Laptop panel at the origin, external monitor to its right, a third below
+fn monitors() -> Vec<MonitorInfo> {
+    [
+        ("eDP-1", 0.0, 0.0, 1920.0, 1080.0),
+        ("DP-1", 1920.0, 0.0, 2560.0, 1440.0),
+        ("HDMI-A-1", 0.0, 1080.0, 1920.0, 1080.0),
+    ]
+    .into_iter()
+    .map(|(connector, x, y, width, height)| {
+        MonitorInfo::from(ScreenInfoEntry {
+            connector: connector.to_string(),
+            name: format!("Monitor on {}", connector),
+            x,
+            y,
+            width,
+            height,
+            physical_mm: None,
+        })
+    })
+    .collect()
+}
+
+fn config(prefer_focused_screen: bool, force_monitor: Option<&str>) -> ScreenConfig {
+    ScreenConfig {
+        prefer_focused_screen,
+        force_monitor: force_monitor.map(str::to_string),
+        ..ScreenConfig::default()
+    }
+}
+
+fn selected(monitors: &[MonitorInfo], config: &ScreenConfig, cursor: Option<(i32, i32)>) -> Option<&str> {
+    select_monitor(monitors, config, cursor).map(|monitor| monitor.entry.connector.as_str())
+}
+
+#[test]
+fn no_monitors_selects_nothing() {
+    assert_eq!(selected(&[], &config(true, Some("DP-1")), Some((0, 0))), None);
+}
+
+#[test]
+fn first_monitor_without_other_hints() {
+    assert_eq!(selected(&monitors(), &config(true, None), None), Some("eDP-1"));
+}
+
+#[test]
+fn cursor_beats_first() {
+    assert_eq!(selected(&monitors(), &config(true, None), Some((100, 1500))), Some("HDMI-A-1"));
+    // Right and bottom edges belong to the next monitor
+    assert_eq!(selected(&monitors(), &config(true, None), Some((1920, 0))), Some("DP-1"));
+}
+
+#[test]
+fn cursor_off_every_monitor_falls_back_to_first() {
+    assert_eq!(selected(&monitors(), &config(true, None), Some((-5, -5))), Some("eDP-1"));
+}
+
+#[test]
+fn primary_beats_cursor() {
+    let mut monitors = monitors();
+    monitors[1].primary = true;
+    assert_eq!(selected(&monitors, &config(true, None), Some((100, 1500))), Some("DP-1"));
+}
+
+#[test]
+fn focused_beats_primary_by_default() {
+    let mut monitors = monitors();
+    monitors[1].primary = true;
+    monitors[2].focused = true;
+    assert_eq!(selected(&monitors, &config(true, None), Some((0, 0))), Some("HDMI-A-1"));
+}
+
+#[test]
+fn primary_beats_focused_without_focus_preference() {
+    let mut monitors = monitors();
+    monitors[1].primary = true;
+    monitors[2].focused = true;
+    assert_eq!(selected(&monitors, &config(false, None), None), Some("DP-1"));
+
+    // Focus still wins over the cursor when nothing is primary
+    monitors[1].primary = false;
+    assert_eq!(selected(&monitors, &config(false, None), Some((0, 0))), Some("HDMI-A-1"));
+}
+
+#[test]
+fn forced_monitor_beats_everything() {
+    let mut monitors = monitors();
+    monitors[1].primary = true;
+    monitors[2].focused = true;
+    for prefer_focused_screen in [true, false] {
+        let config = config(prefer_focused_screen, Some("edp-1"));
+        assert_eq!(selected(&monitors, &config, Some((2000, 10))), Some("eDP-1"));
+    }
+}
+
+#[test]
+fn forced_monitor_matches_display_name() {
+    assert_eq!(
+        selected(&monitors(), &config(true, Some("Monitor on DP-1")), None),
+        Some("DP-1")
+    );
+}
+
+#[test]
+fn missing_forced_monitor_uses_the_normal_policy() {
+    let mut monitors = monitors();
+    monitors[2].focused = true;
+    assert_eq!(selected(&monitors, &config(true, Some("DP-9")), None), Some("HDMI-A-1"));
+
+    monitors[2].focused = false;
+    assert_eq!(selected(&monitors, &config(true, Some("DP-9")), Some((2000, 10))), Some("DP-1"));
+}
+
+#[test]
+fn x11_detection_selects_the_monitor_under_the_pointer() {
+    // The pointer is on the portrait HDMI-1, DP-1 is primary
+    let runner = FixtureRunner::new()
+        .answer("xdotool getmouselocation", "X=3000\nY=100\nSCREEN=0\nWINDOW=123\n")
+        .answer("xrandr --current", fixtures::XRANDR_CURRENT);
+
+    let screen = ScreenInfo::detect_with(&config(true, None), &x11(), &runner, &EmptyFileSystem);
+    assert_eq!((screen.x, screen.width, screen.height), (2560.0, 1440.0, 2560.0));
+    assert_eq!((screen.cursor_x, screen.cursor_y), (Some(3000.0), Some(100.0)));
+
+    let screen = ScreenInfo::detect_with(&config(false, None), &x11(), &runner, &EmptyFileSystem);
+    assert_eq!((screen.x, screen.width, screen.height), (0.0, 2560.0, 1440.0));
+}
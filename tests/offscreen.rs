@@ -0,0 +1,72 @@
+use quick_accent::config::app::AppConfig;
+use quick_accent::geometry::Rect;
+
+/// A 1440p monitor to the right of a 1080p one
+const MONITOR: Rect = Rect {
+    x: 1920.0,
+    y: 0.0,
+    width: 2560.0,
+    height: 1440.0,
+};
+
+#[test]
+fn clamped_into_moves_without_resizing() {
+    let window = Rect::new(4000.0, 1400.0, 800.0, 100.0);
+    let clamped = window.clamped_into(&MONITOR);
+    assert_eq!(clamped, Rect::new(3680.0, 1340.0, 800.0, 100.0));
+    assert_eq!(clamped.visible_fraction_in(&MONITOR), 1.0);
+}
+
+#[test]
+fn clamped_into_pulls_in_from_the_left_and_top() {
+    let window = Rect::new(1000.0, -50.0, 800.0, 100.0);
+    assert_eq!(window.clamped_into(&MONITOR), Rect::new(1920.0, 0.0, 800.0, 100.0));
+}
+
+#[test]
+fn clamped_into_leaves_a_visible_window() {
+    let window = Rect::new(2000.0, 500.0, 800.0, 100.0);
+    assert_eq!(window.clamped_into(&MONITOR), window);
+}
+
+#[test]
+fn oversized_window_is_pinned_to_the_top_left() {
+    let window = Rect::new(1500.0, 300.0, 3000.0, 2000.0);
+    assert_eq!(window.clamped_into(&MONITOR), Rect::new(1920.0, 0.0, 3000.0, 2000.0));
+}
+
+#[test]
+fn ensure_visible_moves_an_offscreen_window() {
+    let config = AppConfig::default();
+    // Entirely on the monitor to the left
+    let window = Rect::new(100.0, 100.0, 800.0, 100.0);
+    assert_eq!(config.ensure_visible(window, MONITOR), Rect::new(1920.0, 100.0, 800.0, 100.0));
+}
+
+#[test]
+fn ensure_visible_keeps_a_window_above_the_threshold() {
+    let mut config = AppConfig::default();
+    config.window.min_visible_fraction = 0.5;
+    // Three quarters on the monitor
+    let window = Rect::new(4280.0, 100.0, 800.0, 100.0);
+    assert_eq!(config.ensure_visible(window, MONITOR), window);
+
+    // One quarter on it
+    let window = Rect::new(4280.0 + 400.0, 100.0, 800.0, 100.0);
+    assert_eq!(config.ensure_visible(window, MONITOR), Rect::new(3680.0, 100.0, 800.0, 100.0));
+}
+
+#[test]
+fn ensure_visible_pins_an_oversized_window() {
+    let config = AppConfig::default();
+    let window = Rect::new(1800.0, -20.0, 3000.0, 100.0);
+    assert_eq!(config.ensure_visible(window, MONITOR), Rect::new(1920.0, 0.0, 3000.0, 100.0));
+}
+
+#[test]
+fn allow_offscreen_skips_the_clamp() {
+    let mut config = AppConfig::default();
+    config.window.allow_offscreen = true;
+    let window = Rect::new(100.0, -500.0, 800.0, 100.0);
+    assert_eq!(config.ensure_visible(window, MONITOR), window);
+}
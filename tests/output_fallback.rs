@@ -0,0 +1,150 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::FixtureRunner;
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::cli::EXIT_INJECTION_FAILED;
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::config::tool::{WindowBehavior, WindowState};
+use quick_accent::output::{Delivery, OutputBackend};
+
+/// Fails every output program in `failing`, succeeds for the others
+fn failing(failing: &[&str]) -> FixtureRunner {
+    ["wtype", "wl-copy", "xdotool", "xclip"]
+        .into_iter()
+        .fold(FixtureRunner::new(), |runner, program| {
+            if failing.contains(&program) {
+                runner.fail(program)
+            } else {
+                runner.answer(program, "")
+            }
+        })
+}
+
+#[test]
+fn typing_works_without_fallback() {
+    let runner = failing(&[]);
+    let delivery = OutputBackend::Wtype.deliver("é", &runner);
+
+    assert_eq!(delivery, Delivery::Emitted(OutputBackend::Wtype));
+    assert_eq!(delivery.exit_code(), 0);
+    assert_eq!(delivery.banner("é"), None);
+    assert_eq!(runner.programs(), vec!["wtype"]);
+}
+
+#[test]
+fn failed_typing_falls_back_to_clipboard() {
+    let runner = failing(&["wtype"]);
+    let delivery = OutputBackend::Wtype.deliver("é", &runner);
+
+    assert!(matches!(
+        delivery,
+        Delivery::FellBack {
+            failed: OutputBackend::Wtype,
+            used: OutputBackend::WlCopy,
+            ..
+        }
+    ));
+    assert_eq!(delivery.exit_code(), EXIT_INJECTION_FAILED);
+    assert!(delivery.banner("é").unwrap().contains("copied é to the clipboard"));
+    assert_eq!(runner.programs(), vec!["wtype", "wl-copy"]);
+}
+
+#[test]
+fn x11_falls_back_to_xclip() {
+    let runner = failing(&["xdotool"]);
+    let delivery = OutputBackend::Xdotool.deliver("ñ", &runner);
+
+    assert!(matches!(delivery, Delivery::FellBack { used: OutputBackend::Xclip, .. }));
+}
+
+#[test]
+fn failed_fallback_still_reports_injection_failure() {
+    let runner = failing(&["wtype", "wl-copy"]);
+    let delivery = OutputBackend::Wtype.deliver("é", &runner);
+
+    assert!(matches!(delivery, Delivery::Failed { backend: OutputBackend::Wtype, .. }));
+    assert_eq!(delivery.exit_code(), EXIT_INJECTION_FAILED);
+    assert!(delivery.banner("é").is_some());
+}
+
+#[test]
+fn banner_window_waits_for_a_keypress() {
+    let app = App::new(
+        AppConfig::default(),
+        Size::new(800.0, 100.0),
+        ClipboardRing::default(),
+        Default::default(),
+    );
+    assert!(!app.is_pending_close());
+
+    let app = app.with_banner("Couldn't type into the focused app".to_string());
+    assert!(app.is_pending_close());
+}
+
+/// App delivering with wtype through `runner`, recording outcomes in the returned handle
+fn delivering_app(runner: FixtureRunner) -> (App, Arc<Mutex<Option<(String, Delivery)>>>) {
+    delivering_app_with(AppConfig::default(), runner)
+}
+
+fn delivering_app_with(
+    config: AppConfig,
+    runner: FixtureRunner,
+) -> (App, Arc<Mutex<Option<(String, Delivery)>>>) {
+    let delivered = Arc::default();
+    let app = App::new(
+        config,
+        Size::new(800.0, 100.0),
+        ClipboardRing::default(),
+        Arc::default(),
+    )
+    .with_delivery(OutputBackend::Wtype, Arc::clone(&delivered))
+    .with_runner(runner);
+    (app, delivered)
+}
+
+#[test]
+fn delivered_pick_closes_the_window() {
+    let (mut app, delivered) = delivering_app(failing(&[]));
+    let _ = app.update(Message::CharacterSelected('é'));
+    let _ = app.update(Message::DeliverPicked);
+
+    assert!(!app.is_pending_close());
+    assert_eq!(app.window_state(), WindowState::Closed);
+    let (text, delivery) = delivered.lock().unwrap().clone().unwrap();
+    assert_eq!(text, "é");
+    assert_eq!(delivery.exit_code(), 0);
+}
+
+#[test]
+fn failed_typing_keeps_the_window_open_with_a_banner() {
+    let (mut app, delivered) = delivering_app(failing(&["wtype"]));
+    let _ = app.update(Message::CharacterSelected('é'));
+    let _ = app.update(Message::DeliverPicked);
+
+    assert!(app.is_pending_close());
+    assert_eq!(app.window_state(), WindowState::Shown);
+    let (_, delivery) = delivered.lock().unwrap().clone().unwrap();
+    assert!(matches!(delivery, Delivery::FellBack { .. }));
+    assert_eq!(delivery.exit_code(), EXIT_INJECTION_FAILED);
+
+    // The next keypress closes it
+    let _ = app.update(Message::Dismissed);
+    assert_eq!(app.window_state(), WindowState::Closed);
+}
+
+#[test]
+fn failed_typing_brings_a_hidden_window_back_with_a_banner() {
+    let mut config = AppConfig::default();
+    config.tool.window_behavior = WindowBehavior::HideAndShow;
+    let (mut app, _) = delivering_app_with(config, failing(&["wtype"]));
+    let _ = app.update(Message::CharacterSelected('é'));
+    assert_eq!(app.window_state(), WindowState::Hidden);
+    let _ = app.update(Message::DeliverPicked);
+
+    assert!(app.is_pending_close());
+    assert_eq!(app.window_state(), WindowState::Shown);
+}
@@ -0,0 +1,65 @@
+use std::sync::{Arc, Mutex};
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+
+fn app(passthrough: bool, compact: bool, picked: Arc<Mutex<Option<String>>>) -> App {
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    config.tool.search_on_type = true;
+    config.tool.compact_mode = compact;
+    config.tool.passthrough_on_no_match = passthrough;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), picked)
+}
+
+fn type_and_confirm(app: &mut App, text: &str) {
+    for ch in text.chars() {
+        let _ = app.update(Message::FilterTyped(ch));
+    }
+    assert!(app.visible_variants().is_empty());
+    let _ = app.update(Message::ConfirmHovered);
+}
+
+#[test]
+fn no_match_outputs_the_query() {
+    let picked = Arc::default();
+    let mut app = app(true, false, Arc::clone(&picked));
+    type_and_confirm(&mut app, "xyz");
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("xyz"));
+}
+
+#[test]
+fn no_match_outputs_the_query_in_compact_mode() {
+    let picked = Arc::default();
+    let mut app = app(true, true, Arc::clone(&picked));
+    type_and_confirm(&mut app, "xyz");
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("xyz"));
+}
+
+#[test]
+fn no_match_does_nothing_by_default() {
+    let picked = Arc::default();
+    let mut app = app(false, false, Arc::clone(&picked));
+    type_and_confirm(&mut app, "xyz");
+    assert_eq!(*picked.lock().unwrap(), None);
+    assert_eq!(app.filter_text(), "xyz");
+}
+
+#[test]
+fn no_match_does_nothing_by_default_in_compact_mode() {
+    let picked = Arc::default();
+    let mut app = app(false, true, Arc::clone(&picked));
+    type_and_confirm(&mut app, "xyz");
+    assert_eq!(*picked.lock().unwrap(), None);
+}
+
+#[test]
+fn a_match_is_not_passed_through() {
+    let picked: Arc<Mutex<Option<String>>> = Arc::default();
+    let mut app = app(true, true, Arc::clone(&picked));
+    let _ = app.update(Message::FilterTyped('g'));
+    let _ = app.update(Message::ConfirmHovered);
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("è"));
+}
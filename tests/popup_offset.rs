@@ -0,0 +1,108 @@
+mod common;
+
+use common::{FixtureRunner, hyprland, x11, xwayland};
+use quick_accent::config::app::AppConfig;
+use quick_accent::env::Env;
+use quick_accent::screen::ScreenInfo;
+
+/// Answers `hyprctl cursorpos` and `xdotool getmouselocation` with fixed positions
+fn cursor_runner() -> FixtureRunner {
+    FixtureRunner::new()
+        .answer("hyprctl cursorpos", "2700, 300\n")
+        .answer("xdotool getmouselocation", "X=640\nY=480\nSCREEN=0\nWINDOW=123\n")
+}
+
+fn cursor_config() -> AppConfig {
+    let mut config = AppConfig::default();
+    config.tool.popup_offset = Some((10.0, -80.0));
+    config
+}
+
+fn screen_with_cursor(x: f32, y: f32) -> ScreenInfo {
+    ScreenInfo {
+        cursor_x: Some(x),
+        cursor_y: Some(y),
+        ..ScreenInfo::new(1920.0, 1080.0)
+    }
+}
+
+#[test]
+fn offset_moves_the_popup_to_the_cursor() {
+    let config = cursor_config();
+    assert_eq!(
+        config.calculate_window_position(1920.0, 1080.0, 400.0, Some((500.0, 600.0))),
+        (510.0, 520.0)
+    );
+}
+
+#[test]
+fn without_an_offset_the_cursor_is_ignored() {
+    let config = AppConfig::default();
+    assert_eq!(
+        config.calculate_window_position(1920.0, 1080.0, 400.0, Some((500.0, 600.0))),
+        config.calculate_window_position(1920.0, 1080.0, 400.0, None)
+    );
+}
+
+#[test]
+fn unknown_cursor_keeps_the_screen_position() {
+    let config = cursor_config();
+    assert_eq!(
+        config.calculate_window_position(1920.0, 1080.0, 400.0, None),
+        AppConfig::default().calculate_window_position(1920.0, 1080.0, 400.0, None)
+    );
+}
+
+#[test]
+fn cursor_is_relative_to_the_monitor() {
+    let screen = ScreenInfo {
+        x: 1920.0,
+        ..screen_with_cursor(2020.0, 600.0)
+    };
+    assert_eq!(screen.cursor_on_screen(), Some((100.0, 600.0)));
+
+    let window = cursor_config().window_rect(&screen);
+    assert_eq!((window.x, window.y), (2030.0, 520.0));
+}
+
+#[test]
+fn popup_near_the_edge_is_kept_on_screen() {
+    let window = cursor_config().window_rect(&screen_with_cursor(1900.0, 20.0));
+    assert!(window.right() <= 1920.0, "{:?}", window);
+    assert!(window.y >= 0.0, "{:?}", window);
+}
+
+#[test]
+fn cursor_parsers() {
+    assert_eq!(ScreenInfo::parse_hyprctl_cursorpos("2700, 300\n"), Some((2700.0, 300.0)));
+    assert_eq!(ScreenInfo::parse_hyprctl_cursorpos("garbage"), None);
+    assert_eq!(
+        ScreenInfo::parse_xdotool_mouse_location("X=640\nY=480\nSCREEN=0\n"),
+        Some((640, 480))
+    );
+    assert_eq!(ScreenInfo::parse_xdotool_mouse_location("X=640\n"), None);
+}
+
+#[test]
+fn cursor_comes_from_the_compositor_or_x11() {
+    let mut screen = ScreenInfo::default();
+    screen.locate_cursor(&hyprland(), &cursor_runner());
+    assert_eq!((screen.cursor_x, screen.cursor_y), (Some(2700.0), Some(300.0)));
+
+    let mut screen = ScreenInfo::default();
+    screen.locate_cursor(&x11(), &cursor_runner());
+    assert_eq!((screen.cursor_x, screen.cursor_y), (Some(640.0), Some(480.0)));
+
+    let mut screen = ScreenInfo::default();
+    screen.locate_cursor(&Env::default(), &cursor_runner());
+    assert_eq!((screen.cursor_x, screen.cursor_y), (None, None));
+}
+
+#[test]
+fn xwayland_is_not_asked_for_the_cursor() {
+    let runner = cursor_runner();
+    let mut screen = ScreenInfo::default();
+    screen.locate_cursor(&xwayland(), &runner);
+    assert_eq!((screen.cursor_x, screen.cursor_y), (None, None));
+    assert!(runner.calls().is_empty());
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{EmptyFileSystem, FixtureRunner, wayland};
+use quick_accent::config::screen::ScreenConfig;
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::fixtures;
+use quick_accent::screen::prewarm::PendingDetection;
+
+/// Answers `wlr-randr` with two outputs, after `delay`
+fn two_outputs(delay: Duration) -> FixtureRunner {
+    FixtureRunner::new()
+        .answer("wlr-randr", fixtures::WLR_RANDR_TWO_OUTPUTS)
+        .delayed(delay)
+}
+
+#[test]
+fn background_detection_matches_sync_detection() {
+    let config = ScreenConfig {
+        force_monitor: Some("HDMI-A-1".to_string()),
+        ..ScreenConfig::default()
+    };
+    let runner = two_outputs(Duration::ZERO);
+    let sync = ScreenInfo::detect_with(&config, &wayland(), &runner, &EmptyFileSystem);
+
+    let pending = PendingDetection::spawn_with(config, wayland(), runner, EmptyFileSystem);
+    let background = pending.join(Duration::from_secs(5));
+
+    assert_eq!(
+        (background.x, background.y, background.width, background.height),
+        (sync.x, sync.y, sync.width, sync.height)
+    );
+}
+
+#[test]
+fn hung_detection_falls_back_to_defaults() {
+    let runner = two_outputs(Duration::from_secs(5));
+    let pending = PendingDetection::spawn_with(ScreenConfig::default(), wayland(), runner, EmptyFileSystem);
+    let screen = pending.join(Duration::from_millis(50));
+
+    let default = ScreenInfo::default();
+    assert_eq!((screen.width, screen.height), (default.width, default.height));
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use common::{FixtureRunner, wayland, x11};
+use quick_accent::config::tool::ToolConfig;
+use quick_accent::env::Env;
+use quick_accent::screen::runner::{CommandRunner, SystemRunner};
+use quick_accent::selection::{base_from_primary_selection, parse_selection};
+
+#[test]
+fn single_character_with_variants() {
+    let config = ToolConfig::default();
+    assert_eq!(parse_selection("e", &config), Some('e'));
+    // xclip keeps the trailing newline
+    assert_eq!(parse_selection("e\n", &config), Some('e'));
+    assert_eq!(parse_selection(" n ", &config), Some('n'));
+}
+
+#[test]
+fn anything_else_is_ignored() {
+    let config = ToolConfig::default();
+    assert_eq!(parse_selection("", &config), None);
+    assert_eq!(parse_selection("\n", &config), None);
+    assert_eq!(parse_selection("ee", &config), None);
+    assert_eq!(parse_selection("e e", &config), None);
+    assert_eq!(parse_selection("q", &config), None);
+}
+
+#[test]
+fn enabled_sets_decide_what_has_variants() {
+    let mut config = ToolConfig::default();
+    assert_eq!(parse_selection("?", &config), None);
+    config.include_typographic = true;
+    assert_eq!(parse_selection("?", &config), Some('?'));
+}
+
+#[test]
+fn selection_is_read_from_the_session_clipboard_tool() {
+    let config = ToolConfig::default();
+    let runner = FixtureRunner::new()
+        .answer("wl-paste --primary --no-newline", "a")
+        .answer("xclip -o -selection primary", "o\n");
+
+    assert_eq!(base_from_primary_selection(&config, &wayland(), &runner), Some('a'));
+    assert_eq!(base_from_primary_selection(&config, &x11(), &runner), Some('o'));
+    assert_eq!(base_from_primary_selection(&config, &Env::default(), &runner), None);
+    assert_eq!(runner.programs(), ["wl-paste", "xclip"]);
+}
+
+#[test]
+fn long_or_failed_selection_is_ignored() {
+    let config = ToolConfig::default();
+    let long = "e".repeat(1000);
+    let runner = FixtureRunner::new().answer("wl-paste", &long);
+    assert_eq!(base_from_primary_selection(&config, &wayland(), &runner), None);
+
+    let runner = FixtureRunner::new().fail("wl-paste");
+    assert_eq!(base_from_primary_selection(&config, &wayland(), &runner), None);
+    let runner = FixtureRunner::new();
+    assert_eq!(base_from_primary_selection(&config, &wayland(), &runner), None);
+}
+
+#[test]
+fn default_run_limited_truncates_at_a_character_boundary() {
+    let runner = FixtureRunner::new().answer("cat", "ééé");
+    let output = runner.run_limited("cat", &[], 3, Duration::from_secs(1)).unwrap();
+    assert!(!output.success);
+    assert_eq!(output.stdout, "é");
+
+    let output = runner.run_limited("cat", &[], 16, Duration::from_secs(1)).unwrap();
+    assert!(output.success);
+    assert_eq!(output.stdout, "ééé");
+}
+
+#[test]
+fn system_run_limited_reads_short_output() {
+    let output = SystemRunner.run_limited("printf", &["e"], 16, Duration::from_secs(5)).unwrap();
+    assert!(output.success);
+    assert_eq!(output.stdout, "e");
+}
+
+#[test]
+fn system_run_limited_stops_at_the_limit() {
+    let output = SystemRunner.run_limited("yes", &[], 16, Duration::from_secs(5)).unwrap();
+    assert!(!output.success);
+    assert_eq!(output.stdout.len(), 16);
+}
+
+#[test]
+fn system_run_limited_gives_up_on_a_stuck_command() {
+    let start = Instant::now();
+    let error = SystemRunner
+        .run_limited("sleep", &["10"], 16, Duration::from_millis(100))
+        .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::TimedOut);
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{FakeFileSystem, FixtureRunner, hyprland};
+use quick_accent::config::app::AppConfig;
+use quick_accent::env::Env;
+use quick_accent::geometry::Rect;
+use quick_accent::record::Recording;
+use quick_accent::screen::fixtures;
+
+/// Hyprland session with two monitors, the portrait one focused
+fn hyprland_monitors() -> FixtureRunner {
+    FixtureRunner::new().answer("hyprctl monitors -j", fixtures::HYPRCTL_MONITORS)
+}
+
+/// Only the DRM connector directory, for the EDID lookup
+fn drm() -> FakeFileSystem {
+    FakeFileSystem::new().with_drm_connector("card0-HDMI-A-1", "1440x2560\n")
+}
+
+fn tuple(rect: Rect) -> (f32, f32, f32, f32) {
+    (rect.x, rect.y, rect.width, rect.height)
+}
+
+#[test]
+fn recorded_session_replays_to_the_same_geometry() {
+    let mut config = AppConfig::default();
+    config.screen.detect_physical_size = true;
+
+    let recording = Recording::capture(&config, &hyprland(), &hyprland_monitors(), &drm());
+    assert_eq!(recording.screen, (2560.0, 0.0, 1440.0, 2560.0));
+    assert!(recording.commands.iter().any(|command| command.program == "hyprctl"));
+    assert!(!recording.reads.is_empty());
+
+    let (screen, window) = recording.replay();
+    assert_eq!(tuple(screen), recording.screen);
+    assert_eq!(tuple(window), recording.window);
+}
+
+#[test]
+fn socket_names_are_redacted() {
+    let recording = Recording::capture(&AppConfig::default(), &hyprland(), &hyprland_monitors(), &drm());
+    assert_eq!(recording.env.hyprland_instance_signature.as_deref(), Some("redacted"));
+    assert_eq!(recording.env.wayland_display.as_deref(), Some("wayland-1"));
+}
+
+#[test]
+fn recording_survives_a_round_trip_through_a_file() {
+    let recording = Recording::capture(&AppConfig::default(), &hyprland(), &hyprland_monitors(), &drm());
+    let path = std::env::temp_dir().join(format!("quick-accent-recording-{}.ron", std::process::id()));
+    recording.save_to_path(&path).unwrap();
+    let loaded = Recording::load_from_path(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(loaded.commands, recording.commands);
+    assert_eq!(loaded.reads, recording.reads);
+    let (screen, window) = loaded.replay();
+    assert_eq!(tuple(screen), recording.screen);
+    assert_eq!(tuple(window), recording.window);
+}
+
+#[test]
+fn locale_is_replayed() {
+    let env = Env {
+        locale: Some("fr_FR.UTF-8".to_string()),
+        ..hyprland()
+    };
+    let recording = Recording::capture(&AppConfig::default(), &env, &hyprland_monitors(), &drm());
+    assert_eq!(Env::from(&recording.env).locale.as_deref(), Some("fr_FR.UTF-8"));
+}
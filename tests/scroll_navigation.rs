@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use iced::Size;
+use quick_accent::accents;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::layout;
+
+fn app() -> App {
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default())
+}
+
+#[test]
+fn scrolling_forward_wraps_to_the_first() {
+    assert_eq!(layout::scroll_step(Some(3), 1, 5), Some(4));
+    assert_eq!(layout::scroll_step(Some(4), 1, 5), Some(0));
+}
+
+#[test]
+fn scrolling_back_wraps_to_the_last() {
+    assert_eq!(layout::scroll_step(Some(1), -1, 5), Some(0));
+    assert_eq!(layout::scroll_step(Some(0), -1, 5), Some(4));
+}
+
+#[test]
+fn first_scroll_starts_at_an_end() {
+    assert_eq!(layout::scroll_step(None, 1, 5), Some(0));
+    assert_eq!(layout::scroll_step(None, -1, 5), Some(4));
+}
+
+#[test]
+fn nothing_to_scroll_through() {
+    assert_eq!(layout::scroll_step(None, 1, 0), None);
+    assert_eq!(layout::scroll_step(Some(2), -1, 0), None);
+}
+
+#[test]
+fn app_scrolls_around_the_row() {
+    let mut app = app();
+    let last = accents::variants_for('e').len() - 1;
+
+    let _ = app.update(Message::ScrollNavigation(-1));
+    assert_eq!(app.hovered_index(), Some(last));
+    let _ = app.update(Message::ScrollNavigation(1));
+    assert_eq!(app.hovered_index(), Some(0));
+    let _ = app.update(Message::ScrollNavigation(1));
+    assert_eq!(app.hovered_index(), Some(1));
+}
+
+#[test]
+fn touchpad_pixels_add_up_to_a_step() {
+    let mut app = app();
+    let _ = app.update(Message::ScrolledPixels(-layout::SCROLL_STEP_PIXELS / 4.0));
+    let _ = app.update(Message::ScrolledPixels(-layout::SCROLL_STEP_PIXELS / 4.0));
+    assert_eq!(app.hovered_index(), None);
+
+    let _ = app.update(Message::ScrolledPixels(-layout::SCROLL_STEP_PIXELS / 2.0));
+    assert_eq!(app.hovered_index(), Some(0));
+
+    // A fast swipe steps several variants at once
+    let _ = app.update(Message::ScrolledPixels(-2.0 * layout::SCROLL_STEP_PIXELS));
+    assert_eq!(app.hovered_index(), Some(2));
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use iced::Size;
+use quick_accent::accents;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::search;
+
+fn app(search_on_type: bool) -> App {
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    config.tool.search_on_type = search_on_type;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default())
+}
+
+#[test]
+fn empty_filter_keeps_everything() {
+    let groups = accents::groups_for('e', true, true);
+    assert_eq!(search::filter_groups(&groups, "", true), groups);
+    assert_eq!(search::filter_groups(&groups, "", false), groups);
+}
+
+#[test]
+fn filter_matches_the_character_or_a_word_of_its_name() {
+    assert!(search::matches('é', "é", false));
+    assert!(search::matches('é', "ac", false));
+    assert!(search::matches('É', "acute", false));
+    assert!(!search::matches('é', "grave", false));
+    // Words are matched from their start only
+    assert!(!search::matches('é', "cute", true));
+}
+
+#[test]
+fn exact_case_match() {
+    assert!(search::matches('À', "À", false));
+    assert!(!search::matches('à', "À", false));
+    assert!(!search::matches('ç', "C", false));
+}
+
+#[test]
+fn case_insensitive_match() {
+    assert!(search::matches('à', "À", true));
+    assert!(search::matches('À', "à", true));
+    assert!(search::matches('à', "A", true));
+    assert!(search::matches('À', "A", true));
+    assert!(search::matches('ç', "C", true));
+}
+
+#[test]
+fn no_match() {
+    assert!(!search::matches('à', "é", true));
+    assert!(!search::matches('à', "z", true));
+    assert!(!search::matches('à', "z", false));
+}
+
+#[test]
+fn filtering_drops_empty_groups() {
+    let groups = vec![vec!['è', 'é'], vec!['€', '&']];
+    assert_eq!(search::filter_groups(&groups, "g", true), vec![vec!['è']]);
+    assert!(search::filter_groups(&groups, "z", true).is_empty());
+}
+
+#[test]
+fn digits_and_whitespace_are_not_searched() {
+    assert!(search::accepts('a'));
+    assert!(search::accepts('é'));
+    assert!(!search::accepts('1'));
+    assert!(!search::accepts(' '));
+}
+
+#[test]
+fn typing_narrows_and_backspace_widens() {
+    let mut app = app(true);
+    let all = app.visible_variants();
+
+    let _ = app.update(Message::FilterTyped('a'));
+    assert_eq!(app.filter_text(), "a");
+    assert_eq!(app.visible_variants(), vec!['é']);
+
+    let _ = app.update(Message::FilterTyped('x'));
+    assert!(app.visible_variants().is_empty());
+
+    let _ = app.update(Message::Backspace);
+    let _ = app.update(Message::Backspace);
+    assert_eq!(app.filter_text(), "");
+    assert_eq!(app.visible_variants(), all);
+}
+
+#[test]
+fn escape_clears_the_filter_first() {
+    let mut app = app(true);
+    let _ = app.update(Message::FilterTyped('g'));
+    assert_eq!(app.visible_variants(), vec!['è']);
+
+    let _ = app.update(Message::Escape);
+    assert_eq!(app.filter_text(), "");
+    assert_eq!(app.visible_variants(), accents::variants_for('e'));
+}
+
+#[test]
+fn filter_text_keeps_the_typed_case() {
+    let mut app = app(true);
+    let _ = app.update(Message::FilterTyped('G'));
+    assert_eq!(app.filter_text(), "G");
+    assert_eq!(app.visible_variants(), vec!['è']);
+}
+
+#[test]
+fn typing_does_nothing_when_search_is_off() {
+    let mut app = app(false);
+    let _ = app.update(Message::FilterTyped('a'));
+    assert_eq!(app.filter_text(), "");
+}
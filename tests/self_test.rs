@@ -0,0 +1,19 @@
+use quick_accent::cli::{Cli, Command};
+use quick_accent::self_test;
+
+#[test]
+fn every_section_passes() {
+    let scratch_dir = std::env::temp_dir().join(format!("quick-accent-self-test-test-{}", std::process::id()));
+    std::fs::create_dir_all(&scratch_dir).unwrap();
+
+    let sections = self_test::run(&scratch_dir);
+    let _ = std::fs::remove_dir_all(&scratch_dir);
+
+    let failed: Vec<_> = sections.iter().filter(|section| !section.passed()).collect();
+    assert!(failed.is_empty(), "failed sections: {:?}", failed);
+}
+
+#[test]
+fn parses_self_test_command() {
+    assert_eq!(Cli::parse(["self-test"]).map(|cli| cli.command), Ok(Command::SelfTest));
+}
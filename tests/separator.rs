@@ -0,0 +1,42 @@
+use std::sync::{Arc, Mutex};
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::layout;
+
+#[test]
+fn separator_yields_seven_children_for_two_groups_of_three() {
+    let groups = vec![vec!['é', 'è', 'ê'], vec!['€', '&', 'ə']];
+    let cells = layout::cells(&groups, true);
+    assert_eq!(cells.len(), 7);
+    assert_eq!(cells[3], None);
+    assert_eq!(cells.iter().filter(|cell| cell.is_some()).count(), 6);
+}
+
+#[test]
+fn no_separator_without_the_option() {
+    let groups = vec![vec!['é', 'è', 'ê'], vec!['€', '&', 'ə']];
+    assert_eq!(layout::cells(&groups, false).len(), 6);
+}
+
+#[test]
+fn single_group_has_no_separator() {
+    assert_eq!(layout::cells(&[vec!['é', 'è', 'ê']], true).len(), 3);
+}
+
+#[test]
+fn separator_takes_no_variant_index() {
+    let picked: Arc<Mutex<Option<String>>> = Arc::default();
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    config.tool.include_typographic = true;
+    config.tool.separator_character = Some('|');
+    let mut app = App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::clone(&picked));
+
+    // Seven accented e's, then the typographic group
+    assert!(!app.visible_variants().contains(&'|'));
+    let _ = app.update(Message::VariantClicked(7));
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("€"));
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use common::{FixtureRunner, wayland, x11};
+use quick_accent::config::app::AppConfig;
+use quick_accent::config::border::BorderConfig;
+use quick_accent::config::shadow::ShadowConfig;
+use quick_accent::shadow;
+
+fn shadow_config() -> ShadowConfig {
+    ShadowConfig {
+        offset_x: 0.0,
+        offset_y: 0.0,
+        blur_radius: 10.0,
+        color: [0, 0, 0, 128],
+    }
+}
+
+#[test]
+fn shadow_grows_window_by_twice_the_blur() {
+    let mut config = AppConfig::default();
+    let (width, height) = config.calculate_window_size(1000.0, 800.0);
+
+    config.tool.shadow = Some(shadow_config());
+    let (shadow_width, shadow_height) = config.calculate_window_size(1000.0, 800.0);
+
+    assert_eq!(shadow_width, width + 20.0);
+    assert_eq!(shadow_height, height + 20.0);
+}
+
+#[test]
+fn shadow_keeps_popup_in_place() {
+    let mut config = AppConfig::default();
+    config.window.center_horizontally = false;
+    let (width, _) = config.calculate_window_size(1000.0, 800.0);
+    let (x, y) = config.calculate_window_position(1000.0, 800.0, width, None);
+
+    config.tool.shadow = Some(shadow_config());
+    let (shadow_width, _) = config.calculate_window_size(1000.0, 800.0);
+    let (shadow_x, shadow_y) = config.calculate_window_position(1000.0, 800.0, shadow_width, None);
+
+    assert_eq!((shadow_x, shadow_y), (x - 10.0, y - 10.0));
+}
+
+#[test]
+fn layers_fade_out_from_the_popup_edge() {
+    let layers = shadow::layers(&shadow_config(), 220.0, 120.0);
+
+    // Outermost layer fills the window, innermost stays just outside the popup
+    let (outer, _) = layers[0];
+    assert_eq!((outer.x, outer.y, outer.width, outer.height), (0.0, 0.0, 220.0, 120.0));
+    let (inner, _) = layers[layers.len() - 1];
+    assert!(inner.x > 0.0 && inner.x < 10.0);
+    assert!(layers.windows(2).all(|pair| pair[0].0.width > pair[1].0.width));
+
+    // Where all layers overlap the shadow reaches the configured alpha
+    let total_alpha: f32 = layers.iter().map(|(_, alpha)| alpha).sum();
+    assert!((total_alpha - 128.0 / 255.0).abs() < 1e-4);
+}
+
+#[test]
+fn layers_follow_the_offset() {
+    let offset = ShadowConfig {
+        offset_x: 3.0,
+        offset_y: 5.0,
+        ..shadow_config()
+    };
+    let (outer, _) = shadow::layers(&offset, 220.0, 120.0)[0];
+    assert_eq!((outer.x, outer.y), (3.0, 5.0));
+}
+
+#[test]
+fn centered_popup_stays_centered_with_shadow() {
+    let mut config = AppConfig::default();
+    config.tool.shadow = Some(shadow_config());
+    let (width, _) = config.calculate_window_size(1000.0, 800.0);
+    let (x, _) = config.calculate_window_position(1000.0, 800.0, width, None);
+
+    let margin = config.tool.shadow_margin();
+    let popup_center = x + margin + (width - 2.0 * margin) / 2.0;
+    assert_eq!(popup_center, 500.0);
+}
+
+#[test]
+fn border_does_not_change_geometry() {
+    let mut config = AppConfig::default();
+    config.tool.shadow = Some(shadow_config());
+    let size = config.calculate_window_size(1000.0, 800.0);
+
+    config.tool.border = Some(BorderConfig {
+        width: 3.0,
+        ..BorderConfig::default()
+    });
+    assert_eq!(config.calculate_window_size(1000.0, 800.0), size);
+    assert_eq!(config.tool.corner_radius(), BorderConfig::default().radius);
+}
+
+/// `pgrep` finding a running X11 compositor, or not
+fn pgrep(found: bool) -> FixtureRunner {
+    if found {
+        FixtureRunner::new().answer("pgrep", "")
+    } else {
+        FixtureRunner::new().fail("pgrep")
+    }
+}
+
+#[test]
+fn transparency_needs_a_compositor_on_plain_x11() {
+    assert!(shadow::transparency_supported(&x11(), &pgrep(true)));
+    assert!(!shadow::transparency_supported(&x11(), &pgrep(false)));
+
+    assert!(shadow::transparency_supported(&wayland(), &pgrep(false)));
+}
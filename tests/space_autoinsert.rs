@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex};
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::config::tool::WindowState;
+use quick_accent::search;
+
+fn app(picked: Arc<Mutex<Option<String>>>) -> App {
+    let mut config = AppConfig::default();
+    config.tool.space_autoinsert = true;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), picked)
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for ch in text.chars() {
+        let _ = app.update(Message::FilterTyped(ch));
+    }
+}
+
+#[test]
+fn variant_names_resolve() {
+    assert_eq!(search::resolve_trigger("eacute"), Some('é'));
+    assert_eq!(search::resolve_trigger("Eacute"), Some('É'));
+    assert_eq!(search::resolve_trigger("ccedilla"), Some('ç'));
+}
+
+#[test]
+fn html_entity_names_resolve() {
+    assert_eq!(search::resolve_trigger("szlig"), Some('ß'));
+    assert_eq!(search::resolve_trigger("euro"), Some('€'));
+}
+
+#[test]
+fn unknown_words_do_not_resolve() {
+    assert_eq!(search::resolve_trigger("hello"), None);
+    assert_eq!(search::resolve_trigger(""), None);
+}
+
+#[test]
+fn space_inserts_the_character_and_the_space() {
+    assert_eq!(search::expand_on_space("eacute ").as_deref(), Some("é "));
+}
+
+#[test]
+fn no_match_passes_the_text_through() {
+    assert_eq!(search::expand_on_space("hello ").as_deref(), Some("hello "));
+}
+
+#[test]
+fn nothing_happens_before_the_space() {
+    assert_eq!(search::expand_on_space("eacute"), None);
+}
+
+#[test]
+fn app_inserts_on_space() {
+    let picked = Arc::default();
+    let mut app = app(Arc::clone(&picked));
+    type_text(&mut app, "eacute ");
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("é "));
+}
+
+#[test]
+fn app_passes_unknown_words_through() {
+    let picked = Arc::default();
+    let mut app = app(Arc::clone(&picked));
+    type_text(&mut app, "cafe ");
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("cafe "));
+}
+
+#[test]
+fn space_without_a_word_is_ignored() {
+    let picked = Arc::default();
+    let mut app = app(Arc::clone(&picked));
+    type_text(&mut app, " ");
+    assert_eq!(*picked.lock().unwrap(), None);
+    assert_eq!(app.window_state(), WindowState::Shown);
+
+    type_text(&mut app, "eacute ");
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("é "));
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::FixtureRunner;
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::config::tool::{ToolConfig, WindowBehavior};
+use quick_accent::output::OutputBackend;
+use quick_accent::sticky::StickySession;
+
+#[test]
+fn first_pick_has_no_separator() {
+    let mut session = StickySession::new(", ".to_string());
+    assert_eq!(session.text_for("é"), "é");
+}
+
+#[test]
+fn later_picks_are_separated() {
+    let mut session = StickySession::new(" ".to_string());
+    let texts: Vec<String> = ["é", "è", "ê"].iter().map(|pick| session.text_for(pick)).collect();
+    assert_eq!(texts, vec!["é", " è", " ê"]);
+}
+
+#[test]
+fn default_separator_joins_back_to_back() {
+    let mut session = StickySession::new(ToolConfig::default().sticky_separator);
+    let joined: String = ["é", "è"].iter().map(|pick| session.text_for(pick)).collect();
+    assert_eq!(joined, "éè");
+}
+
+#[test]
+fn closing_the_window_starts_a_new_session() {
+    let mut session = StickySession::new(",".to_string());
+    assert_eq!(session.text_for("é"), "é");
+    assert_eq!(session.text_for("è"), ",è");
+
+    session.reset();
+    assert_eq!(session.text_for("ê"), "ê");
+    assert_eq!(session.text_for("ë"), ",ë");
+}
+
+fn pick(app: &mut App, ch: char) {
+    let _ = app.update(Message::CharacterSelected(ch));
+    let _ = app.update(Message::DeliverPicked);
+}
+
+#[test]
+fn app_separates_picks_until_dismissed() {
+    let mut config = AppConfig::default();
+    config.tool.window_behavior = WindowBehavior::HideAndShow;
+    config.tool.sticky_separator = ",".to_string();
+    let runner = FixtureRunner::new().answer("wtype", "");
+    let mut app = App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default())
+        .with_delivery(OutputBackend::Wtype, Arc::default())
+        .with_runner(runner.clone());
+
+    pick(&mut app, 'é');
+    pick(&mut app, 'è');
+    let _ = app.update(Message::Dismissed);
+    pick(&mut app, 'ê');
+
+    assert_eq!(runner.calls(), vec!["wtype -- é", "wtype -- ,è", "wtype -- ê"]);
+}
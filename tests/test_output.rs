@@ -0,0 +1,51 @@
+use quick_accent::cli::{Cli, Command};
+use quick_accent::output::OutputBackend;
+use quick_accent::test_output::{self, MARKER, TestOutputMode};
+
+#[test]
+fn countdown_counts_down_to_one() {
+    assert_eq!(test_output::countdown_lines(3), vec!["3...", "2...", "1..."]);
+    assert!(test_output::countdown_lines(0).is_empty());
+}
+
+#[test]
+fn marker_mixes_scripts() {
+    assert!(MARKER.contains('é'));
+    assert!(MARKER.contains('日'));
+    assert!(MARKER.contains('€'));
+}
+
+#[test]
+fn marker_is_passed_after_end_of_options() {
+    assert_eq!(
+        OutputBackend::Wtype.command(MARKER),
+        Some(("wtype", vec!["--", MARKER]))
+    );
+    assert_eq!(
+        OutputBackend::Xdotool.command(MARKER),
+        Some(("xdotool", vec!["type", "--clearmodifiers", "--", MARKER]))
+    );
+    assert_eq!(
+        OutputBackend::WlCopy.command(MARKER),
+        Some(("wl-copy", vec!["--", MARKER]))
+    );
+}
+
+#[test]
+fn xclip_takes_marker_on_stdin() {
+    let (program, args) = OutputBackend::Xclip.command(MARKER).unwrap();
+    assert_eq!(program, "xclip");
+    assert!(!args.contains(&MARKER));
+}
+
+#[test]
+fn parses_test_output_modes() {
+    let parse = |args: &[&str]| Cli::parse(args.iter().copied()).map(|cli| cli.command);
+
+    assert_eq!(parse(&["test-output"]), Ok(Command::TestOutput(TestOutputMode::Type)));
+    assert_eq!(
+        parse(&["test-output", "--mode", "clipboard"]),
+        Ok(Command::TestOutput(TestOutputMode::Clipboard))
+    );
+    assert!(parse(&["test-output", "--mode", "fax"]).is_err());
+}
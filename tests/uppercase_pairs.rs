@@ -0,0 +1,89 @@
+use quick_accent::accents::{self, names, uppercase_digraph, uppercase_form, uppercase_variant};
+
+#[test]
+fn language_pair_wins_over_to_uppercase() {
+    assert_eq!(uppercase_variant('i', 'i', Some("tr")), Some('İ'));
+    assert_eq!(uppercase_variant('i', 'i', Some("az")), Some('İ'));
+    assert_eq!(uppercase_variant('i', 'ı', Some("az")), Some('I'));
+    // to_uppercase would give "SS"
+    assert_eq!(uppercase_variant('s', 'ß', Some("de")), Some('ẞ'));
+}
+
+#[test]
+fn pairs_only_apply_to_their_language() {
+    assert_eq!(uppercase_variant('i', 'i', None), Some('I'));
+    assert_eq!(uppercase_variant('i', 'i', Some("fr")), Some('I'));
+    assert_eq!(uppercase_variant('s', 'ß', None), None);
+    assert_eq!(uppercase_variant('s', 'ß', Some("tr")), None);
+}
+
+#[test]
+fn without_a_pair_falls_back_to_to_uppercase() {
+    assert_eq!(uppercase_variant('e', 'é', Some("tr")), Some('É'));
+    assert_eq!(uppercase_variant('o', 'œ', None), Some('Œ'));
+    assert_eq!(uppercase_variant('i', 'ĳ', None), Some('Ĳ'));
+}
+
+#[test]
+fn ipa_letters_have_uppercase_forms() {
+    assert_eq!(uppercase_variant('e', 'ə', None), Some('Ə'));
+    assert_eq!(uppercase_variant('s', 'ʃ', None), Some('Ʃ'));
+}
+
+#[test]
+fn symbols_have_no_uppercase_form() {
+    assert_eq!(uppercase_variant('e', '€', None), Some('€'));
+    assert_eq!(uppercase_variant('.', '·', None), Some('·'));
+}
+
+#[test]
+fn dutch_ij_uppercases_to_two_letters() {
+    assert_eq!(uppercase_form('i', 'ĳ', Some("nl")), "IJ");
+    // The row still shows the single capital ligature
+    assert_eq!(uppercase_variant('i', 'ĳ', Some("nl")), Some('Ĳ'));
+    assert_eq!(uppercase_digraph('I', 'Ĳ', Some("nl")), Some("IJ"));
+
+    // Elsewhere, and in a lowercase row, Ĳ is typed as itself
+    assert_eq!(uppercase_form('i', 'ĳ', None), "Ĳ");
+    assert_eq!(uppercase_digraph('I', 'Ĳ', None), None);
+    assert_eq!(uppercase_digraph('i', 'ĳ', Some("nl")), None);
+}
+
+#[test]
+fn multi_char_uppercase_has_no_single_form() {
+    assert_eq!(uppercase_form('s', 'ß', None), "SS");
+    assert_eq!(uppercase_variant('s', 'ß', None), None);
+    // ŉ uppercases to "ʼN"
+    assert_eq!(uppercase_variant('n', 'ŉ', None), None);
+}
+
+#[test]
+fn turkish_i_row_offers_the_dotted_capital() {
+    let turkish = accents::variants_in('I', Some("tr"));
+    assert!(turkish.contains(&'İ'));
+    // The dotless ı uppercases to the base I itself
+    assert!(!turkish.contains(&'I'));
+    assert_eq!(names::variant_name('İ').as_deref(), Some("I dot above"));
+
+    assert_eq!(accents::variants_in('I', Some("az")), turkish);
+
+    assert!(!accents::variants_for('I').contains(&'İ'));
+    assert!(accents::variants_for('I').contains(&'Ĳ'));
+}
+
+#[test]
+fn german_s_row_offers_the_capital_sharp_s() {
+    let german = accents::variants_in('S', Some("de"));
+    assert!(german.contains(&'ẞ'));
+    assert_eq!(names::variant_name('ẞ').as_deref(), Some("capital sharp s"));
+
+    // Elsewhere ß stays, its uppercase form is two letters
+    assert!(accents::variants_for('S').contains(&'ß'));
+    assert!(!accents::variants_for('S').contains(&'ẞ'));
+}
+
+#[test]
+fn lowercase_rows_are_unchanged() {
+    assert_eq!(accents::variants_in('i', Some("tr")), accents::variants_for('i'));
+    assert_eq!(accents::variants_in('s', Some("de")), accents::variants_for('s'));
+}
@@ -0,0 +1,112 @@
+use std::time::{Duration, Instant};
+
+use quick_accent::cli::{Cli, EXIT_INJECTION_FAILED, EXIT_INVALID_URI, EXIT_USAGE};
+use quick_accent::config::tool::ToolConfig;
+use quick_accent::output::{OutputBackend, OutputRepresentation};
+use quick_accent::trigger::{self, Trigger};
+use quick_accent::uri::{AccentUri, CharacterSet};
+
+#[test]
+fn plain_base() {
+    let uri = AccentUri::parse("accent:e").unwrap();
+    assert_eq!(uri.base, 'e');
+    assert_eq!(uri.set, None);
+    assert!(uri.ignored_keys.is_empty());
+}
+
+#[test]
+fn percent_encoded_utf8_base() {
+    let uri = AccentUri::parse("accent:%C3%A9").unwrap();
+    assert_eq!(uri.base, 'é');
+}
+
+#[test]
+fn query_keys_map_onto_config() {
+    let uri = AccentUri::parse("accent:e?uppercase=1&set=all&output=xclip").unwrap();
+    assert_eq!(uri.uppercase, Some(true));
+    assert_eq!(uri.set, Some(CharacterSet::All));
+
+    let mut tool = ToolConfig::default();
+    uri.apply(&mut tool);
+    assert_eq!(tool.base_character, 'E');
+    assert!(tool.include_typographic && tool.include_ipa);
+    assert_eq!(tool.output_backend, Some(OutputBackend::Xclip));
+}
+
+#[test]
+fn print_wins_over_output() {
+    let uri = AccentUri::parse("accent:a?output=wtype&print=1").unwrap();
+    let mut tool = ToolConfig::default();
+    uri.apply(&mut tool);
+    assert_eq!(tool.output_backend, Some(OutputBackend::Stdout));
+}
+
+#[test]
+fn representation_key() {
+    let uri = AccentUri::parse("accent:e?as=latex").unwrap();
+    let mut tool = ToolConfig::default();
+    uri.apply(&mut tool);
+    assert_eq!(tool.output_representation, OutputRepresentation::Latex);
+    assert!(AccentUri::parse("accent:e?as=rtf").is_err());
+}
+
+#[test]
+fn unknown_keys_are_ignored() {
+    let uri = AccentUri::parse("accent:o?theme=dark&set=ipa").unwrap();
+    assert_eq!(uri.ignored_keys, vec!["theme".to_string()]);
+    assert_eq!(uri.set, Some(CharacterSet::Ipa));
+}
+
+#[test]
+fn malformed_uris_are_rejected() {
+    for uri in [
+        "accent:",
+        "accent:ab",
+        "accent:%20",
+        "accent:%C3",
+        "accent:%zz",
+        "accent:e?set=klingon",
+        "accent:e?uppercase=yes",
+        "accent:e?output=printer",
+    ] {
+        assert!(AccentUri::parse(uri).is_err(), "{} should be rejected", uri);
+    }
+}
+
+#[test]
+fn cli_keeps_uri_for_the_picker() {
+    let cli = Cli::parse(["accent:%C3%A9?set=all"]).unwrap();
+    assert_eq!(cli.uri.as_deref(), Some("accent:%C3%A9?set=all"));
+    assert!(Cli::parse(["self-test", "accent:e"]).is_err());
+}
+
+#[test]
+fn invalid_uri_has_its_own_exit_status() {
+    assert_eq!(EXIT_INVALID_URI, 3);
+    assert_ne!(EXIT_INVALID_URI, EXIT_USAGE);
+    assert_ne!(EXIT_INVALID_URI, EXIT_INJECTION_FAILED);
+    assert_ne!(EXIT_INVALID_URI, 0);
+}
+
+#[test]
+fn uri_is_forwarded_to_a_running_daemon() {
+    let path = std::env::temp_dir().join(format!("quick-accent-uri-{}.sock", std::process::id()));
+    assert!(trigger::forward(&path, "accent:e").is_err(), "no daemon is running yet");
+
+    trigger::spawn_socket_listener(&path).unwrap();
+    assert!(trigger::spawn_socket_listener(&path).is_err(), "a second daemon must not take over");
+    trigger::forward(&path, "accent:%C3%A9?set=all").unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut received = Vec::new();
+    while received.is_empty() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+        received = trigger::take_pending();
+    }
+    let _ = std::fs::remove_file(&path);
+
+    assert!(matches!(
+        received.as_slice(),
+        [Trigger::Uri(uri)] if uri.base == 'é' && uri.set == Some(CharacterSet::All)
+    ));
+}
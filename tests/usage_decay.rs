@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::usage::{UsageHistory, decayed_score};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn days_ago(now: SystemTime, days: u32) -> SystemTime {
+    now - DAY * days
+}
+
+#[test]
+fn fresh_entry_keeps_its_count() {
+    let now = SystemTime::now();
+    assert_eq!(decayed_score(8, now, now, 30.0), 8.0);
+}
+
+#[test]
+fn one_half_life_halves_the_score() {
+    let now = SystemTime::now();
+    let score = decayed_score(8, days_ago(now, 30), now, 30.0);
+    assert!((score - 4.0).abs() < 1e-4, "{}", score);
+
+    let score = decayed_score(8, days_ago(now, 60), now, 30.0);
+    assert!((score - 2.0).abs() < 1e-4, "{}", score);
+}
+
+#[test]
+fn recent_picks_beat_an_old_burst() {
+    let now = SystemTime::now();
+    let old_burst = decayed_score(50, days_ago(now, 365), now, 30.0);
+    let recent = decayed_score(3, days_ago(now, 2), now, 30.0);
+    assert!(recent > old_burst, "{} <= {}", recent, old_burst);
+}
+
+#[test]
+fn future_timestamp_counts_as_now() {
+    let now = SystemTime::now();
+    assert_eq!(decayed_score(5, now + DAY, now, 30.0), 5.0);
+}
+
+#[test]
+fn half_life_must_be_positive() {
+    let mut config = AppConfig::default();
+    config.tool.history_decay_half_life_days = 0.0;
+    assert!(config.validate().is_err());
+
+    config.tool.history_decay_half_life_days = 7.0;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn ranking_puts_recent_picks_first() {
+    let now = SystemTime::now();
+    let mut history = UsageHistory::default();
+    for _ in 0..50 {
+        history.record('è', days_ago(now, 365));
+    }
+    history.record('ê', days_ago(now, 1));
+
+    let mut group = vec!['é', 'è', 'ê', 'ë'];
+    history.rank(&mut group, now, 30.0);
+    assert_eq!(group, vec!['ê', 'è', 'é', 'ë']);
+}
+
+#[test]
+fn history_survives_a_round_trip_through_a_file() {
+    let mut history = UsageHistory::default();
+    history.record('é', SystemTime::now());
+    history.record('é', SystemTime::now());
+
+    let path = std::env::temp_dir().join(format!("quick-accent-usage-{}.ron", std::process::id()));
+    history.save_to_path(&path).unwrap();
+    let loaded = UsageHistory::load_from_path(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(loaded, history);
+    assert_eq!(loaded.get('é').map(|usage| usage.count), Some(2));
+}
+
+#[test]
+fn app_ranks_by_usage_and_counts_picks() {
+    let mut history = UsageHistory::default();
+    history.record('ê', SystemTime::now());
+    let usage = Arc::new(Mutex::new(history));
+
+    let mut config = AppConfig::default();
+    config.tool.base_character = 'e';
+    let mut app = App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), Arc::default())
+        .with_usage(Arc::clone(&usage));
+    assert_eq!(app.visible_variants().first(), Some(&'ê'));
+
+    let _ = app.update(Message::CharacterSelected('è'));
+    assert_eq!(usage.lock().unwrap().get('è').map(|usage| usage.count), Some(1));
+}
@@ -0,0 +1,132 @@
+use std::sync::{Arc, Mutex};
+
+use iced::Size;
+use quick_accent::app::{App, Message};
+use quick_accent::clipboard_ring::ClipboardRing;
+use quick_accent::config::app::AppConfig;
+use quick_accent::config::tool::{WindowBehavior, WindowEvent, WindowState};
+use quick_accent::trigger::{self, Trigger};
+
+const EVENTS: [WindowEvent; 3] = [WindowEvent::Triggered, WindowEvent::Picked, WindowEvent::Dismissed];
+
+fn app(behavior: WindowBehavior, picked: Arc<Mutex<Option<String>>>) -> App {
+    let mut config = AppConfig::default();
+    config.tool.window_behavior = behavior;
+    App::new(config, Size::new(800.0, 100.0), ClipboardRing::default(), picked)
+}
+
+#[test]
+fn default_closes_each_time() {
+    assert_eq!(WindowBehavior::default(), WindowBehavior::CloseAndReopenEachTime);
+    assert!(!WindowBehavior::default().keeps_window());
+}
+
+#[test]
+fn close_and_reopen_transitions() {
+    let behavior = WindowBehavior::CloseAndReopenEachTime;
+    assert_eq!(behavior.initial_state(), WindowState::Shown);
+    assert_eq!(behavior.next_state(WindowState::Shown, WindowEvent::Triggered), WindowState::Shown);
+    assert_eq!(behavior.next_state(WindowState::Shown, WindowEvent::Picked), WindowState::Closed);
+    assert_eq!(behavior.next_state(WindowState::Shown, WindowEvent::Dismissed), WindowState::Closed);
+}
+
+#[test]
+fn hide_and_show_transitions() {
+    let behavior = WindowBehavior::HideAndShow;
+    // Launched by the trigger it answers, so the first use is already shown
+    assert_eq!(behavior.initial_state(), WindowState::Shown);
+    assert_eq!(behavior.next_state(WindowState::Shown, WindowEvent::Picked), WindowState::Hidden);
+    assert_eq!(behavior.next_state(WindowState::Shown, WindowEvent::Dismissed), WindowState::Hidden);
+    assert_eq!(behavior.next_state(WindowState::Hidden, WindowEvent::Triggered), WindowState::Shown);
+}
+
+#[test]
+fn singleton_daemon_starts_hidden() {
+    let behavior = WindowBehavior::SingletonDaemon;
+    assert_eq!(behavior.initial_state(), WindowState::Hidden);
+    assert_eq!(behavior.next_state(WindowState::Hidden, WindowEvent::Triggered), WindowState::Shown);
+    assert_eq!(behavior.next_state(WindowState::Shown, WindowEvent::Picked), WindowState::Hidden);
+}
+
+#[test]
+fn closed_is_final() {
+    for behavior in [
+        WindowBehavior::CloseAndReopenEachTime,
+        WindowBehavior::HideAndShow,
+        WindowBehavior::SingletonDaemon,
+    ] {
+        for event in EVENTS {
+            assert_eq!(behavior.next_state(WindowState::Closed, event), WindowState::Closed);
+        }
+    }
+}
+
+#[test]
+fn app_hides_on_pick_and_shows_on_trigger() {
+    let picked = Arc::default();
+    let mut app = app(WindowBehavior::HideAndShow, Arc::clone(&picked));
+
+    let _ = app.update(Message::CharacterSelected('é'));
+    assert_eq!(app.window_state(), WindowState::Hidden);
+    // Without a delivery backend the pick is left for `main`
+    assert_eq!(picked.lock().unwrap().as_deref(), Some("é"));
+
+    let _ = app.update(Message::TriggerForKey('a'));
+    assert_eq!(app.window_state(), WindowState::Shown);
+    assert!(app.visible_variants().contains(&'à'));
+
+    let _ = app.update(Message::Dismissed);
+    assert_eq!(app.window_state(), WindowState::Hidden);
+}
+
+#[test]
+fn app_closes_by_default() {
+    let mut app = app(WindowBehavior::CloseAndReopenEachTime, Arc::default());
+    let _ = app.update(Message::Dismissed);
+    assert_eq!(app.window_state(), WindowState::Closed);
+}
+
+#[test]
+fn banner_always_closes() {
+    let mut app = app(WindowBehavior::HideAndShow, Arc::default()).with_banner("failed".to_string());
+    let _ = app.update(Message::Dismissed);
+    assert_eq!(app.window_state(), WindowState::Closed);
+}
+
+#[test]
+fn trigger_lines() {
+    assert_eq!(Trigger::parse(""), Some(Trigger::Show));
+    assert_eq!(Trigger::parse("show\n"), Some(Trigger::Show));
+    assert_eq!(Trigger::parse(" ö "), Some(Trigger::Base('ö')));
+    assert_eq!(Trigger::parse("hide"), None);
+    assert!(matches!(Trigger::parse("accent:%C3%A9"), Some(Trigger::Uri(uri)) if uri.base == 'é'));
+    assert_eq!(Trigger::parse("accent:"), None);
+}
+
+#[test]
+fn daemon_shows_on_a_stdin_trigger() {
+    let mut app = app(WindowBehavior::SingletonDaemon, Arc::default());
+    assert_eq!(app.window_state(), WindowState::Hidden);
+
+    let _ = app.update(Message::PollTriggers);
+    assert_eq!(app.window_state(), WindowState::Hidden);
+
+    trigger::read_lines("nonsense\no\n".as_bytes());
+    let _ = app.update(Message::PollTriggers);
+    assert_eq!(app.window_state(), WindowState::Shown);
+    assert!(app.visible_variants().contains(&'ö'));
+
+    let _ = app.update(Message::Dismissed);
+    assert_eq!(app.window_state(), WindowState::Hidden);
+
+    // A forwarded URI's options hold for that use only
+    trigger::read_lines("accent:e?set=ipa\n".as_bytes());
+    let _ = app.update(Message::PollTriggers);
+    assert_eq!(app.window_state(), WindowState::Shown);
+    assert!(app.visible_variants().contains(&'ə'));
+
+    let _ = app.update(Message::Dismissed);
+    trigger::read_lines("e\n".as_bytes());
+    let _ = app.update(Message::PollTriggers);
+    assert!(!app.visible_variants().contains(&'ə'));
+}
@@ -0,0 +1,80 @@
+use proptest::prelude::*;
+
+use quick_accent::config::app::AppConfig;
+use quick_accent::screen::ScreenInfo;
+
+/// Window settings within their documented ranges, on screens from 320x200 to 8K
+fn valid_config() -> impl Strategy<Value = (AppConfig, f32, f32)> {
+    (
+        320.0f32..7680.0,
+        200.0f32..4320.0,
+        0.05f32..=1.0,
+        0.0f32..=1.0,
+        10.0f32..200.0,
+        any::<bool>(),
+        0.0f32..2000.0,
+    )
+        .prop_map(
+            |(screen_width, screen_height, width_fraction, y_position_fraction, height, center, x_offset)| {
+                let mut config = AppConfig::default();
+                config.window.width_fraction = width_fraction;
+                config.window.y_position_fraction = y_position_fraction;
+                config.window.height = height;
+                config.window.center_horizontally = center;
+                config.window.x_offset = x_offset;
+                (config, screen_width, screen_height)
+            },
+        )
+}
+
+proptest! {
+    #[test]
+    fn window_fits_the_screen_width((config, screen_width, screen_height) in valid_config()) {
+        let (width, height) = config.calculate_window_size(screen_width, screen_height);
+        prop_assert!(width.is_finite() && height.is_finite());
+        prop_assert!(width <= screen_width);
+        prop_assert_eq!(height, config.window.height);
+    }
+
+    #[test]
+    fn window_position_is_never_negative((config, screen_width, screen_height) in valid_config()) {
+        let (width, _) = config.calculate_window_size(screen_width, screen_height);
+        let (x, y) = config.calculate_window_position(screen_width, screen_height, width, None);
+        prop_assert!(x >= 0.0, "x = {}", x);
+        prop_assert!(y >= 0.0, "y = {}", y);
+    }
+
+    #[test]
+    fn window_position_keeps_a_fitting_window_above_the_bottom((config, screen_width, screen_height) in valid_config()) {
+        // Only a configured position that runs off the bottom may do so, `ensure_visible` handles those
+        prop_assume!(screen_height * config.window.y_position_fraction + config.window.height <= screen_height);
+        let (width, height) = config.calculate_window_size(screen_width, screen_height);
+        let (_, y) = config.calculate_window_position(screen_width, screen_height, width, None);
+        prop_assert!(y + height <= screen_height + 1e-3, "y = {}, height = {}", y, height);
+    }
+
+    #[test]
+    fn placed_window_stays_on_screen((config, screen_width, screen_height) in valid_config()) {
+        let window = config.window_rect(&ScreenInfo::new(screen_width, screen_height));
+        // Rounding in the visible-fraction check may leave the window a hair outside
+        let tolerance = 1e-3 * screen_width.max(screen_height);
+        prop_assert!(window.x >= -tolerance && window.y >= -tolerance, "{:?}", window);
+        prop_assert!(window.right() <= screen_width + tolerance, "{:?}", window);
+        prop_assert!(window.bottom() <= screen_height + tolerance, "{:?}", window);
+    }
+
+    #[test]
+    fn config_roundtrips_through_ron(
+        (mut config, _, _) in valid_config(),
+        font_size in 6.0f32..200.0,
+        padding in 0.0f32..0.5,
+    ) {
+        config.tool.font_size = font_size;
+        config.tool.horizontal_padding_fraction = padding;
+        prop_assert!(config.validate().is_ok());
+
+        let serialized = ron::to_string(&config).unwrap();
+        let parsed: AppConfig = ron::from_str(&serialized).unwrap();
+        prop_assert_eq!(ron::to_string(&parsed).unwrap(), serialized);
+    }
+}
@@ -0,0 +1,37 @@
+use quick_accent::screen::ScreenInfo;
+use quick_accent::screen::fixtures::{XRANDR_CURRENT, XRANDR_VERBOSE};
+
+#[test]
+fn short_format_primary() {
+    let screen = ScreenInfo::parse_xrandr_primary(XRANDR_CURRENT).unwrap();
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+    assert_eq!((screen.x, screen.y), (0.0, 0.0));
+}
+
+#[test]
+fn verbose_format_primary() {
+    let screen = ScreenInfo::parse_xrandr_verbose_primary(XRANDR_VERBOSE).unwrap();
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+    assert_eq!((screen.x, screen.y), (0.0, 0.0));
+}
+
+#[test]
+fn verbose_format_without_output_geometry() {
+    let output = "\
+Screen 0: minimum 320 x 200, current 2560 x 1440, maximum 16384 x 16384
+DP-1 connected primary (0x48) normal (normal left inverted right x axis y axis) 597mm x 336mm
+\tIdentifier: 0x42
+  1920x1080 (0x1a3) 148.500MHz +HSync +VSync
+  2560x1440 (0x48) 241.500MHz +HSync -VSync *current +preferred
+";
+    assert!(ScreenInfo::parse_xrandr_primary(output).is_none());
+
+    let screen = ScreenInfo::parse_xrandr_verbose_primary(output).unwrap();
+    assert_eq!((screen.width, screen.height), (2560.0, 1440.0));
+}
+
+#[test]
+fn verbose_parser_ignores_short_format() {
+    // Short mode lines have no "*current" marker
+    assert!(ScreenInfo::parse_xrandr_verbose_primary(XRANDR_CURRENT).is_none());
+}